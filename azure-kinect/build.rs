@@ -1,26 +1,128 @@
-use std::env;
-use std::path::PathBuf;
-
 fn main() {
-    if cfg!(target_os = "windows") { 
-        let bindings = bindgen::Builder::default()
-        .header("wrapper.h")
-        .clang_arg(r#"-IC:\Program Files\Azure Kinect SDK v1.4.1\sdk\include"#)
-        .clang_arg(r#"-IC:\Program Files\Azure Kinect Body Tracking SDK\sdk\include"#)
-        .clang_arg("-v")
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks))
-        .allowlist_function("k4a.*")
-        .allowlist_type("_?[kK]4[aA].*")
-        .allowlist_var("[kK]4[aA].*")
-        .rustified_enum("[kK]4[aA].*")
-        .generate()
-        .expect("Unable to generate bindings");
-
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");
+    // The pre-generated bindings in `src/bindings` need nothing at build time.
+    #[cfg(feature = "bindgen")]
+    generate::bindings();
+    #[cfg(feature = "link")]
+    link::search_paths();
+}
+
+/// Generates the bindings from the SDK headers.
+///
+/// `K4A_INCLUDE_DIR` and `K4ABT_INCLUDE_DIR` hold the `include` directories of the Sensor SDK and
+/// the Body Tracking SDK, as lists like `PATH`. When unset, the newest SDK installed under
+/// `%ProgramFiles%` is used on Windows, and the system include path (where the
+/// `libk4a1.4-dev` and `libk4abt1.1-dev` packages install) elsewhere.
+#[cfg(feature = "bindgen")]
+mod generate {
+    use std::env;
+    use std::path::PathBuf;
+
+    pub(crate) fn bindings() {
+        println!("cargo:rerun-if-changed=wrapper.h");
+        let mut builder = bindgen::Builder::default()
+            .header("wrapper.h")
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+            .allowlist_function("k4a.*")
+            .allowlist_type("_?[kK]4[aA].*")
+            .allowlist_var("[kK]4[aA].*")
+            .rustified_enum("[kK]4[aA].*");
+        for dir in super::sdk::dirs("K4A_INCLUDE_DIR", "Azure Kinect SDK v", "include")
+            .into_iter()
+            .chain(super::sdk::dirs(
+                "K4ABT_INCLUDE_DIR",
+                "Azure Kinect Body Tracking SDK",
+                "include",
+            ))
+        {
+            builder = builder.clang_arg(format!("-I{}", dir.display()));
+        }
+        let bindings = builder
+            .generate()
+            .expect("Unable to generate bindings, set K4A_INCLUDE_DIR and K4ABT_INCLUDE_DIR");
+
+        // Write the bindings to the $OUT_DIR/bindings.rs file.
+        let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+        bindings
+            .write_to_file(out_path.join("bindings.rs"))
+            .expect("Couldn't write bindings!");
+    }
+}
+
+/// Adds the directories of the import libraries (`k4a.lib`) or shared libraries (`libk4a.so`)
+/// to the linker search path.
+///
+/// `K4A_LIB_DIR` and `K4ABT_LIB_DIR` work like the include directories above. When unset, the
+/// `lib` directory of the newest SDK installed under `%ProgramFiles%` is used on Windows, and
+/// the system library path elsewhere.
+#[cfg(feature = "link")]
+mod link {
+    pub(crate) fn search_paths() {
+        let mut dirs = super::sdk::dirs("K4A_LIB_DIR", "Azure Kinect SDK v", "lib");
+        if cfg!(feature = "link-k4abt") {
+            dirs.extend(super::sdk::dirs(
+                "K4ABT_LIB_DIR",
+                "Azure Kinect Body Tracking SDK",
+                "lib",
+            ));
+        }
+        for dir in dirs {
+            println!("cargo:rustc-link-search=native={}", dir.display());
+        }
+    }
+}
+
+#[cfg(any(feature = "bindgen", feature = "link"))]
+mod sdk {
+    use std::env;
+    use std::path::{Path, PathBuf};
+
+    /// The directories listed in `variable`, or else `kind` (`include` or `lib`) of the newest
+    /// SDK installed under `%ProgramFiles%` when building for Windows.
+    pub(crate) fn dirs(variable: &str, install_folder: &str, kind: &str) -> Vec<PathBuf> {
+        println!("cargo:rerun-if-env-changed={}", variable);
+        match env::var_os(variable) {
+            Some(value) => env::split_paths(&value).collect(),
+            None => installed_dir(install_folder, kind).into_iter().collect(),
+        }
+    }
+
+    /// For example `C:\Program Files\Azure Kinect SDK v1.4.1\sdk\include`, or
+    /// `...\sdk\windows-desktop\amd64\release\lib` for libraries.
+    fn installed_dir(install_folder: &str, kind: &str) -> Option<PathBuf> {
+        if env::var("CARGO_CFG_WINDOWS").is_err() {
+            return None;
+        }
+        let sdk_dir = |sdk: &Path| match kind {
+            "include" => sdk.join("sdk").join("include"),
+            _ => sdk.join(r"sdk\windows-desktop\amd64\release").join(kind),
+        };
+        let program_files = env::var_os("ProgramFiles")?;
+        std::fs::read_dir(program_files)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.starts_with(install_folder))
+            })
+            .map(|entry| entry.path())
+            .filter(|sdk| sdk_dir(sdk).is_dir())
+            .max_by_key(|sdk| version_key(sdk))
+            .map(|sdk| sdk_dir(&sdk))
+    }
+
+    /// Orders `...SDK v1.4.1` after `...SDK v1.3.0`, and `...SDK v1.10.0` after both.
+    fn version_key(sdk: &Path) -> Vec<u32> {
+        sdk.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.rsplit_once(" v"))
+            .map(|(_, version)| {
+                version
+                    .split('.')
+                    .filter_map(|part| part.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
     }
-    
 }
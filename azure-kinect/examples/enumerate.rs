@@ -2,20 +2,22 @@ use azure_kinect::*;
 
 pub fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let api = Api::new()?;
-    let device_count = api.device_get_installed_count();
-    println!("Found {} connected devices:", device_count);
+    let devices = Device::enumerate(&api);
+    println!("Found {} connected devices:", devices.len());
 
-    for device_idx in 0..device_count {
-        match Device::new(api.clone(), device_idx) {
-            Ok(device) => {
+    for (device_idx, device) in devices.iter().enumerate() {
+        match device {
+            Ok(info) => {
                 println!(
-                    "Device {} | serial number: {} | firmware version: {:#?}",
-                    device_idx,
-                    device.get_serialnum()?,
-                    device.get_version()?
+                    "Device {} | serial number: {} | sync in/out: {}/{} | firmware version: {:#?}",
+                    info.index,
+                    info.serial,
+                    info.sync_in_connected,
+                    info.sync_out_connected,
+                    info.version
                 );
             }
-            Err(err) => print!("Couldn't open Device {}", device_idx),
+            Err(err) => println!("Couldn't open Device {}: {}", device_idx, err),
         }
     }
     Ok(())
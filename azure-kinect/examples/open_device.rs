@@ -2,11 +2,17 @@ use azure_kinect::*;
 
 pub fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let api = Api::new()?;
-    let device = Device::new(api.clone(), 0)?;
-    let camera_config = k4a_device_configuration_t::default();
-    device.start_cameras(&camera_config)?;
+    let mut device = Device::new(api.clone(), 0)?;
+    let camera_config = DeviceConfiguration::new()
+        .color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_720P,
+        )
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .build()?;
+    let cameras = device.start_cameras(&camera_config)?;
 
-    if let Ok(capture) = device.get_capture(1000) {
+    if let Ok(capture) = cameras.get_capture(1000) {
         let image = capture.get_color_image();
         println!(
             "format = {:?}, width = {}, height = {}, temparature = {}",
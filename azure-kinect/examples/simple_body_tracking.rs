@@ -2,18 +2,18 @@ use azure_kinect::*;
 
 pub fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let api = Api::new()?;
-    let device = Device::new(api.clone(), 0).expect("Open K4A Device failed!");
+    let mut device = Device::new(api.clone(), 0).expect("Open K4A Device failed!");
 
     let camera_config = k4a_device_configuration_t {
         depth_mode: k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED,
         ..k4a_device_configuration_t::default()
     };
 
-    device
+    let cameras = device
         .start_cameras(&camera_config)
         .expect("Start K4A cameras failed!");
 
-    let sensor_calibration = device
+    let sensor_calibration = cameras
         .get_calibration(
             camera_config.depth_mode,
             k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF,
@@ -30,16 +30,16 @@ pub fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     .expect("Body tracker initialization failed!");
 
     for frame_count in 0..10000 {
-        if let Ok(capture) = device.get_capture_wait_infinite() {
+        if let Ok(capture) = cameras.get_capture_wait_infinite() {
             //println!("Start processing frame {}", frame_count);
 
-            match tracker.enqueue_capture(capture.handle, K4A_WAIT_INFINITE) {
+            match tracker.enqueue_capture(&capture, K4A_WAIT_INFINITE) {
                 Ok(_) => (),
-                Err(err) => match azure_kinect::Error::from(err) {
-                    Error::Timeout => {
+                Err(err) => match err.kind() {
+                    ErrorKind::Timeout => {
                         println!("Error! Add capture to tracker process queue timeout!")
                     }
-                    Error::Failed => {
+                    ErrorKind::Failed => {
                         println!("Error! Add capture to tracker process queue failed!")
                     }
                     _ => println!("an unexpected error occured"),
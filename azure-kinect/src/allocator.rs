@@ -0,0 +1,144 @@
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::os::raw;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Provides the memory of the image buffers the sensor SDK allocates, such as the images of
+/// every capture. Install one with [`Api::set_allocator`](crate::Api::set_allocator).
+///
+/// # Safety
+///
+/// `allocate` must return null or a buffer of at least `size` bytes, aligned to at least 32 bytes,
+/// that stays valid and is not used by anything else until it is passed to `deallocate`. Both
+/// methods are called from the threads of the SDK.
+pub unsafe trait Allocator: Send + Sync {
+    /// Returns a buffer of at least `size` bytes, or null if no memory is available.
+    fn allocate(&self, size: usize) -> *mut u8;
+
+    /// Releases a buffer returned by `allocate`.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` was returned by `self.allocate(size)` and is not used afterwards.
+    unsafe fn deallocate(&self, buffer: *mut u8, size: usize);
+}
+
+/// Allocates from the global Rust allocator. Install it to collect [`AllocationStats`] without
+/// changing where the memory comes from.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemAllocator;
+
+impl SystemAllocator {
+    const ALIGNMENT: usize = 64;
+
+    fn layout(size: usize) -> Option<Layout> {
+        Layout::from_size_align(size.max(1), Self::ALIGNMENT).ok()
+    }
+}
+
+unsafe impl Allocator for SystemAllocator {
+    fn allocate(&self, size: usize) -> *mut u8 {
+        match Self::layout(size) {
+            Some(layout) => unsafe { std::alloc::alloc(layout) },
+            None => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn deallocate(&self, buffer: *mut u8, size: usize) {
+        if let Some(layout) = Self::layout(size) {
+            std::alloc::dealloc(buffer, layout);
+        }
+    }
+}
+
+/// Counters of the buffers allocated through the hooks of
+/// [`Api::set_allocator`](crate::Api::set_allocator), across every allocator installed in the
+/// process.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Bytes currently allocated.
+    pub live_bytes: usize,
+    /// Highest value `live_bytes` has reached.
+    pub peak_bytes: usize,
+    /// Buffers currently allocated.
+    pub live_allocations: usize,
+    /// Buffers allocated since the process started.
+    pub total_allocations: usize,
+    /// Allocations the allocator returned null for.
+    pub failed_allocations: usize,
+}
+
+static ALLOCATOR: RwLock<Option<Arc<dyn Allocator>>> = RwLock::new(None);
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static TOTAL_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static FAILED_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Handed to the SDK as the context of an allocation. Holds on to the allocator, so a buffer is
+/// released by the allocator it came from even after another one was installed.
+struct Allocation {
+    allocator: Arc<dyn Allocator>,
+    size: usize,
+}
+
+pub(crate) fn stats() -> AllocationStats {
+    AllocationStats {
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+        live_allocations: LIVE_ALLOCATIONS.load(Ordering::Relaxed),
+        total_allocations: TOTAL_ALLOCATIONS.load(Ordering::Relaxed),
+        failed_allocations: FAILED_ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Replaces the allocator used by the hooks, returning the previous one.
+pub(crate) fn replace(allocator: Option<Arc<dyn Allocator>>) -> Option<Arc<dyn Allocator>> {
+    let mut current = ALLOCATOR.write().unwrap_or_else(|e| e.into_inner());
+    std::mem::replace(&mut *current, allocator)
+}
+
+pub(crate) unsafe extern "C" fn allocate_hook(
+    size: raw::c_int,
+    context: *mut *mut c_void,
+) -> *mut u8 {
+    let allocator = match ALLOCATOR.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        Some(allocator) => allocator.clone(),
+        None => return ptr::null_mut(),
+    };
+    if size < 0 || context.is_null() {
+        return ptr::null_mut();
+    }
+    let size = size as usize;
+    // Unwinding into the SDK is undefined behavior.
+    let buffer = panic::catch_unwind(AssertUnwindSafe(|| allocator.allocate(size)))
+        .unwrap_or(ptr::null_mut());
+    if buffer.is_null() {
+        FAILED_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return ptr::null_mut();
+    }
+    *context = Box::into_raw(Box::new(Allocation { allocator, size })) as *mut c_void;
+    let live_bytes = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(live_bytes, Ordering::Relaxed);
+    LIVE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    TOTAL_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    buffer
+}
+
+pub(crate) unsafe extern "C" fn free_hook(buffer: *mut c_void, context: *mut c_void) {
+    if context.is_null() {
+        return;
+    }
+    let allocation = Box::from_raw(context as *mut Allocation);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        allocation
+            .allocator
+            .deallocate(buffer as *mut u8, allocation.size)
+    }));
+    LIVE_BYTES.fetch_sub(allocation.size, Ordering::Relaxed);
+    LIVE_ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+}
@@ -1,41 +1,44 @@
-use super::error::ToResult;
 use super::k4a_functions::*;
 use super::k4abt_functions::*;
+use super::loader;
 use super::*;
-use std::ffi::{c_void, CString};
+use std::ffi::c_void;
 use std::os::raw;
+use std::path::Path;
 use std::ptr;
-use std::sync::Arc;
-
-#[link(name = "kernel32")]
-#[no_mangle]
-extern "stdcall" {
-    fn GetLastError() -> u32;
-    fn LoadLibraryExW(
-        lpLibFileName: *const u16,
-        hFile: *const c_void,
-        dwFlags: u32,
-    ) -> *const c_void;
-    fn FreeLibrary(hLibModule: *const c_void) -> i32;
-    fn GetProcAddress(hModule: *const c_void, lpProcName: *const u8) -> *const c_void;
-}
+use std::sync::{Arc, RwLock};
+
+/// Receives the level, source file, line and text of the debug messages of the SDK. It is called
+/// from the threads of the SDK, possibly from several at once.
+pub type DebugMessageHandler =
+    Box<dyn Fn(k4a_log_level_t, &str, raw::c_int, &str) + Send + Sync + 'static>;
 
-pub type DebugMessageHandler = Box<dyn Fn(k4a_log_level_t, &str, raw::c_int, &str)>;
+/// The SDK keeps a single debug message handler per process, and so does this crate. Every
+/// registration points the SDK at `debug_message_handler_func`, which calls the handler stored
+/// here, so the callback never refers to memory owned by an `Api`.
+static DEBUG_MESSAGE_HANDLER: RwLock<Option<DebugMessageHandler>> = RwLock::new(None);
 
+// SAFETY: the tables hold a library handle and function pointers, which are never changed after
+// loading and only released on drop. The SDK functions are thread-safe.
 unsafe impl Send for Api {}
 unsafe impl Sync for Api {}
 
+unsafe impl Send for ApiRecord {}
+unsafe impl Sync for ApiRecord {}
+
 unsafe impl Send for ApiTracker {}
 unsafe impl Sync for ApiTracker {}
 
 pub struct Api {
-    handle: *const c_void,
-    require_free_library: bool,
-    debug_message_handler: Option<DebugMessageHandler>,
+    pub(crate) handle: *const c_void,
+    pub(crate) require_free_library: bool,
+    /// The depth engine plugin preloaded by [`Api::with_search`], or null.
+    pub(crate) depth_engine: *const c_void,
+    pub(crate) sdk_version: SdkVersion,
 
     pub(crate) k4a_device_get_installed_count: k4a_device_get_installed_count,
     pub(crate) k4a_set_debug_message_handler: k4a_set_debug_message_handler,
-    //pub(crate) k4a_set_allocator: k4a_set_allocator,
+    pub(crate) k4a_set_allocator: Option<k4a_set_allocator>,
     pub(crate) k4a_device_open: k4a_device_open,
     pub(crate) k4a_device_close: k4a_device_close,
     pub(crate) k4a_device_get_capture: k4a_device_get_capture,
@@ -59,17 +62,14 @@ pub struct Api {
     pub(crate) k4a_image_get_width_pixels: k4a_image_get_width_pixels,
     pub(crate) k4a_image_get_height_pixels: k4a_image_get_height_pixels,
     pub(crate) k4a_image_get_stride_bytes: k4a_image_get_stride_bytes,
-    //pub(crate) k4a_image_get_timestamp_usec: k4a_image_get_timestamp_usec,
     pub(crate) k4a_image_get_device_timestamp_usec: k4a_image_get_device_timestamp_usec,
     pub(crate) k4a_image_get_system_timestamp_nsec: k4a_image_get_system_timestamp_nsec,
     pub(crate) k4a_image_get_exposure_usec: k4a_image_get_exposure_usec,
     pub(crate) k4a_image_get_white_balance: k4a_image_get_white_balance,
     pub(crate) k4a_image_get_iso_speed: k4a_image_get_iso_speed,
     pub(crate) k4a_image_set_device_timestamp_usec: k4a_image_set_device_timestamp_usec,
-    //pub(crate) k4a_image_set_timestamp_usec: k4a_image_set_timestamp_usec,
     pub(crate) k4a_image_set_system_timestamp_nsec: k4a_image_set_system_timestamp_nsec,
     pub(crate) k4a_image_set_exposure_usec: k4a_image_set_exposure_usec,
-    //pub(crate) k4a_image_set_exposure_time_usec: k4a_image_set_exposure_time_usec,
     pub(crate) k4a_image_set_white_balance: k4a_image_set_white_balance,
     pub(crate) k4a_image_set_iso_speed: k4a_image_set_iso_speed,
     pub(crate) k4a_image_reference: k4a_image_reference,
@@ -97,58 +97,50 @@ pub struct Api {
     pub(crate) k4a_transformation_depth_image_to_color_camera:
         k4a_transformation_depth_image_to_color_camera,
     pub(crate) k4a_transformation_depth_image_to_color_camera_custom:
-        k4a_transformation_depth_image_to_color_camera_custom,
+        Option<k4a_transformation_depth_image_to_color_camera_custom>,
     pub(crate) k4a_transformation_color_image_to_depth_camera:
         k4a_transformation_color_image_to_depth_camera,
     pub(crate) k4a_transformation_depth_image_to_point_cloud:
         k4a_transformation_depth_image_to_point_cloud,
 }
 
-const K4A_LIBNAME: &'static str = "k4a.dll";
-const K4ARECORD_LIBNAME: &'static str = "k4arecord.dll";
-const K4ABT_LIBNAME: &'static str = "k4abt.dll";
-
-fn load_library(lib_dir: &str, dll_file_name: &str) -> Result<*const c_void, Error> {
-    let full_path =
-        std::path::Path::new(if lib_dir.len() > 0 { lib_dir } else { "." }).join(dll_file_name);
-
-    unsafe {
-        LoadLibraryExW(
-            full_path
-                .to_str()
-                .ok_or(Error::Failed)?
-                .encode_utf16()
-                .chain(Some(0))
-                .collect::<Vec<u16>>()
-                .as_ptr(),
-            ptr::null(),
-            0x000,
+/// Resolves a required entry point, trying the deprecated names it used to have in older SDK
+/// versions when the current name is missing.
+macro_rules! proc_address {
+    ($h:ident, $proc_name:ident $(, $deprecated_name:ident)*) => {
+        std::mem::transmute::<*const c_void, $proc_name>(
+            loader::get_proc_address($h, concat!(stringify!($proc_name), "\0"))
+                $(.or_else(|e| {
+                    loader::get_proc_address($h, concat!(stringify!($deprecated_name), "\0"))
+                        .map_err(|_| e)
+                }))*?,
         )
-        .to_result()
-    }
+    };
 }
 
-macro_rules! proc_address {
+/// Resolves an entry point that not every supported SDK version exports.
+macro_rules! optional_proc_address {
     ($h:ident, $proc_name:ident) => {
-        std::mem::transmute::<_, $proc_name>(
-            GetProcAddress($h, concat!(stringify!($proc_name), "\0").as_ptr()).to_result()?,
-        )
+        loader::get_proc_address($h, concat!(stringify!($proc_name), "\0"))
+            .ok()
+            .map(|p| std::mem::transmute::<*const c_void, $proc_name>(p))
     };
 }
 
 impl Api {
     fn with_handle(handle: *const c_void, require_free_library: bool) -> Result<Api, Error> {
         unsafe {
-            Ok(Api {
+            let mut api = Api {
                 handle: handle,
                 require_free_library: require_free_library,
-                debug_message_handler: None,
+                depth_engine: ptr::null(),
+                sdk_version: SdkVersion::BINDINGS,
                 k4a_device_get_installed_count: proc_address!(
                     handle,
                     k4a_device_get_installed_count
                 ),
                 k4a_set_debug_message_handler: proc_address!(handle, k4a_set_debug_message_handler),
-                //k4a_set_allocator: proc_address!(handle, k4a_set_allocator),
+                k4a_set_allocator: optional_proc_address!(handle, k4a_set_allocator),
                 k4a_device_open: proc_address!(handle, k4a_device_open),
                 k4a_device_close: proc_address!(handle, k4a_device_close),
                 k4a_device_get_capture: proc_address!(handle, k4a_device_get_capture),
@@ -172,10 +164,10 @@ impl Api {
                 k4a_image_get_width_pixels: proc_address!(handle, k4a_image_get_width_pixels),
                 k4a_image_get_height_pixels: proc_address!(handle, k4a_image_get_height_pixels),
                 k4a_image_get_stride_bytes: proc_address!(handle, k4a_image_get_stride_bytes),
-                //k4a_image_get_timestamp_usec: proc_address!(handle, k4a_image_get_timestamp_usec),
                 k4a_image_get_device_timestamp_usec: proc_address!(
                     handle,
-                    k4a_image_get_device_timestamp_usec
+                    k4a_image_get_device_timestamp_usec,
+                    k4a_image_get_timestamp_usec
                 ),
                 k4a_image_get_system_timestamp_nsec: proc_address!(
                     handle,
@@ -186,18 +178,18 @@ impl Api {
                 k4a_image_get_iso_speed: proc_address!(handle, k4a_image_get_iso_speed),
                 k4a_image_set_device_timestamp_usec: proc_address!(
                     handle,
-                    k4a_image_set_device_timestamp_usec
+                    k4a_image_set_device_timestamp_usec,
+                    k4a_image_set_timestamp_usec
                 ),
-                //k4a_image_set_timestamp_usec: proc_address!(handle, k4a_image_set_timestamp_usec),
                 k4a_image_set_system_timestamp_nsec: proc_address!(
                     handle,
                     k4a_image_set_system_timestamp_nsec
                 ),
-                k4a_image_set_exposure_usec: proc_address!(handle, k4a_image_set_exposure_usec),
-                //k4a_image_set_exposure_time_usec: proc_address!(
-                //    handle,
-                //    k4a_image_set_exposure_time_usec
-                //),
+                k4a_image_set_exposure_usec: proc_address!(
+                    handle,
+                    k4a_image_set_exposure_usec,
+                    k4a_image_set_exposure_time_usec
+                ),
                 k4a_image_set_white_balance: proc_address!(handle, k4a_image_set_white_balance),
                 k4a_image_set_iso_speed: proc_address!(handle, k4a_image_set_iso_speed),
                 k4a_image_reference: proc_address!(handle, k4a_image_reference),
@@ -235,7 +227,7 @@ impl Api {
                     handle,
                     k4a_transformation_depth_image_to_color_camera
                 ),
-                k4a_transformation_depth_image_to_color_camera_custom: proc_address!(
+                k4a_transformation_depth_image_to_color_camera_custom: optional_proc_address!(
                     handle,
                     k4a_transformation_depth_image_to_color_camera_custom
                 ),
@@ -247,108 +239,234 @@ impl Api {
                     handle,
                     k4a_transformation_depth_image_to_point_cloud
                 ),
-            })
+            };
+            // The SDK does not export its version, so it is read from the library file. Failing
+            // that, report the oldest release that exports the same entry points.
+            api.sdk_version = loader::library_version(
+                handle,
+                api.k4a_device_get_installed_count as *const c_void,
+            )
+            .unwrap_or(
+                if api
+                    .k4a_transformation_depth_image_to_color_camera_custom
+                    .is_some()
+                {
+                    SdkVersion::new(1, 3, 0)
+                } else {
+                    SdkVersion::new(1, 2, 0)
+                },
+            );
+            Ok(api)
         }
     }
 
+    /// Loads the library from the default locations of [`LibrarySearch`].
+    #[cfg(not(feature = "link"))]
     pub fn new() -> Result<Arc<Api>, Error> {
-        Ok(Arc::new(Self::with_library_directory(
-            std::env::current_exe()
-                .map_err(|_| Error::Failed)?
-                .parent()
-                .ok_or(Error::Failed)?
-                .to_str()
-                .ok_or(Error::Failed)?,
-        )?))
+        Ok(Arc::new(Self::with_search(&LibrarySearch::new())?))
     }
 
+    /// Uses the library linked at build time. The `with_*` constructors still load one at run
+    /// time.
+    #[cfg(feature = "link")]
+    pub fn new() -> Result<Arc<Api>, Error> {
+        Ok(Arc::new(Api::with_handle(loader::LINKED, false)?))
+    }
+
+    /// Loads the library from `lib_dir` only.
     pub fn with_library_directory(lib_dir: &str) -> Result<Api, Error> {
-        let h = load_library(lib_dir, K4A_LIBNAME)?;
+        Self::with_search(&LibrarySearch::empty().directory(lib_dir))
+    }
+
+    /// Loads the library from the first location of `search` it is found in, and preloads the
+    /// depth engine plugin. On failure the error lists every path that was tried.
+    pub fn with_search(search: &LibrarySearch) -> Result<Api, Error> {
+        let (handle, path) = loader::load_library(search, &loader::K4A)?;
+        let mut api = Self::with_library(handle)?;
+        let directory = match path.parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => Some(directory.to_path_buf()),
+            _ => unsafe {
+                loader::library_path(handle, api.k4a_device_get_installed_count as *const c_void)
+            }
+            .and_then(|path| path.parent().map(Path::to_path_buf)),
+        };
+        match loader::load_depth_engine(search, directory.as_deref()) {
+            Ok(depth_engine) => api.depth_engine = depth_engine,
+            Err(error) if search.require_depth_engine => return Err(error),
+            Err(_) => (),
+        }
+        Ok(api)
+    }
+
+    fn with_library(h: *const c_void) -> Result<Api, Error> {
         let r = Api::with_handle(h, true);
-        if let Err(_) = r {
+        if r.is_err() {
             unsafe {
-                FreeLibrary(h);
+                loader::free_library(h);
             }
         }
         r
     }
 
-    /// Sets and clears the callback function to receive debug messages from the Azure Kinect device.
+    /// Sets the callback function to receive debug messages from the Azure Kinect device.
     pub fn set_debug_message_handler(
-        mut self,
+        self,
         debug_message_handler: DebugMessageHandler,
         min_level: k4a_log_level_t,
     ) -> Self {
-        self.set_debug_message_handler_internal(debug_message_handler, min_level);
+        let _ = self.replace_debug_message_handler(Some(debug_message_handler), min_level);
         self
     }
 
     /// Clears the callback function to receive debug messages from the Azure Kinect device.
-    pub fn reset_debug_message_handler(mut self) -> Self {
-        self.reset_debug_message_handler_internal();
+    pub fn reset_debug_message_handler(self) -> Self {
+        let _ = self.replace_debug_message_handler(None, k4a_log_level_t::K4A_LOG_LEVEL_OFF);
         self
     }
 
-    /// Sets and clears the callback function to receive debug messages from the Azure Kinect device.
-    pub(crate) fn set_debug_message_handler_internal(
-        &mut self,
-        debug_message_handler: DebugMessageHandler,
+    /// Sets or, with `None`, clears the callback function to receive debug messages from the
+    /// Azure Kinect device. Unlike [`Api::set_debug_message_handler`] this works on a shared
+    /// `Api`, such as the one returned by [`Api::new`].
+    ///
+    /// There is one handler per process: it replaces the handler set through any other `Api`.
+    /// It must not set or clear the handler itself.
+    pub fn replace_debug_message_handler(
+        &self,
+        debug_message_handler: Option<DebugMessageHandler>,
         min_level: k4a_log_level_t,
-    ) {
-        self.debug_message_handler = debug_message_handler.into();
-        (self.k4a_set_debug_message_handler)(
-            Some(debug_message_handler_func),
-            &self.debug_message_handler as *const Option<DebugMessageHandler> as *mut (),
-            min_level,
-        );
+    ) -> Result<(), Error> {
+        // The lock is not held while calling into the SDK, which may log from the same thread.
+        let replace = |debug_message_handler| {
+            *DEBUG_MESSAGE_HANDLER
+                .write()
+                .unwrap_or_else(|e| e.into_inner()) = debug_message_handler;
+        };
+        if debug_message_handler.is_some() {
+            replace(debug_message_handler);
+            Error::check("k4a_set_debug_message_handler", unsafe {
+                (self.k4a_set_debug_message_handler)(
+                    Some(debug_message_handler_func),
+                    ptr::null_mut(),
+                    min_level,
+                )
+            })
+        } else {
+            let result = Error::check("k4a_set_debug_message_handler", unsafe {
+                (self.k4a_set_debug_message_handler)(
+                    None,
+                    ptr::null_mut(),
+                    k4a_log_level_t::K4A_LOG_LEVEL_OFF,
+                )
+            });
+            replace(None);
+            result
+        }
     }
 
-    /// Clears the callback function to receive debug messages from the Azure Kinect device.
-    pub fn reset_debug_message_handler_internal(&mut self) {
-        self.debug_message_handler = None;
-        (self.k4a_set_debug_message_handler)(
-            None,
-            ptr::null_mut(),
-            k4a_log_level_t::K4A_LOG_LEVEL_OFF,
-        );
+    /// Routes the image buffer allocations of the SDK through `allocator`, and starts counting
+    /// them in [`Api::allocation_stats`]. Buffers allocated before keep their previous allocator.
+    ///
+    /// The hook is global to the loaded library, so it affects every `Api` using it.
+    pub fn set_allocator(&self, allocator: Arc<dyn Allocator>) -> Result<(), Error> {
+        let k4a_set_allocator = self
+            .k4a_set_allocator
+            .ok_or(Error::new(ErrorKind::Unsupported("k4a_set_allocator")))?;
+        let previous = allocator::replace(Some(allocator));
+        let result = Error::check("k4a_set_allocator", unsafe {
+            k4a_set_allocator(Some(allocator::allocate_hook), Some(allocator::free_hook))
+        });
+        if result.is_err() {
+            allocator::replace(previous);
+        }
+        result
+    }
+
+    /// Restores the default allocator of the SDK. Buffers allocated through the hook are still
+    /// released by the allocator they came from.
+    pub fn reset_allocator(&self) -> Result<(), Error> {
+        let k4a_set_allocator = self
+            .k4a_set_allocator
+            .ok_or(Error::new(ErrorKind::Unsupported("k4a_set_allocator")))?;
+        Error::check("k4a_set_allocator", unsafe {
+            k4a_set_allocator(None, None)
+        })?;
+        allocator::replace(None);
+        Ok(())
+    }
+
+    /// Counters of the buffers allocated through [`Api::set_allocator`] in this process.
+    pub fn allocation_stats(&self) -> AllocationStats {
+        allocator::stats()
+    }
+
+    /// Version of the loaded sensor SDK library.
+    pub fn sdk_version(&self) -> SdkVersion {
+        self.sdk_version
+    }
+
+    /// Whether the loaded library exports the entry points behind `capability`.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Allocator => self.k4a_set_allocator.is_some(),
+            Capability::CustomTransformation => self
+                .k4a_transformation_depth_image_to_color_camera_custom
+                .is_some(),
+        }
+    }
+
+    /// Returns true for the tables created by [`Api::simulated`]. Handles from one backend
+    /// must never be passed to the other.
+    pub(crate) fn is_simulated(&self) -> bool {
+        self.handle.is_null()
     }
 
     /// Gets the number of connected devices
     pub fn device_get_installed_count(&self) -> u32 {
-        (self.k4a_device_get_installed_count)()
+        unsafe { (self.k4a_device_get_installed_count)() }
     }
 }
 
 extern "C" fn debug_message_handler_func(
-    context: *mut ::std::os::raw::c_void,
+    _context: *mut ::std::os::raw::c_void,
     level: k4a_log_level_t,
     file: *const ::std::os::raw::c_char,
     line: ::std::os::raw::c_int,
     message: *const ::std::os::raw::c_char,
 ) {
-    unsafe {
-        let h = context as *const Option<DebugMessageHandler>;
-        if h != ptr::null() && (*h).is_some() {
-            (*h).as_ref().unwrap()(
-                level,
-                std::ffi::CStr::from_ptr(file).to_str().unwrap_or_default(),
-                line,
-                std::ffi::CStr::from_ptr(message)
-                    .to_str()
-                    .unwrap_or_default(),
-            );
+    let to_str = |s: *const ::std::os::raw::c_char| {
+        if s.is_null() {
+            Default::default()
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(s) }.to_string_lossy()
         }
+    };
+    let (file, message) = (to_str(file), to_str(message));
+    error::record_sdk_message(level, &file, line, &message);
+    let handler = DEBUG_MESSAGE_HANDLER
+        .read()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(handler) = handler.as_ref() {
+        // Unwinding into the SDK is undefined behavior.
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            handler(level, &file, line, &message)
+        }));
     }
 }
 
 impl Drop for Api {
     fn drop(&mut self) {
-        if self.handle != ptr::null() && self.require_free_library {
+        if !self.handle.is_null() && self.require_free_library {
             unsafe {
-                FreeLibrary(self.handle);
+                loader::free_library(self.handle);
                 self.handle = ptr::null();
             }
         }
+        if !self.depth_engine.is_null() {
+            unsafe {
+                loader::free_library(self.depth_engine);
+                self.depth_engine = ptr::null();
+            }
+        }
     }
 }
 
@@ -357,7 +475,7 @@ impl Drop for Api {
 //--------------------------------------------------//
 
 pub struct ApiRecord {
-    handle: *const c_void,
+    pub(crate) handle: *const c_void,
     pub(crate) k4a_playback_open: k4a_playback_open,
     pub(crate) k4a_playback_get_raw_calibration: k4a_playback_get_raw_calibration,
     pub(crate) k4a_playback_get_calibration: k4a_playback_get_calibration,
@@ -385,7 +503,6 @@ pub struct ApiRecord {
     pub(crate) k4a_playback_data_block_release: k4a_playback_data_block_release,
     pub(crate) k4a_playback_seek_timestamp: k4a_playback_seek_timestamp,
     pub(crate) k4a_playback_get_recording_length_usec: k4a_playback_get_recording_length_usec,
-    //pub(crate) k4a_playback_get_last_timestamp_usec: k4a_playback_get_last_timestamp_usec,
     pub(crate) k4a_playback_close: k4a_playback_close,
     pub(crate) k4a_record_create: k4a_record_create,
     pub(crate) k4a_record_add_tag: k4a_record_add_tag,
@@ -481,9 +598,9 @@ impl ApiRecord {
                 k4a_playback_seek_timestamp: proc_address!(handle, k4a_playback_seek_timestamp),
                 k4a_playback_get_recording_length_usec: proc_address!(
                     handle,
-                    k4a_playback_get_recording_length_usec
+                    k4a_playback_get_recording_length_usec,
+                    k4a_playback_get_last_timestamp_usec
                 ),
-                //k4a_playback_get_last_timestamp_usec : proc_address!(handle, k4a_playback_get_last_timestamp_usec),
                 k4a_playback_close: proc_address!(handle, k4a_playback_close),
                 k4a_record_create: proc_address!(handle, k4a_record_create),
                 k4a_record_add_tag: proc_address!(handle, k4a_record_add_tag),
@@ -510,34 +627,51 @@ impl ApiRecord {
         }
     }
 
+    /// Loads the library from the default locations of [`LibrarySearch`].
+    #[cfg(not(feature = "link"))]
     pub fn new() -> Result<Arc<ApiRecord>, Error> {
-        Ok(Arc::new(Self::with_library_directory(
-            std::env::current_exe()
-                .map_err(|_| Error::Failed)?
-                .parent()
-                .ok_or(Error::Failed)?
-                .to_str()
-                .ok_or(Error::Failed)?,
-        )?))
+        Ok(Arc::new(Self::with_search(&LibrarySearch::new())?))
+    }
+
+    /// Uses the library linked at build time. The `with_*` constructors still load one at run
+    /// time.
+    #[cfg(feature = "link")]
+    pub fn new() -> Result<Arc<ApiRecord>, Error> {
+        Ok(Arc::new(Self::with_library(loader::LINKED)?))
     }
 
+    /// Loads the library from `lib_dir` only.
     pub fn with_library_directory(lib_dir: &str) -> Result<ApiRecord, Error> {
-        let h = load_library(lib_dir, K4ARECORD_LIBNAME)?;
+        Self::with_search(&LibrarySearch::empty().directory(lib_dir))
+    }
+
+    /// Loads the library from the first location of `search` it is found in. On failure the
+    /// error lists every path that was tried.
+    pub fn with_search(search: &LibrarySearch) -> Result<ApiRecord, Error> {
+        Self::with_library(loader::load_library(search, &loader::K4ARECORD)?.0)
+    }
+
+    fn with_library(h: *const c_void) -> Result<ApiRecord, Error> {
         let r = ApiRecord::with_handle(h);
-        if let Err(_) = r {
+        if r.is_err() {
             unsafe {
-                FreeLibrary(h);
+                loader::free_library(h);
             }
         }
         r
     }
+
+    /// Returns true for the tables created by [`ApiRecord::simulated`].
+    pub(crate) fn is_simulated(&self) -> bool {
+        self.handle.is_null()
+    }
 }
 
 impl Drop for ApiRecord {
     fn drop(&mut self) {
-        if self.handle != ptr::null() {
+        if !self.handle.is_null() {
             unsafe {
-                FreeLibrary(self.handle);
+                loader::free_library(self.handle);
                 self.handle = ptr::null();
             }
         }
@@ -549,7 +683,7 @@ impl Drop for ApiRecord {
 //--------------------------------------------------//
 
 pub struct ApiTracker {
-    handle: *const c_void,
+    pub(crate) handle: *const c_void,
     pub(crate) k4abt_tracker_create: k4abt_tracker_create,
     pub(crate) k4abt_tracker_destroy: k4abt_tracker_destroy,
     pub(crate) k4abt_tracker_set_temporal_smoothing: k4abt_tracker_set_temporal_smoothing,
@@ -598,61 +732,71 @@ impl ApiTracker {
         }
     }
 
+    /// Loads the library from the default locations of [`LibrarySearch`].
+    #[cfg(not(feature = "link-k4abt"))]
     pub fn new() -> Result<Arc<ApiTracker>, Error> {
-        Ok(Arc::new(Self::with_library_directory(
-            std::env::current_exe()
-                .map_err(|_| Error::Failed)?
-                .parent()
-                .ok_or(Error::Failed)?
-                .to_str()
-                .ok_or(Error::Failed)?,
-        )?))
+        Ok(Arc::new(Self::with_search(&LibrarySearch::new())?))
+    }
+
+    /// Uses the library linked at build time. The `with_*` constructors still load one at run
+    /// time.
+    #[cfg(feature = "link-k4abt")]
+    pub fn new() -> Result<Arc<ApiTracker>, Error> {
+        Ok(Arc::new(Self::with_library(loader::LINKED)?))
     }
 
+    /// Loads the library from `lib_dir` only.
     pub fn with_library_directory(lib_dir: &str) -> Result<ApiTracker, Error> {
-        let h = load_library(lib_dir, K4ABT_LIBNAME)?;
+        Self::with_search(&LibrarySearch::empty().directory(lib_dir))
+    }
+
+    /// Loads the library from the first location of `search` it is found in. On failure the
+    /// error lists every path that was tried.
+    pub fn with_search(search: &LibrarySearch) -> Result<ApiTracker, Error> {
+        Self::with_library(loader::load_library(search, &loader::K4ABT)?.0)
+    }
+
+    fn with_library(h: *const c_void) -> Result<ApiTracker, Error> {
         let r = ApiTracker::with_handle(h);
-        if let Err(_) = r {
+        if r.is_err() {
             unsafe {
-                FreeLibrary(h);
+                loader::free_library(h);
             }
         }
         r
     }
+
+    /// Returns true for the tables created by [`ApiTracker::simulated`].
+    pub(crate) fn is_simulated(&self) -> bool {
+        self.handle.is_null()
+    }
 }
 
 impl Drop for ApiTracker {
     fn drop(&mut self) {
-        if self.handle != ptr::null() {
+        if !self.handle.is_null() {
             unsafe {
-                FreeLibrary(self.handle);
+                loader::free_library(self.handle);
                 self.handle = ptr::null();
             }
         }
     }
 }
 
-impl ToResult for *const c_void {
-    fn to_result(&self) -> Result<*const c_void, Error> {
-        if *self == ptr::null() {
-            unsafe { Err(Error::Win32Error(GetLastError())) }
-        } else {
-            Ok(*self)
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test() -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let manager =
-            Api::with_library_directory(std::env::current_dir()?.to_str().ok_or(Error::Failed)?);
+        let manager = Api::with_library_directory(
+            std::env::current_dir()?
+                .to_str()
+                .ok_or(Error::new(ErrorKind::Failed))?,
+        );
         assert!(manager.is_ok());
         let manager2 = manager.unwrap();
-        let c = (manager2.k4a_device_get_installed_count)();
+        let c = unsafe { (manager2.k4a_device_get_installed_count)() };
         println!("device count = {}", c);
         Ok(())
     }
@@ -0,0 +1,213 @@
+//! `futures::Stream`s of captures, IMU samples and body frames, for async applications.
+//!
+//! The streams are fed by the reader threads of [`CaptureStream`] and [`BodyFrames`], so they
+//! work with any executor and never block it. Dropping a stream, or a `next()` future, loses no
+//! item: they stay queued until the next poll. A stream ends after it yielded the error that
+//! stopped its reader thread.
+//!
+//! ```no_run
+//! # use azure_kinect::*;
+//! # use azure_kinect::async_stream::*;
+//! # use futures::StreamExt;
+//! # async fn run(stream: CaptureStream) -> Result<(), Error> {
+//! let mut captures = stream.captures().timeout(std::time::Duration::from_secs(1));
+//! while let Some(capture) = captures.next().await {
+//!     let capture = capture?;
+//!     println!("{}", capture.get_temperature_c());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::stream::{Queue, Reader, Shared};
+use super::*;
+use futures_core::Stream;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// How long the reader thread of [`BodyFrames`] waits for a frame before it checks whether it
+/// should stop.
+const POLL_TIMEOUT_MS: i32 = 100;
+
+/// Polls a queue fed by a reader thread.
+struct Poller {
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+    ended: bool,
+}
+
+impl Poller {
+    fn new() -> Poller {
+        Poller {
+            timeout: None,
+            deadline: None,
+            ended: false,
+        }
+    }
+
+    fn poll<T>(
+        &mut self,
+        shared: &Shared<T>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<T, Error>>> {
+        if self.ended {
+            return Poll::Ready(None);
+        }
+        let mut queue = shared.lock();
+        if let Some(item) = shared.take(&mut queue) {
+            self.deadline = None;
+            return Poll::Ready(Some(Ok(item)));
+        }
+        if let Some(error) = &queue.finished {
+            self.ended = true;
+            return Poll::Ready(Some(Err(error.clone())));
+        }
+        // The reader thread wakes the task at least every time a wait on the SDK times out, so
+        // the deadline is checked about as often.
+        if let Some(timeout) = self.timeout {
+            let now = Instant::now();
+            let deadline = *self.deadline.get_or_insert(now + timeout);
+            if now >= deadline {
+                self.deadline = None;
+                return Poll::Ready(Some(Err(Error::new(ErrorKind::Timeout))));
+            }
+        }
+        register(&mut queue, cx);
+        Poll::Pending
+    }
+}
+
+fn register<T>(queue: &mut Queue<T>, cx: &mut Context<'_>) {
+    match &queue.waker {
+        Some(waker) if waker.will_wake(cx.waker()) => {}
+        _ => queue.waker = Some(cx.waker().clone()),
+    }
+}
+
+impl CaptureStream {
+    /// The captures of the stream, as a `futures::Stream`. There should be a single reader of the
+    /// captures at a time.
+    pub fn captures(&self) -> Captures<'_> {
+        Captures {
+            stream: self,
+            poller: Poller::new(),
+        }
+    }
+
+    /// The IMU samples of a stream started with [`CaptureStream::start_with_imu`], as a
+    /// `futures::Stream`. There should be a single reader of the samples at a time.
+    pub fn imu_samples(&self) -> Result<ImuSamples<'_>, Error> {
+        match self.imu_queue() {
+            Some(queue) => Ok(ImuSamples {
+                queue,
+                poller: Poller::new(),
+            }),
+            None => Err(Error::new(ErrorKind::Failed)),
+        }
+    }
+}
+
+/// The captures of a [`CaptureStream`], returned by [`CaptureStream::captures`].
+pub struct Captures<'a> {
+    stream: &'a CaptureStream,
+    poller: Poller,
+}
+
+impl Captures<'_> {
+    /// Yields [`ErrorKind::Timeout`] whenever no capture arrived for `timeout`, like
+    /// `get_capture` does, instead of waiting for as long as it takes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.poller.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Stream for Captures<'_> {
+    type Item = Result<Capture, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.poller.poll(this.stream.capture_queue(), cx)
+    }
+}
+
+/// The IMU samples of a [`CaptureStream`], returned by [`CaptureStream::imu_samples`].
+pub struct ImuSamples<'a> {
+    queue: &'a Shared<k4a_imu_sample_t>,
+    poller: Poller,
+}
+
+impl ImuSamples<'_> {
+    /// Yields [`ErrorKind::Timeout`] whenever no sample arrived for `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.poller.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Stream for ImuSamples<'_> {
+    type Item = Result<k4a_imu_sample_t, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.poller.poll(this.queue, cx)
+    }
+}
+
+/// The body frames of a [`Tracker`], popped on a thread of their own. Captures are enqueued
+/// through [`BodyFrames::tracker`] as usual.
+pub struct BodyFrames {
+    tracker: Arc<Tracker>,
+    reader: Reader<Frame>,
+    poller: Poller,
+}
+
+impl BodyFrames {
+    /// Pops the body frames of `tracker` into a queue of `capacity` frames, dropping the oldest
+    /// when it is full.
+    pub fn new(tracker: Arc<Tracker>, capacity: usize) -> Result<BodyFrames, Error> {
+        let reader = {
+            let tracker = tracker.clone();
+            Reader::spawn(
+                "k4abt-frames",
+                capacity,
+                OverflowPolicy::DropOldest,
+                move |starter| {
+                    starter.run(|| tracker.pop_result(POLL_TIMEOUT_MS));
+                },
+            )?
+        };
+        Ok(BodyFrames {
+            tracker,
+            reader,
+            poller: Poller::new(),
+        })
+    }
+
+    /// The tracker, to enqueue captures and read the bodies of the frames.
+    pub fn tracker(&self) -> &Tracker {
+        &self.tracker
+    }
+
+    /// Yields [`ErrorKind::Timeout`] whenever no frame arrived for `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.poller.timeout = Some(timeout);
+        self
+    }
+
+    /// Number of frames dropped because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.reader.shared.lock().dropped
+    }
+}
+
+impl Stream for BodyFrames {
+    type Item = Result<Frame, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.poller.poll(&this.reader.shared, cx)
+    }
+}
@@ -1,5 +1,8 @@
 /* automatically generated by rust-bindgen 0.59.1 */
 
+/* Sensor SDK 1.4.1, Body Tracking SDK 1.1.0. Layout tests use `addr_of!` instead of
+   dereferencing null, as current bindgen does. */
+
 pub const K4A_VERSION_MAJOR: u32 = 1;
 pub const K4A_VERSION_MINOR: u32 = 4;
 pub const K4A_VERSION_PATCH: u32 = 1;
@@ -31,6 +34,8 @@ pub struct _k4a_device_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_device_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_device_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_device_t>(),
         8usize,
@@ -42,7 +47,7 @@ fn bindgen_test_layout__k4a_device_t() {
         concat!("Alignment of ", stringify!(_k4a_device_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_device_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -60,6 +65,8 @@ pub struct _k4a_capture_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_capture_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_capture_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_capture_t>(),
         8usize,
@@ -71,7 +78,7 @@ fn bindgen_test_layout__k4a_capture_t() {
         concat!("Alignment of ", stringify!(_k4a_capture_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_capture_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -89,6 +96,8 @@ pub struct _k4a_image_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_image_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_image_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_image_t>(),
         8usize,
@@ -100,7 +109,7 @@ fn bindgen_test_layout__k4a_image_t() {
         concat!("Alignment of ", stringify!(_k4a_image_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_image_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -118,6 +127,9 @@ pub struct _k4a_transformation_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_transformation_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_transformation_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_transformation_t>(),
         8usize,
@@ -129,7 +141,7 @@ fn bindgen_test_layout__k4a_transformation_t() {
         concat!("Alignment of ", stringify!(_k4a_transformation_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_transformation_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -810,6 +822,9 @@ pub struct _k4a_device_configuration_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_device_configuration_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_device_configuration_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_device_configuration_t>(),
         36usize,
@@ -821,10 +836,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         concat!("Alignment of ", stringify!(_k4a_device_configuration_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).color_format as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_format) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -834,10 +846,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).color_resolution as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_resolution) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -847,9 +856,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).depth_mode as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_mode) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -859,9 +866,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).camera_fps as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).camera_fps) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -871,10 +876,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).synchronized_images_only
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).synchronized_images_only) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -884,10 +886,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).depth_delay_off_color_usec
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_delay_off_color_usec) as usize - ptr as usize },
         20usize,
         concat!(
             "Offset of field: ",
@@ -897,10 +896,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).wired_sync_mode as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).wired_sync_mode) as usize - ptr as usize },
         24usize,
         concat!(
             "Offset of field: ",
@@ -911,8 +907,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
     );
     assert_eq!(
         unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>()))
-                .subordinate_delay_off_master_usec as *const _ as usize
+            ::std::ptr::addr_of!((*ptr).subordinate_delay_off_master_usec) as usize - ptr as usize
         },
         28usize,
         concat!(
@@ -923,10 +918,7 @@ fn bindgen_test_layout__k4a_device_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_device_configuration_t>())).disable_streaming_indicator
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).disable_streaming_indicator) as usize - ptr as usize },
         32usize,
         concat!(
             "Offset of field: ",
@@ -969,6 +961,9 @@ pub struct _k4a_calibration_extrinsics_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_calibration_extrinsics_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_calibration_extrinsics_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_calibration_extrinsics_t>(),
         48usize,
@@ -980,9 +975,7 @@ fn bindgen_test_layout__k4a_calibration_extrinsics_t() {
         concat!("Alignment of ", stringify!(_k4a_calibration_extrinsics_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_extrinsics_t>())).rotation as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).rotation) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -992,10 +985,7 @@ fn bindgen_test_layout__k4a_calibration_extrinsics_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_extrinsics_t>())).translation as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).translation) as usize - ptr as usize },
         36usize,
         concat!(
             "Offset of field: ",
@@ -1043,13 +1033,6 @@ pub union k4a_calibration_intrinsic_parameters_t {
     pub v: [f32; 15usize],
 }
 
-impl std::fmt::Debug for k4a_calibration_intrinsic_parameters_t {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?} ", unsafe{self.v})
-        
-    }
-}
-
 #[doc = " individual parameter or array representation of intrinsic model."]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -1087,6 +1070,9 @@ pub struct k4a_calibration_intrinsic_parameters_t__param {
 }
 #[test]
 fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_calibration_intrinsic_parameters_t__param> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_calibration_intrinsic_parameters_t__param>(),
         60usize,
@@ -1104,10 +1090,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).cx as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).cx) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1117,10 +1100,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).cy as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).cy) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1130,10 +1110,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).fx as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).fx) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1143,10 +1120,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).fy as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).fy) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -1156,10 +1130,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k1 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k1) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -1169,10 +1140,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k2 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k2) as usize - ptr as usize },
         20usize,
         concat!(
             "Offset of field: ",
@@ -1182,10 +1150,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k3 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k3) as usize - ptr as usize },
         24usize,
         concat!(
             "Offset of field: ",
@@ -1195,10 +1160,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k4 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k4) as usize - ptr as usize },
         28usize,
         concat!(
             "Offset of field: ",
@@ -1208,10 +1170,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k5 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k5) as usize - ptr as usize },
         32usize,
         concat!(
             "Offset of field: ",
@@ -1221,10 +1180,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).k6 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).k6) as usize - ptr as usize },
         36usize,
         concat!(
             "Offset of field: ",
@@ -1234,10 +1190,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).codx
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).codx) as usize - ptr as usize },
         40usize,
         concat!(
             "Offset of field: ",
@@ -1247,10 +1200,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).cody
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).cody) as usize - ptr as usize },
         44usize,
         concat!(
             "Offset of field: ",
@@ -1260,10 +1210,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).p2 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).p2) as usize - ptr as usize },
         48usize,
         concat!(
             "Offset of field: ",
@@ -1273,10 +1220,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).p1 as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).p1) as usize - ptr as usize },
         52usize,
         concat!(
             "Offset of field: ",
@@ -1286,10 +1230,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t__param>())).metric_radius
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).metric_radius) as usize - ptr as usize },
         56usize,
         concat!(
             "Offset of field: ",
@@ -1301,6 +1242,9 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t__param() {
 }
 #[test]
 fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_calibration_intrinsic_parameters_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_calibration_intrinsic_parameters_t>(),
         60usize,
@@ -1318,10 +1262,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t>())).param as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).param) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1331,10 +1272,7 @@ fn bindgen_test_layout_k4a_calibration_intrinsic_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<k4a_calibration_intrinsic_parameters_t>())).v as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1372,6 +1310,9 @@ pub struct _k4a_calibration_intrinsics_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_calibration_intrinsics_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_calibration_intrinsics_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_calibration_intrinsics_t>(),
         68usize,
@@ -1383,9 +1324,7 @@ fn bindgen_test_layout__k4a_calibration_intrinsics_t() {
         concat!("Alignment of ", stringify!(_k4a_calibration_intrinsics_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_intrinsics_t>())).type_ as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1395,10 +1334,7 @@ fn bindgen_test_layout__k4a_calibration_intrinsics_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_intrinsics_t>())).parameter_count as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).parameter_count) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1408,10 +1344,7 @@ fn bindgen_test_layout__k4a_calibration_intrinsics_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_intrinsics_t>())).parameters as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).parameters) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1463,6 +1396,9 @@ pub struct _k4a_calibration_camera_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_calibration_camera_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_calibration_camera_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_calibration_camera_t>(),
         128usize,
@@ -1474,9 +1410,7 @@ fn bindgen_test_layout__k4a_calibration_camera_t() {
         concat!("Alignment of ", stringify!(_k4a_calibration_camera_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_camera_t>())).extrinsics as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).extrinsics) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1486,9 +1420,7 @@ fn bindgen_test_layout__k4a_calibration_camera_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_camera_t>())).intrinsics as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).intrinsics) as usize - ptr as usize },
         48usize,
         concat!(
             "Offset of field: ",
@@ -1498,10 +1430,7 @@ fn bindgen_test_layout__k4a_calibration_camera_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_camera_t>())).resolution_width as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).resolution_width) as usize - ptr as usize },
         116usize,
         concat!(
             "Offset of field: ",
@@ -1511,10 +1440,7 @@ fn bindgen_test_layout__k4a_calibration_camera_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_camera_t>())).resolution_height as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).resolution_height) as usize - ptr as usize },
         120usize,
         concat!(
             "Offset of field: ",
@@ -1524,9 +1450,7 @@ fn bindgen_test_layout__k4a_calibration_camera_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_camera_t>())).metric_radius as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).metric_radius) as usize - ptr as usize },
         124usize,
         concat!(
             "Offset of field: ",
@@ -1574,6 +1498,8 @@ pub struct _k4a_calibration_t {
 
 #[test]
 fn bindgen_test_layout__k4a_calibration_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_calibration_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_calibration_t>(),
         1032usize,
@@ -1585,10 +1511,7 @@ fn bindgen_test_layout__k4a_calibration_t() {
         concat!("Alignment of ", stringify!(_k4a_calibration_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_t>())).depth_camera_calibration as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_camera_calibration) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1598,10 +1521,7 @@ fn bindgen_test_layout__k4a_calibration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_t>())).color_camera_calibration as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_camera_calibration) as usize - ptr as usize },
         128usize,
         concat!(
             "Offset of field: ",
@@ -1611,7 +1531,7 @@ fn bindgen_test_layout__k4a_calibration_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_calibration_t>())).extrinsics as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).extrinsics) as usize - ptr as usize },
         256usize,
         concat!(
             "Offset of field: ",
@@ -1621,7 +1541,7 @@ fn bindgen_test_layout__k4a_calibration_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_calibration_t>())).depth_mode as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_mode) as usize - ptr as usize },
         1024usize,
         concat!(
             "Offset of field: ",
@@ -1631,9 +1551,7 @@ fn bindgen_test_layout__k4a_calibration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_calibration_t>())).color_resolution as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_resolution) as usize - ptr as usize },
         1028usize,
         concat!(
             "Offset of field: ",
@@ -1671,6 +1589,8 @@ pub struct _k4a_version_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_version_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_version_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_version_t>(),
         12usize,
@@ -1682,7 +1602,7 @@ fn bindgen_test_layout__k4a_version_t() {
         concat!("Alignment of ", stringify!(_k4a_version_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_version_t>())).major as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).major) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1692,7 +1612,7 @@ fn bindgen_test_layout__k4a_version_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_version_t>())).minor as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).minor) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1702,7 +1622,7 @@ fn bindgen_test_layout__k4a_version_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_version_t>())).iteration as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).iteration) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1745,6 +1665,9 @@ pub struct _k4a_hardware_version_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_hardware_version_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_hardware_version_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_hardware_version_t>(),
         56usize,
@@ -1756,7 +1679,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         concat!("Alignment of ", stringify!(_k4a_hardware_version_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_hardware_version_t>())).rgb as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).rgb) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1766,7 +1689,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_hardware_version_t>())).depth as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -1776,7 +1699,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_hardware_version_t>())).audio as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).audio) as usize - ptr as usize },
         24usize,
         concat!(
             "Offset of field: ",
@@ -1786,9 +1709,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_hardware_version_t>())).depth_sensor as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_sensor) as usize - ptr as usize },
         36usize,
         concat!(
             "Offset of field: ",
@@ -1798,9 +1719,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_hardware_version_t>())).firmware_build as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).firmware_build) as usize - ptr as usize },
         48usize,
         concat!(
             "Offset of field: ",
@@ -1810,10 +1729,7 @@ fn bindgen_test_layout__k4a_hardware_version_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_hardware_version_t>())).firmware_signature as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).firmware_signature) as usize - ptr as usize },
         52usize,
         concat!(
             "Offset of field: ",
@@ -1857,6 +1773,8 @@ pub struct k4a_float2_t__xy {
 }
 #[test]
 fn bindgen_test_layout_k4a_float2_t__xy() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_float2_t__xy> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_float2_t__xy>(),
         8usize,
@@ -1868,7 +1786,7 @@ fn bindgen_test_layout_k4a_float2_t__xy() {
         concat!("Alignment of ", stringify!(k4a_float2_t__xy))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float2_t__xy>())).x as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1878,7 +1796,7 @@ fn bindgen_test_layout_k4a_float2_t__xy() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float2_t__xy>())).y as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1890,6 +1808,8 @@ fn bindgen_test_layout_k4a_float2_t__xy() {
 }
 #[test]
 fn bindgen_test_layout_k4a_float2_t() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_float2_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_float2_t>(),
         8usize,
@@ -1901,7 +1821,7 @@ fn bindgen_test_layout_k4a_float2_t() {
         concat!("Alignment of ", stringify!(k4a_float2_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float2_t>())).xy as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).xy) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1911,7 +1831,7 @@ fn bindgen_test_layout_k4a_float2_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float2_t>())).v as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1949,6 +1869,8 @@ pub struct k4a_float3_t__xyz {
 }
 #[test]
 fn bindgen_test_layout_k4a_float3_t__xyz() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_float3_t__xyz> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_float3_t__xyz>(),
         12usize,
@@ -1960,7 +1882,7 @@ fn bindgen_test_layout_k4a_float3_t__xyz() {
         concat!("Alignment of ", stringify!(k4a_float3_t__xyz))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float3_t__xyz>())).x as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1970,7 +1892,7 @@ fn bindgen_test_layout_k4a_float3_t__xyz() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float3_t__xyz>())).y as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1980,7 +1902,7 @@ fn bindgen_test_layout_k4a_float3_t__xyz() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float3_t__xyz>())).z as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1992,6 +1914,8 @@ fn bindgen_test_layout_k4a_float3_t__xyz() {
 }
 #[test]
 fn bindgen_test_layout_k4a_float3_t() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_float3_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_float3_t>(),
         12usize,
@@ -2003,7 +1927,7 @@ fn bindgen_test_layout_k4a_float3_t() {
         concat!("Alignment of ", stringify!(k4a_float3_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float3_t>())).xyz as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).xyz) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -2013,7 +1937,7 @@ fn bindgen_test_layout_k4a_float3_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_float3_t>())).v as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -2046,6 +1970,8 @@ pub struct _k4a_imu_sample_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_imu_sample_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_imu_sample_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_imu_sample_t>(),
         48usize,
@@ -2057,7 +1983,7 @@ fn bindgen_test_layout__k4a_imu_sample_t() {
         concat!("Alignment of ", stringify!(_k4a_imu_sample_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_imu_sample_t>())).temperature as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).temperature) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -2067,7 +1993,7 @@ fn bindgen_test_layout__k4a_imu_sample_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_imu_sample_t>())).acc_sample as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).acc_sample) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -2077,9 +2003,7 @@ fn bindgen_test_layout__k4a_imu_sample_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_imu_sample_t>())).acc_timestamp_usec as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).acc_timestamp_usec) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -2089,7 +2013,7 @@ fn bindgen_test_layout__k4a_imu_sample_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_imu_sample_t>())).gyro_sample as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).gyro_sample) as usize - ptr as usize },
         24usize,
         concat!(
             "Offset of field: ",
@@ -2099,9 +2023,7 @@ fn bindgen_test_layout__k4a_imu_sample_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_imu_sample_t>())).gyro_timestamp_usec as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).gyro_timestamp_usec) as usize - ptr as usize },
         40usize,
         concat!(
             "Offset of field: ",
@@ -4429,6 +4351,8 @@ pub struct _k4a_record_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_record_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_record_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_record_t>(),
         8usize,
@@ -4440,7 +4364,7 @@ fn bindgen_test_layout__k4a_record_t() {
         concat!("Alignment of ", stringify!(_k4a_record_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_record_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4458,6 +4382,8 @@ pub struct _k4a_playback_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_playback_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_playback_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_playback_t>(),
         8usize,
@@ -4469,7 +4395,7 @@ fn bindgen_test_layout__k4a_playback_t() {
         concat!("Alignment of ", stringify!(_k4a_playback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4a_playback_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4487,6 +4413,9 @@ pub struct _k4a_playback_data_block_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_playback_data_block_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_playback_data_block_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_playback_data_block_t>(),
         8usize,
@@ -4498,9 +4427,7 @@ fn bindgen_test_layout__k4a_playback_data_block_t() {
         concat!("Alignment of ", stringify!(_k4a_playback_data_block_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_playback_data_block_t>()))._rsvd as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4588,6 +4515,9 @@ pub struct _k4a_record_configuration_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_record_configuration_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_record_configuration_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_record_configuration_t>(),
         36usize,
@@ -4599,10 +4529,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         concat!("Alignment of ", stringify!(_k4a_record_configuration_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).color_format as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_format) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4612,10 +4539,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).color_resolution as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_resolution) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -4625,9 +4549,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).depth_mode as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_mode) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4637,9 +4559,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).camera_fps as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).camera_fps) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -4649,10 +4569,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).color_track_enabled as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).color_track_enabled) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -4662,10 +4579,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).depth_track_enabled as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_track_enabled) as usize - ptr as usize },
         17usize,
         concat!(
             "Offset of field: ",
@@ -4675,10 +4589,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).ir_track_enabled as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).ir_track_enabled) as usize - ptr as usize },
         18usize,
         concat!(
             "Offset of field: ",
@@ -4688,10 +4599,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).imu_track_enabled as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).imu_track_enabled) as usize - ptr as usize },
         19usize,
         concat!(
             "Offset of field: ",
@@ -4701,10 +4609,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).depth_delay_off_color_usec
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_delay_off_color_usec) as usize - ptr as usize },
         20usize,
         concat!(
             "Offset of field: ",
@@ -4714,10 +4619,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).wired_sync_mode as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).wired_sync_mode) as usize - ptr as usize },
         24usize,
         concat!(
             "Offset of field: ",
@@ -4728,8 +4630,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
     );
     assert_eq!(
         unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>()))
-                .subordinate_delay_off_master_usec as *const _ as usize
+            ::std::ptr::addr_of!((*ptr).subordinate_delay_off_master_usec) as usize - ptr as usize
         },
         28usize,
         concat!(
@@ -4740,10 +4641,7 @@ fn bindgen_test_layout__k4a_record_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_configuration_t>())).start_timestamp_offset_usec
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).start_timestamp_offset_usec) as usize - ptr as usize },
         32usize,
         concat!(
             "Offset of field: ",
@@ -4783,6 +4681,9 @@ pub struct _k4a_record_video_settings_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_record_video_settings_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_record_video_settings_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_record_video_settings_t>(),
         24usize,
@@ -4794,9 +4695,7 @@ fn bindgen_test_layout__k4a_record_video_settings_t() {
         concat!("Alignment of ", stringify!(_k4a_record_video_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_video_settings_t>())).width as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4806,9 +4705,7 @@ fn bindgen_test_layout__k4a_record_video_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_video_settings_t>())).height as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4818,9 +4715,7 @@ fn bindgen_test_layout__k4a_record_video_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_video_settings_t>())).frame_rate as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_rate) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -4859,6 +4754,9 @@ pub struct _k4a_record_subtitle_settings_t {
 }
 #[test]
 fn bindgen_test_layout__k4a_record_subtitle_settings_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4a_record_subtitle_settings_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4a_record_subtitle_settings_t>(),
         1usize,
@@ -4870,10 +4768,7 @@ fn bindgen_test_layout__k4a_record_subtitle_settings_t() {
         concat!("Alignment of ", stringify!(_k4a_record_subtitle_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4a_record_subtitle_settings_t>())).high_freq_data as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).high_freq_data) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5357,6 +5252,8 @@ pub struct _k4abt_tracker_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_tracker_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_tracker_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_tracker_t>(),
         8usize,
@@ -5368,7 +5265,7 @@ fn bindgen_test_layout__k4abt_tracker_t() {
         concat!("Alignment of ", stringify!(_k4abt_tracker_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_tracker_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5386,6 +5283,8 @@ pub struct _k4abt_frame_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_frame_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_frame_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_frame_t>(),
         8usize,
@@ -5397,7 +5296,7 @@ fn bindgen_test_layout__k4abt_frame_t() {
         concat!("Alignment of ", stringify!(_k4abt_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_frame_t>()))._rsvd as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr)._rsvd) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5512,6 +5411,9 @@ pub struct _k4abt_tracker_configuration_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_tracker_configuration_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_tracker_configuration_t> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_tracker_configuration_t>(),
         24usize,
@@ -5523,10 +5425,7 @@ fn bindgen_test_layout__k4abt_tracker_configuration_t() {
         concat!("Alignment of ", stringify!(_k4abt_tracker_configuration_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4abt_tracker_configuration_t>())).sensor_orientation
-                as *const _ as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).sensor_orientation) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5536,10 +5435,7 @@ fn bindgen_test_layout__k4abt_tracker_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4abt_tracker_configuration_t>())).processing_mode as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).processing_mode) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -5549,10 +5445,7 @@ fn bindgen_test_layout__k4abt_tracker_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4abt_tracker_configuration_t>())).gpu_device_id as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).gpu_device_id) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -5562,10 +5455,7 @@ fn bindgen_test_layout__k4abt_tracker_configuration_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_k4abt_tracker_configuration_t>())).model_path as *const _
-                as usize
-        },
+        unsafe { ::std::ptr::addr_of!((*ptr).model_path) as usize - ptr as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -5604,6 +5494,9 @@ pub struct k4a_quaternion_t__wxyz {
 }
 #[test]
 fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_quaternion_t__wxyz> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_quaternion_t__wxyz>(),
         16usize,
@@ -5615,7 +5508,7 @@ fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
         concat!("Alignment of ", stringify!(k4a_quaternion_t__wxyz))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t__wxyz>())).w as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5625,7 +5518,7 @@ fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t__wxyz>())).x as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -5635,7 +5528,7 @@ fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t__wxyz>())).y as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
         8usize,
         concat!(
             "Offset of field: ",
@@ -5645,7 +5538,7 @@ fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t__wxyz>())).z as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -5657,6 +5550,8 @@ fn bindgen_test_layout_k4a_quaternion_t__wxyz() {
 }
 #[test]
 fn bindgen_test_layout_k4a_quaternion_t() {
+    const UNINIT: ::std::mem::MaybeUninit<k4a_quaternion_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<k4a_quaternion_t>(),
         16usize,
@@ -5668,7 +5563,7 @@ fn bindgen_test_layout_k4a_quaternion_t() {
         concat!("Alignment of ", stringify!(k4a_quaternion_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t>())).wxyz as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).wxyz) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5678,7 +5573,7 @@ fn bindgen_test_layout_k4a_quaternion_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<k4a_quaternion_t>())).v as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5723,6 +5618,8 @@ pub struct _k4abt_joint_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_joint_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_joint_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_joint_t>(),
         32usize,
@@ -5734,7 +5631,7 @@ fn bindgen_test_layout__k4abt_joint_t() {
         concat!("Alignment of ", stringify!(_k4abt_joint_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_joint_t>())).position as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).position) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5744,7 +5641,7 @@ fn bindgen_test_layout__k4abt_joint_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_joint_t>())).orientation as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).orientation) as usize - ptr as usize },
         12usize,
         concat!(
             "Offset of field: ",
@@ -5754,7 +5651,7 @@ fn bindgen_test_layout__k4abt_joint_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_joint_t>())).confidence_level as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).confidence_level) as usize - ptr as usize },
         28usize,
         concat!(
             "Offset of field: ",
@@ -5778,6 +5675,8 @@ pub struct _k4abt_skeleton_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_skeleton_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_skeleton_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_skeleton_t>(),
         1024usize,
@@ -5789,7 +5688,7 @@ fn bindgen_test_layout__k4abt_skeleton_t() {
         concat!("Alignment of ", stringify!(_k4abt_skeleton_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_skeleton_t>())).joints as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).joints) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5812,6 +5711,8 @@ pub struct _k4abt_body_t {
 }
 #[test]
 fn bindgen_test_layout__k4abt_body_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_k4abt_body_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
     assert_eq!(
         ::std::mem::size_of::<_k4abt_body_t>(),
         1028usize,
@@ -5823,7 +5724,7 @@ fn bindgen_test_layout__k4abt_body_t() {
         concat!("Alignment of ", stringify!(_k4abt_body_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_body_t>())).id as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5833,7 +5734,7 @@ fn bindgen_test_layout__k4abt_body_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_k4abt_body_t>())).skeleton as *const _ as usize },
+        unsafe { ::std::ptr::addr_of!((*ptr).skeleton) as usize - ptr as usize },
         4usize,
         concat!(
             "Offset of field: ",
@@ -21,14 +21,16 @@ impl Calibration {
         target_color_resolution: k4a_color_resolution_t,
     ) -> Result<Calibration, Error> {
         let mut calibration = k4a_calibration_t::default();
-        Error::from((api.k4a_calibration_get_from_raw)(
-            raw_calibration.as_ptr() as *mut i8,
-            raw_calibration.len(),
-            target_depth_mode,
-            target_color_resolution,
-            &mut calibration,
-        ))
-        .to_result_fn(|| Calibration::from_handle(api, calibration))
+        Error::check("k4a_calibration_get_from_raw", unsafe {
+            (api.k4a_calibration_get_from_raw)(
+                raw_calibration.as_ptr() as *mut ::std::os::raw::c_char,
+                raw_calibration.len(),
+                target_depth_mode,
+                target_color_resolution,
+                &mut calibration,
+            )
+        })
+        .map(|()| Calibration::from_handle(api, calibration))
     }
 
     /// Transform a 3d point of a source coordinate system into a 3d point of the target coordinate system.
@@ -39,14 +41,16 @@ impl Calibration {
         target_camera: k4a_calibration_type_t,
     ) -> Result<k4a_float3_t, Error> {
         let mut target_point3d = k4a_float3_t::default();
-        Error::from((self.api.k4a_calibration_3d_to_3d)(
-            &self.calibration,
-            source_point3d,
-            source_camera,
-            target_camera,
-            &mut target_point3d,
-        ))
-        .to_result(target_point3d)
+        Error::check("k4a_calibration_3d_to_3d", unsafe {
+            (self.api.k4a_calibration_3d_to_3d)(
+                &self.calibration,
+                source_point3d,
+                source_camera,
+                target_camera,
+                &mut target_point3d,
+            )
+        })
+        .map(|()| target_point3d)
     }
 
     /// Transform a 2d pixel coordinate with an associated depth value of the source camera into a 3d point of the target coordinate system.
@@ -60,16 +64,18 @@ impl Calibration {
     ) -> Result<(k4a_float3_t, bool), Error> {
         let mut target_point3d = k4a_float3_t::default();
         let mut valid: i32 = 0;
-        Error::from((self.api.k4a_calibration_2d_to_3d)(
-            &self.calibration,
-            source_point2d,
-            source_depth,
-            source_camera,
-            target_camera,
-            &mut target_point3d,
-            &mut valid,
-        ))
-        .to_result((target_point3d, valid != 0))
+        Error::check("k4a_calibration_2d_to_3d", unsafe {
+            (self.api.k4a_calibration_2d_to_3d)(
+                &self.calibration,
+                source_point2d,
+                source_depth,
+                source_camera,
+                target_camera,
+                &mut target_point3d,
+                &mut valid,
+            )
+        })
+        .map(|()| (target_point3d, valid != 0))
     }
 
     /// Transform a 3d point of a source coordinate system into a 2d pixel coordinate of the target camera.
@@ -82,15 +88,17 @@ impl Calibration {
     ) -> Result<(k4a_float2_t, bool), Error> {
         let mut target_point2d = k4a_float2_t::default();
         let mut valid: i32 = 0;
-        Error::from((self.api.k4a_calibration_3d_to_2d)(
-            &self.calibration,
-            source_point3d,
-            source_camera,
-            target_camera,
-            &mut target_point2d,
-            &mut valid,
-        ))
-        .to_result((target_point2d, valid != 0))
+        Error::check("k4a_calibration_3d_to_2d", unsafe {
+            (self.api.k4a_calibration_3d_to_2d)(
+                &self.calibration,
+                source_point3d,
+                source_camera,
+                target_camera,
+                &mut target_point2d,
+                &mut valid,
+            )
+        })
+        .map(|()| (target_point2d, valid != 0))
     }
 
     /// Transform a 2d pixel coordinate with an associated depth value of the source camera into a 2d pixel coordinate of the target camera
@@ -104,16 +112,18 @@ impl Calibration {
     ) -> Result<(k4a_float2_t, bool), Error> {
         let mut target_point2d = k4a_float2_t::default();
         let mut valid: i32 = 0;
-        Error::from((self.api.k4a_calibration_2d_to_2d)(
-            &self.calibration,
-            source_point2d,
-            source_depth,
-            source_camera,
-            target_camera,
-            &mut target_point2d,
-            &mut valid,
-        ))
-        .to_result((target_point2d, valid != 0))
+        Error::check("k4a_calibration_2d_to_2d", unsafe {
+            (self.api.k4a_calibration_2d_to_2d)(
+                &self.calibration,
+                source_point2d,
+                source_depth,
+                source_camera,
+                target_camera,
+                &mut target_point2d,
+                &mut valid,
+            )
+        })
+        .map(|()| (target_point2d, valid != 0))
     }
 
     /// Transform a 2D pixel coordinate from color camera into a 2D pixel coordinate of the depth camera. This function
@@ -126,13 +136,15 @@ impl Calibration {
     ) -> Result<(k4a_float2_t, bool), Error> {
         let mut target_point2d = k4a_float2_t::default();
         let mut valid: i32 = 0;
-        Error::from((self.api.k4a_calibration_color_2d_to_depth_2d)(
-            &self.calibration,
-            source_point2d,
-            depth_image.handle,
-            &mut target_point2d,
-            &mut valid,
-        ))
-        .to_result((target_point2d, valid != 0))
+        Error::check("k4a_calibration_color_2d_to_depth_2d", unsafe {
+            (self.api.k4a_calibration_color_2d_to_depth_2d)(
+                &self.calibration,
+                source_point2d,
+                depth_image.handle,
+                &mut target_point2d,
+                &mut valid,
+            )
+        })
+        .map(|()| (target_point2d, valid != 0))
     }
 }
@@ -2,16 +2,21 @@ use super::*;
 use std::ptr;
 use std::sync::Arc;
 
+// Captures are reference counted by the SDK, which releases them from any thread.
+unsafe impl Send for Capture {}
+
 pub struct Capture {
-    api: Arc<Api>,
-    pub handle: k4a_capture_t,
+    pub(crate) api: Arc<Api>,
+    pub(crate) handle: k4a_capture_t,
 }
 
 impl Capture {
     pub fn new(api: Arc<Api>) -> Result<Capture, Error> {
         let mut handle: k4a_capture_t = ptr::null_mut();
-        Error::from((api.k4a_capture_create)(&mut handle))
-            .to_result_fn(|| Capture::from_handle(api, handle))
+        Error::check("k4a_capture_create", unsafe {
+            (api.k4a_capture_create)(&mut handle)
+        })
+        .map(|()| Capture::from_handle(api, handle))
     }
 
     pub(crate) fn from_handle(api: Arc<Api>, handle: k4a_capture_t) -> Capture {
@@ -23,64 +28,75 @@ impl Capture {
 
     /// Get the color image associated with the capture
     pub fn get_color_image(&self) -> Image {
-        Image::from_handle(
-            self.api.clone(),
-            (self.api.k4a_capture_get_color_image)(self.handle),
-        )
+        Image::from_handle(self.api.clone(), unsafe {
+            (self.api.k4a_capture_get_color_image)(self.handle)
+        })
     }
 
     /// Get the depth image associated with the capture
     pub fn get_depth_image(&self) -> Image {
-        Image::from_handle(
-            self.api.clone(),
-            (self.api.k4a_capture_get_depth_image)(self.handle),
-        )
+        Image::from_handle(self.api.clone(), unsafe {
+            (self.api.k4a_capture_get_depth_image)(self.handle)
+        })
     }
 
     /// Get the IR image associated with the capture
     pub fn get_ir_image(&self) -> Image {
-        Image::from_handle(
-            self.api.clone(),
-            (self.api.k4a_capture_get_ir_image)(self.handle),
-        )
+        Image::from_handle(self.api.clone(), unsafe {
+            (self.api.k4a_capture_get_ir_image)(self.handle)
+        })
     }
 
     /// Set / add a color image to the capture
     pub fn set_color_image(&mut self, color_image: Image) {
-        (self.api.k4a_capture_set_color_image)(self.handle, color_image.handle)
+        assert_same_backend(&self.api, &color_image.api);
+        color_image.mark_shared();
+        unsafe { (self.api.k4a_capture_set_color_image)(self.handle, color_image.handle) }
     }
 
     /// Set / add a depth image to the capture
     pub fn set_depth_image(&mut self, depth_image: Image) {
-        (self.api.k4a_capture_set_depth_image)(self.handle, depth_image.handle)
+        assert_same_backend(&self.api, &depth_image.api);
+        depth_image.mark_shared();
+        unsafe { (self.api.k4a_capture_set_depth_image)(self.handle, depth_image.handle) }
     }
 
     /// Set / add an IR image to the capture
     pub fn set_ir_image(&mut self, ir_image: Image) {
-        (self.api.k4a_capture_set_ir_image)(self.handle, ir_image.handle)
+        assert_same_backend(&self.api, &ir_image.api);
+        ir_image.mark_shared();
+        unsafe { (self.api.k4a_capture_set_ir_image)(self.handle, ir_image.handle) }
     }
 
     /// Set the temperature associated with the capture in Celsius.
     pub fn set_temperature_c(&mut self, temperature_c: f32) {
-        (self.api.k4a_capture_set_temperature_c)(self.handle, temperature_c)
+        unsafe { (self.api.k4a_capture_set_temperature_c)(self.handle, temperature_c) }
     }
 
     /// Get temperature (in Celsius) associated with the capture.
     pub fn get_temperature_c(&self) -> f32 {
-        (self.api.k4a_capture_get_temperature_c)(self.handle)
+        unsafe { (self.api.k4a_capture_get_temperature_c)(self.handle) }
     }
 }
 
+fn assert_same_backend(capture_api: &Api, image_api: &Api) {
+    assert_eq!(
+        capture_api.is_simulated(),
+        image_api.is_simulated(),
+        "images from the simulated backend cannot be added to SDK captures, or the other way round"
+    );
+}
+
 impl Drop for Capture {
     fn drop(&mut self) {
-        (self.api.k4a_capture_release)(self.handle);
+        unsafe { (self.api.k4a_capture_release)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
 
 impl Clone for Capture {
     fn clone(&self) -> Self {
-        (self.api.k4a_capture_reference)(self.handle);
+        unsafe { (self.api.k4a_capture_reference)(self.handle) };
         Capture::from_handle(self.api.clone(), self.handle)
     }
 }
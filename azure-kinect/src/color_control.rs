@@ -0,0 +1,340 @@
+use super::*;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Whether the color camera sets a control itself, or uses the given value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ControlMode<T> {
+    Auto,
+    Manual(T),
+}
+
+/// Frequency of the mains power, to avoid flicker under artificial light.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerlineFrequency {
+    Hz50,
+    Hz60,
+}
+
+/// A color camera control together with its setting.
+///
+/// Controls without a unit take the raw values reported by
+/// [`Device::get_color_control_capabilities`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorControl {
+    /// Exposure time, with microsecond precision.
+    Exposure(ControlMode<Duration>),
+    /// Deprecated, the SDK ignores it.
+    AutoExposurePriority(bool),
+    Brightness(i32),
+    Contrast(i32),
+    Saturation(i32),
+    Sharpness(i32),
+    /// White balance in Kelvin.
+    WhiteBalance(ControlMode<u32>),
+    BacklightCompensation(bool),
+    Gain(i32),
+    PowerlineFrequency(PowerlineFrequency),
+}
+
+impl ColorControl {
+    /// The SDK command for this control.
+    pub fn command(&self) -> k4a_color_control_command_t {
+        use k4a_color_control_command_t::*;
+        match self {
+            ColorControl::Exposure(_) => K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE,
+            ColorControl::AutoExposurePriority(_) => K4A_COLOR_CONTROL_AUTO_EXPOSURE_PRIORITY,
+            ColorControl::Brightness(_) => K4A_COLOR_CONTROL_BRIGHTNESS,
+            ColorControl::Contrast(_) => K4A_COLOR_CONTROL_CONTRAST,
+            ColorControl::Saturation(_) => K4A_COLOR_CONTROL_SATURATION,
+            ColorControl::Sharpness(_) => K4A_COLOR_CONTROL_SHARPNESS,
+            ColorControl::WhiteBalance(_) => K4A_COLOR_CONTROL_WHITEBALANCE,
+            ColorControl::BacklightCompensation(_) => K4A_COLOR_CONTROL_BACKLIGHT_COMPENSATION,
+            ColorControl::Gain(_) => K4A_COLOR_CONTROL_GAIN,
+            ColorControl::PowerlineFrequency(_) => K4A_COLOR_CONTROL_POWERLINE_FREQUENCY,
+        }
+    }
+
+    /// The mode and value passed to `k4a_device_set_color_control`.
+    pub fn to_raw(&self) -> (k4a_color_control_mode_t, i32) {
+        fn manual(value: i32) -> (k4a_color_control_mode_t, i32) {
+            (
+                k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL,
+                value,
+            )
+        }
+        fn with_mode<T>(
+            mode: &ControlMode<T>,
+            value: impl Fn(&T) -> i32,
+        ) -> (k4a_color_control_mode_t, i32) {
+            match mode {
+                ControlMode::Auto => (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO, 0),
+                ControlMode::Manual(v) => manual(value(v)),
+            }
+        }
+        match *self {
+            ColorControl::Exposure(ref mode) => with_mode(mode, |exposure| {
+                // Out of range values are rejected by the capability check.
+                i32::try_from(exposure.as_micros()).unwrap_or(i32::MAX)
+            }),
+            ColorControl::WhiteBalance(ref mode) => {
+                with_mode(mode, |kelvin| i32::try_from(*kelvin).unwrap_or(i32::MAX))
+            }
+            ColorControl::AutoExposurePriority(on) | ColorControl::BacklightCompensation(on) => {
+                manual(on as i32)
+            }
+            ColorControl::Brightness(value)
+            | ColorControl::Contrast(value)
+            | ColorControl::Saturation(value)
+            | ColorControl::Sharpness(value)
+            | ColorControl::Gain(value) => manual(value),
+            ColorControl::PowerlineFrequency(PowerlineFrequency::Hz50) => manual(1),
+            ColorControl::PowerlineFrequency(PowerlineFrequency::Hz60) => manual(2),
+        }
+    }
+
+    /// Interprets a mode and value read with `k4a_device_get_color_control`. The value of a
+    /// control in automatic mode is not kept.
+    pub fn from_raw(
+        command: k4a_color_control_command_t,
+        mode: k4a_color_control_mode_t,
+        value: i32,
+    ) -> Result<ColorControl, Error> {
+        use k4a_color_control_command_t::*;
+        fn with_mode<T>(mode: k4a_color_control_mode_t, value: T) -> ControlMode<T> {
+            match mode {
+                k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO => ControlMode::Auto,
+                k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL => {
+                    ControlMode::Manual(value)
+                }
+            }
+        }
+        let unsigned = |value: i32| {
+            u32::try_from(value).map_err(|_| {
+                Error::new(ErrorKind::InvalidColorControl(format!(
+                    "{} is not a valid value for {:?}",
+                    value, command
+                )))
+            })
+        };
+        Ok(match command {
+            K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE => ColorControl::Exposure(with_mode(
+                mode,
+                Duration::from_micros(unsigned(value)? as u64),
+            )),
+            K4A_COLOR_CONTROL_AUTO_EXPOSURE_PRIORITY => {
+                ColorControl::AutoExposurePriority(value != 0)
+            }
+            K4A_COLOR_CONTROL_BRIGHTNESS => ColorControl::Brightness(value),
+            K4A_COLOR_CONTROL_CONTRAST => ColorControl::Contrast(value),
+            K4A_COLOR_CONTROL_SATURATION => ColorControl::Saturation(value),
+            K4A_COLOR_CONTROL_SHARPNESS => ColorControl::Sharpness(value),
+            K4A_COLOR_CONTROL_WHITEBALANCE => {
+                ColorControl::WhiteBalance(with_mode(mode, unsigned(value)?))
+            }
+            K4A_COLOR_CONTROL_BACKLIGHT_COMPENSATION => {
+                ColorControl::BacklightCompensation(value != 0)
+            }
+            K4A_COLOR_CONTROL_GAIN => ColorControl::Gain(value),
+            K4A_COLOR_CONTROL_POWERLINE_FREQUENCY => match value {
+                1 => ColorControl::PowerlineFrequency(PowerlineFrequency::Hz50),
+                2 => ColorControl::PowerlineFrequency(PowerlineFrequency::Hz60),
+                _ => {
+                    return Err(Error::new(ErrorKind::InvalidColorControl(format!(
+                        "{} is not a valid value for {:?}",
+                        value, command
+                    ))))
+                }
+            },
+        })
+    }
+}
+
+/// The range of values a color control accepts, in the raw units of the SDK.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ColorControlCapabilities {
+    pub supports_auto: bool,
+    pub min_value: i32,
+    pub max_value: i32,
+    pub step_value: i32,
+    pub default_value: i32,
+    pub default_mode: k4a_color_control_mode_t,
+}
+
+impl ColorControlCapabilities {
+    /// Checks that the control accepts the setting.
+    pub fn check(&self, control: &ColorControl) -> Result<(), Error> {
+        let invalid = |message| Err(Error::new(ErrorKind::InvalidColorControl(message)));
+        match control.to_raw() {
+            (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO, _) => {
+                if !self.supports_auto {
+                    return invalid(format!(
+                        "{:?} does not support automatic mode",
+                        control.command()
+                    ));
+                }
+            }
+            (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL, value) => {
+                let step = self.step_value.max(1) as i64;
+                let (value, min, max) =
+                    (value as i64, self.min_value as i64, self.max_value as i64);
+                if value < min || value > max || (value - min) % step != 0 {
+                    return invalid(format!(
+                        "{:?} is not a valid setting ({}..={} step {})",
+                        control, self.min_value, self.max_value, self.step_value
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The settings of every color control of a device, to restore them after a reboot or to store
+/// them in a recording.
+///
+/// The text form has one `NAME=value` pair per control, separated by `;`. Values are `auto` or the
+/// raw value of the control, so it fits in a recording tag:
+///
+/// ```no_run
+/// # use azure_kinect::*;
+/// # fn save(device: &Device, record: &record::Record) -> Result<(), Error> {
+/// let profile = ColorControlProfile::read(device)?;
+/// record.add_tag(ColorControlProfile::TAG_NAME, &profile.to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorControlProfile {
+    pub controls: Vec<ColorControl>,
+}
+
+impl ColorControlProfile {
+    /// Name of the recording tag that holds a profile.
+    pub const TAG_NAME: &'static str = "CUSTOM_COLOR_CONTROL_PROFILE";
+    /// Name of the recording attachment that holds a profile.
+    pub const ATTACHMENT_NAME: &'static str = "color_control_profile.txt";
+
+    /// The controls in a profile, in the order they are applied. The deprecated
+    /// `K4A_COLOR_CONTROL_AUTO_EXPOSURE_PRIORITY` is left out.
+    pub const COMMANDS: [k4a_color_control_command_t; 9] = [
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_POWERLINE_FREQUENCY,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_GAIN,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_WHITEBALANCE,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_BRIGHTNESS,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_CONTRAST,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_SATURATION,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_SHARPNESS,
+        k4a_color_control_command_t::K4A_COLOR_CONTROL_BACKLIGHT_COMPENSATION,
+    ];
+
+    /// Reads every control in [`ColorControlProfile::COMMANDS`] from the device.
+    pub fn read(device: &Device) -> Result<ColorControlProfile, Error> {
+        Self::COMMANDS
+            .iter()
+            .map(|&command| device.get_color_control(command))
+            .collect::<Result<_, _>>()
+            .map(|controls| ColorControlProfile { controls })
+    }
+
+    /// Sets every control of the profile on the device, and returns the controls that failed.
+    pub fn apply(&self, device: &Device) -> Vec<(ColorControl, Error)> {
+        self.controls
+            .iter()
+            .filter_map(|&control| {
+                device
+                    .set_color_control(control)
+                    .err()
+                    .map(|error| (control, error))
+            })
+            .collect()
+    }
+
+    /// Reads a profile stored in the tag or the attachment of a recording.
+    pub fn from_playback(playback: &playback::Playback) -> Result<ColorControlProfile, Error> {
+        let text = match playback.get_tag(Self::TAG_NAME) {
+            Ok(text) => text,
+            Err(_) => {
+                let data = playback.get_attachment(Self::ATTACHMENT_NAME)?;
+                String::from_utf8_lossy(&data).into_owned()
+            }
+        };
+        text.parse().map_err(|e: Error| e.with_path(&playback.path))
+    }
+}
+
+fn control_name(command: k4a_color_control_command_t) -> &'static str {
+    use k4a_color_control_command_t::*;
+    match command {
+        K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE => "EXPOSURE_TIME_ABSOLUTE",
+        K4A_COLOR_CONTROL_AUTO_EXPOSURE_PRIORITY => "AUTO_EXPOSURE_PRIORITY",
+        K4A_COLOR_CONTROL_BRIGHTNESS => "BRIGHTNESS",
+        K4A_COLOR_CONTROL_CONTRAST => "CONTRAST",
+        K4A_COLOR_CONTROL_SATURATION => "SATURATION",
+        K4A_COLOR_CONTROL_SHARPNESS => "SHARPNESS",
+        K4A_COLOR_CONTROL_WHITEBALANCE => "WHITEBALANCE",
+        K4A_COLOR_CONTROL_BACKLIGHT_COMPENSATION => "BACKLIGHT_COMPENSATION",
+        K4A_COLOR_CONTROL_GAIN => "GAIN",
+        K4A_COLOR_CONTROL_POWERLINE_FREQUENCY => "POWERLINE_FREQUENCY",
+    }
+}
+
+impl fmt::Display for ColorControlProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, control) in self.controls.iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{}=", control_name(control.command()))?;
+            match control.to_raw() {
+                (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO, _) => write!(f, "auto")?,
+                (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL, value) => {
+                    write!(f, "{}", value)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ColorControlProfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ColorControlProfile, Error> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidColorControl(message));
+        let mut controls = Vec::new();
+        for pair in s.split(';').map(str::trim).filter(|pair| !pair.is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("{:?} is not a NAME=value pair", pair)))?;
+            let command = Self::COMMANDS
+                .iter()
+                .chain(&[k4a_color_control_command_t::K4A_COLOR_CONTROL_AUTO_EXPOSURE_PRIORITY])
+                .copied()
+                .find(|&command| control_name(command) == name.trim())
+                .ok_or_else(|| invalid(format!("{:?} is not a color control", name)))?;
+            let (mode, value) = match value.trim() {
+                "auto" => (k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO, 0),
+                value => (
+                    k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL,
+                    value.parse().map_err(|_| {
+                        invalid(format!("{:?} is not a valid value for {}", value, name))
+                    })?,
+                ),
+            };
+            let control = ColorControl::from_raw(command, mode, value)?;
+            if control.to_raw().0 != mode {
+                return Err(invalid(format!("{} has no automatic mode", name)));
+            }
+            controls.push(control);
+        }
+        Ok(ColorControlProfile { controls })
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+
+/// Builds a [`k4a_device_configuration_t`] and checks it against the rules the SDK enforces in
+/// `k4a_device_start_cameras`, so a bad combination is reported before the device is touched.
+///
+/// Everything starts disabled, like `K4A_DEVICE_CONFIG_INIT_DISABLE_ALL`:
+///
+/// ```no_run
+/// # use azure_kinect::*;
+/// let config = DeviceConfiguration::new()
+///     .color(
+///         k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
+///         k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+///     )
+///     .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED)
+///     .fps(k4a_fps_t::K4A_FRAMES_PER_SECOND_30)
+///     .synchronized_images_only(true)
+///     .build()?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DeviceConfiguration {
+    config: k4a_device_configuration_t,
+}
+
+impl DeviceConfiguration {
+    pub fn new() -> DeviceConfiguration {
+        DeviceConfiguration {
+            config: k4a_device_configuration_t {
+                color_format: k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
+                color_resolution: k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF,
+                depth_mode: k4a_depth_mode_t::K4A_DEPTH_MODE_OFF,
+                camera_fps: k4a_fps_t::K4A_FRAMES_PER_SECOND_30,
+                synchronized_images_only: false,
+                depth_delay_off_color_usec: 0,
+                wired_sync_mode: k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_STANDALONE,
+                subordinate_delay_off_master_usec: 0,
+                disable_streaming_indicator: false,
+            },
+        }
+    }
+
+    /// Enables the color camera. NV12 and YUY2 are only available at 720P.
+    pub fn color(mut self, format: k4a_image_format_t, resolution: k4a_color_resolution_t) -> Self {
+        self.config.color_format = format;
+        self.config.color_resolution = resolution;
+        self
+    }
+
+    /// Enables the depth camera, or the IR camera alone with `K4A_DEPTH_MODE_PASSIVE_IR`.
+    pub fn depth(mut self, mode: k4a_depth_mode_t) -> Self {
+        self.config.depth_mode = mode;
+        self
+    }
+
+    /// Frame rate of both cameras. 30 fps is not available with `K4A_DEPTH_MODE_WFOV_UNBINNED`
+    /// or `K4A_COLOR_RESOLUTION_3072P`.
+    pub fn fps(mut self, fps: k4a_fps_t) -> Self {
+        self.config.camera_fps = fps;
+        self
+    }
+
+    /// Only return captures that contain both a color and a depth image.
+    pub fn synchronized_images_only(mut self, synchronized_images_only: bool) -> Self {
+        self.config.synchronized_images_only = synchronized_images_only;
+        self
+    }
+
+    /// Delay of the depth frames relative to the color frames, at most one frame period either
+    /// way.
+    pub fn depth_delay_off_color_usec(mut self, delay_usec: i32) -> Self {
+        self.config.depth_delay_off_color_usec = delay_usec;
+        self
+    }
+
+    /// Drives the sync out jack. The color camera must be enabled on the master.
+    pub fn master(mut self) -> Self {
+        self.config.wired_sync_mode = k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_MASTER;
+        self.config.subordinate_delay_off_master_usec = 0;
+        self
+    }
+
+    /// Follows the sync in jack, capturing `delay_usec` after the master, at most one frame
+    /// period.
+    pub fn subordinate(mut self, delay_usec: u32) -> Self {
+        self.config.wired_sync_mode = k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE;
+        self.config.subordinate_delay_off_master_usec = delay_usec;
+        self
+    }
+
+    /// Ignores the sync jacks. This is the default.
+    pub fn standalone(mut self) -> Self {
+        self.config.wired_sync_mode = k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_STANDALONE;
+        self.config.subordinate_delay_off_master_usec = 0;
+        self
+    }
+
+    /// Turns off the LED while streaming.
+    pub fn disable_streaming_indicator(mut self, disable: bool) -> Self {
+        self.config.disable_streaming_indicator = disable;
+        self
+    }
+
+    /// Checks the configuration and returns it in the form the SDK takes.
+    pub fn build(&self) -> Result<k4a_device_configuration_t, Error> {
+        self.validate()
+            .map(|()| self.config)
+            .map_err(|message| Error::new(ErrorKind::InvalidConfiguration(message)))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        use k4a_color_resolution_t::*;
+        use k4a_depth_mode_t::*;
+        use k4a_image_format_t::*;
+        let config = &self.config;
+        let color_enabled = config.color_resolution != K4A_COLOR_RESOLUTION_OFF;
+        let depth_enabled = config.depth_mode != K4A_DEPTH_MODE_OFF;
+        let period_usec = 1_000_000 / config.camera_fps.get_u32() as i64;
+        if !color_enabled && !depth_enabled {
+            return Err("at least one of the color or depth cameras must be enabled".into());
+        }
+        if config.camera_fps == k4a_fps_t::K4A_FRAMES_PER_SECOND_30 {
+            if config.depth_mode == K4A_DEPTH_MODE_WFOV_UNBINNED {
+                return Err("30 fps is not supported with K4A_DEPTH_MODE_WFOV_UNBINNED".into());
+            }
+            if config.color_resolution == K4A_COLOR_RESOLUTION_3072P {
+                return Err("30 fps is not supported with K4A_COLOR_RESOLUTION_3072P".into());
+            }
+        }
+        if color_enabled {
+            match config.color_format {
+                K4A_IMAGE_FORMAT_COLOR_MJPG | K4A_IMAGE_FORMAT_COLOR_BGRA32 => {}
+                K4A_IMAGE_FORMAT_COLOR_NV12 | K4A_IMAGE_FORMAT_COLOR_YUY2 => {
+                    if config.color_resolution != K4A_COLOR_RESOLUTION_720P {
+                        return Err(format!(
+                            "{:?} is only supported at K4A_COLOR_RESOLUTION_720P, not {:?}",
+                            config.color_format, config.color_resolution
+                        ));
+                    }
+                }
+                format => return Err(format!("{:?} is not a color camera format", format)),
+            }
+        }
+        if config.synchronized_images_only && !(color_enabled && depth_enabled) {
+            return Err(
+                "synchronized_images_only requires both the color and depth cameras".into(),
+            );
+        }
+        if (config.depth_delay_off_color_usec as i64).abs() > period_usec {
+            return Err(format!(
+                "depth_delay_off_color_usec of {} must not exceed the frame period of {} us",
+                config.depth_delay_off_color_usec, period_usec
+            ));
+        }
+        match config.wired_sync_mode {
+            k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE => {
+                if config.subordinate_delay_off_master_usec as i64 > period_usec {
+                    return Err(format!(
+                        "subordinate_delay_off_master_usec of {} must not exceed the frame \
+                         period of {} us",
+                        config.subordinate_delay_off_master_usec, period_usec
+                    ));
+                }
+            }
+            mode => {
+                if config.subordinate_delay_off_master_usec != 0 {
+                    return Err(
+                        "subordinate_delay_off_master_usec requires subordinate mode".into(),
+                    );
+                }
+                if mode == k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_MASTER && !color_enabled {
+                    return Err("the color camera must be enabled on the master".into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for DeviceConfiguration {
+    fn default() -> Self {
+        DeviceConfiguration::new()
+    }
+}
+
+/// Starts from an existing configuration, to check it with [`DeviceConfiguration::build`].
+impl From<k4a_device_configuration_t> for DeviceConfiguration {
+    fn from(config: k4a_device_configuration_t) -> Self {
+        DeviceConfiguration { config }
+    }
+}
+
+impl std::convert::TryFrom<DeviceConfiguration> for k4a_device_configuration_t {
+    type Error = Error;
+
+    fn try_from(config: DeviceConfiguration) -> Result<Self, Error> {
+        config.build()
+    }
+}
@@ -0,0 +1,217 @@
+//! Conversion of MJPG, NV12 and YUY2 color images to BGRA32, for [`Image::convert_to`].
+
+use super::*;
+use std::slice;
+use std::thread;
+
+/// The pixels of a color image, ready to be converted row by row.
+enum Source<'a> {
+    /// A plane of luma, then a plane of interleaved U and V at half the resolution.
+    Nv12 {
+        luma: &'a [u8],
+        chroma: &'a [u8],
+        stride: usize,
+    },
+    /// Y0, U, Y1 and V for each pair of pixels.
+    Yuy2 { data: &'a [u8], stride: usize },
+    /// Decoded MJPG, in RGB.
+    #[cfg(feature = "mjpg")]
+    Rgb24(Vec<u8>),
+    /// Decoded grayscale MJPG.
+    #[cfg(feature = "mjpg")]
+    Luma8(Vec<u8>),
+}
+
+pub(crate) fn convert(
+    image: &Image,
+    format: k4a_image_format_t,
+    threads: usize,
+) -> Result<Image, Error> {
+    use k4a_image_format_t::*;
+    let invalid = |message: String| Err(Error::new(ErrorKind::InvalidImage(message)));
+    if image.handle.is_null() {
+        return invalid("the image is missing from the capture".into());
+    }
+    let source_format = image.get_format();
+    if source_format == format {
+        return Ok(image.clone());
+    }
+    if format != K4A_IMAGE_FORMAT_COLOR_BGRA32 {
+        return invalid(format!(
+            "{:?} images cannot be converted to {:?}",
+            source_format, format
+        ));
+    }
+    let (width, height) = (
+        image.get_width_pixels().max(0) as usize,
+        image.get_height_pixels().max(0) as usize,
+    );
+    let stride = image.get_stride_bytes().max(0) as usize;
+    let bytes = match image.get_size() {
+        0 => &[],
+        size => unsafe { slice::from_raw_parts(image.get_buffer(), size) },
+    };
+    let too_small = || {
+        invalid(format!(
+            "a buffer of {} bytes is too small for a {}x{} {:?} image with a stride of {} bytes",
+            bytes.len(),
+            width,
+            height,
+            source_format,
+            stride
+        ))
+    };
+    let source = match source_format {
+        K4A_IMAGE_FORMAT_COLOR_NV12 => {
+            let luma_size = stride * height;
+            if stride < width.div_ceil(2) * 2
+                || bytes.len() < luma_size + stride * height.div_ceil(2)
+            {
+                return too_small();
+            }
+            let (luma, chroma) = bytes.split_at(luma_size);
+            Source::Nv12 {
+                luma,
+                chroma,
+                stride,
+            }
+        }
+        K4A_IMAGE_FORMAT_COLOR_YUY2 => {
+            if stride < width.div_ceil(2) * 4 || bytes.len() < stride * height {
+                return too_small();
+            }
+            Source::Yuy2 {
+                data: bytes,
+                stride,
+            }
+        }
+        K4A_IMAGE_FORMAT_COLOR_MJPG => decode_mjpg(bytes, width, height)?,
+        _ => {
+            return invalid(format!(
+                "{:?} images cannot be converted to {:?}",
+                source_format, format
+            ))
+        }
+    };
+
+    let mut converted = Image::with_format(
+        image.api.clone(),
+        K4A_IMAGE_FORMAT_COLOR_BGRA32,
+        width as i32,
+        height as i32,
+        width as i32 * 4,
+    )?;
+    {
+        let mut view = converted.as_bgra32_mut()?;
+        let mut rows: Vec<_> = view.rows_mut().enumerate().collect();
+        if threads <= 1 || rows.len() <= 1 {
+            for (y, row) in rows {
+                source.convert_row(y, row);
+            }
+        } else {
+            let (source, rows_per_thread) = (&source, rows.len() / threads + 1);
+            thread::scope(|scope| {
+                for chunk in rows.chunks_mut(rows_per_thread) {
+                    scope.spawn(move || {
+                        for (y, row) in chunk {
+                            source.convert_row(*y, row);
+                        }
+                    });
+                }
+            });
+        }
+    }
+    converted.set_device_timestamp_usec(image.get_device_timestamp_usec());
+    converted.set_system_timestamp_nsec(image.get_system_timestamp_nsec());
+    converted.set_exposure_usec(image.get_exposure_usec());
+    converted.set_white_balance(image.get_white_balance());
+    converted.set_iso_speed(image.get_iso_speed());
+    Ok(converted)
+}
+
+#[cfg(feature = "mjpg")]
+fn decode_mjpg(bytes: &[u8], width: usize, height: usize) -> Result<Source<'static>, Error> {
+    use jpeg_decoder::{Decoder, PixelFormat};
+    let invalid = |message: String| Err(Error::new(ErrorKind::InvalidImage(message)));
+    let mut decoder = Decoder::new(bytes);
+    let pixels = match decoder.decode() {
+        Ok(pixels) => pixels,
+        Err(e) => return invalid(format!("the MJPG image could not be decoded: {}", e)),
+    };
+    let info = decoder.info().unwrap();
+    if (info.width as usize, info.height as usize) != (width, height) {
+        return invalid(format!(
+            "the MJPG image is {}x{}, not {}x{}",
+            info.width, info.height, width, height
+        ));
+    }
+    match info.pixel_format {
+        PixelFormat::RGB24 => Ok(Source::Rgb24(pixels)),
+        PixelFormat::L8 => Ok(Source::Luma8(pixels)),
+        pixel_format => invalid(format!(
+            "MJPG images in {:?} cannot be converted",
+            pixel_format
+        )),
+    }
+}
+
+#[cfg(not(feature = "mjpg"))]
+fn decode_mjpg(_bytes: &[u8], _width: usize, _height: usize) -> Result<Source<'static>, Error> {
+    Err(Error::new(ErrorKind::InvalidImage(
+        "decoding MJPG images needs the `mjpg` feature".into(),
+    )))
+}
+
+impl<'a> Source<'a> {
+    fn convert_row(&self, y: usize, row: &mut [[u8; 4]]) {
+        let width = row.len();
+        match self {
+            Source::Nv12 {
+                luma,
+                chroma,
+                stride,
+            } => {
+                let luma = &luma[y * stride..][..width];
+                let chroma = &chroma[y / 2 * stride..];
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    let uv = x / 2 * 2;
+                    *pixel = yuv_to_bgra(luma[x], chroma[uv], chroma[uv + 1]);
+                }
+            }
+            Source::Yuy2 { data, stride } => {
+                let data = &data[y * stride..];
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    let pair = &data[x / 2 * 4..][..4];
+                    *pixel = yuv_to_bgra(pair[x % 2 * 2], pair[1], pair[3]);
+                }
+            }
+            #[cfg(feature = "mjpg")]
+            Source::Rgb24(pixels) => {
+                let rgb = pixels[y * width * 3..][..width * 3].chunks_exact(3);
+                for (pixel, rgb) in row.iter_mut().zip(rgb) {
+                    *pixel = [rgb[2], rgb[1], rgb[0], 255];
+                }
+            }
+            #[cfg(feature = "mjpg")]
+            Source::Luma8(pixels) => {
+                for (pixel, &l) in row.iter_mut().zip(&pixels[y * width..][..width]) {
+                    *pixel = [l, l, l, 255];
+                }
+            }
+        }
+    }
+}
+
+/// BT.601 in the studio range, with 16 to 235 for luma, as the color camera outputs it and the
+/// SDK converts it.
+fn yuv_to_bgra(y: u8, u: u8, v: u8) -> [u8; 4] {
+    let c = 298 * (y as i32 - 16);
+    let (d, e) = (u as i32 - 128, v as i32 - 128);
+    let clamp = |value: i32| ((value + 128) >> 8).clamp(0, 255) as u8;
+    [
+        clamp(c + 516 * d),
+        clamp(c - 100 * d - 208 * e),
+        clamp(c + 409 * e),
+        255,
+    ]
+}
@@ -9,119 +9,164 @@ unsafe impl Sync for Device {}
 pub struct Device {
     pub(crate) api: Arc<Api>,
     pub(crate) handle: k4a_device_t,
+    pub(crate) id: DeviceId,
 }
 
-#[derive(Copy, Clone, Default)]
-pub struct ColorControlCapabilities {
-    supports_auto: bool,
-    min_value: i32,
-    max_value: i32,
-    step_value: i32,
-    default_value: i32,
-    default_mode: k4a_color_control_mode_t,
+/// An attached device, as reported by [`Device::enumerate`].
+#[derive(Clone, Debug)]
+pub struct DeviceInfo {
+    pub index: u32,
+    pub serial: String,
+    pub version: k4a_hardware_version_t,
+    pub sync_in_connected: bool,
+    pub sync_out_connected: bool,
 }
 
 impl Device {
     /// Open a k4a device.
     pub fn new(api: Arc<Api>, index: u32) -> Result<Device, Error> {
         let mut handle: k4a_device_t = ptr::null_mut();
-        Error::from((api.k4a_device_open)(index, &mut handle)).to_result_fn(|| Self { api, handle })
-    }
-
-    /// Starts the K4A device's cameras
-    pub fn start_cameras(&self, configuration: &k4a_device_configuration_t) -> Result<(), Error> {
-        Error::from((self.api.k4a_device_start_cameras)(
-            self.handle,
-            configuration,
-        ))
-        .to_result(())
+        let id = DeviceId {
+            index,
+            serial: None,
+        };
+        Error::check("k4a_device_open", unsafe {
+            (api.k4a_device_open)(index, &mut handle)
+        })
+        .map_err(|e| e.with_device(&id))?;
+        let mut device = Self { api, handle, id };
+        device.id.serial = device.get_serialnum().ok();
+        Ok(device)
+    }
+
+    /// Opens the attached device with the given serial number.
+    pub fn open_by_serial(api: Arc<Api>, serial: &str) -> Result<Device, Error> {
+        for index in 0..api.device_get_installed_count() {
+            // Devices that are already open, here or in another process, can't be opened again.
+            if let Ok(device) = Device::new(api.clone(), index) {
+                if device.id.serial.as_deref() == Some(serial) {
+                    return Ok(device);
+                }
+            }
+        }
+        Err(Error::new(ErrorKind::DeviceNotFound(serial.to_string())))
+    }
+
+    /// Describes every attached device. Each device is opened briefly to read its serial number,
+    /// versions and sync jacks, so a device that is already open reports the error of
+    /// `k4a_device_open` instead.
+    pub fn enumerate(api: &Arc<Api>) -> Vec<Result<DeviceInfo, Error>> {
+        (0..api.device_get_installed_count())
+            .map(|index| Device::new(api.clone(), index)?.info())
+            .collect()
+    }
+
+    /// Describes this device.
+    pub fn info(&self) -> Result<DeviceInfo, Error> {
+        let (sync_in_connected, sync_out_connected) = self.is_sync_connected()?;
+        Ok(DeviceInfo {
+            index: self.id.index,
+            serial: match &self.id.serial {
+                Some(serial) => serial.clone(),
+                None => self.get_serialnum()?,
+            },
+            version: self.get_version()?,
+            sync_in_connected,
+            sync_out_connected,
+        })
     }
 
-    /// Stops the K4A device's cameras
-    pub fn stop_cameras(&self) {
-        (self.api.k4a_device_stop_cameras)(self.handle);
-        (self.api.k4a_device_stop_imu)(self.handle);
+    /// Adds the device to the error of a failed call.
+    pub(crate) fn check<S: Status>(&self, call: &'static str, status: S) -> Result<(), Error> {
+        Error::check(call, status).map_err(|e| e.with_device(&self.id))
     }
 
-    /// Reads a sensor capture into cap.  Returns true if a capture was read, false if the read timed out.
-    pub fn get_capture(&self, timeout_in_ms: i32) -> Result<Capture, Error> {
-        let mut handle: k4a_capture_t = ptr::null_mut();
-        Error::from((self.api.k4a_device_get_capture)(
-            self.handle,
-            &mut handle,
-            timeout_in_ms,
-        ))
-        .to_result_fn(|| Capture::from_handle(self.api.clone(), handle))
+    /// Starts the K4A device's cameras. They run until the returned session is dropped, and the
+    /// device can only be used through the session meanwhile.
+    pub fn start_cameras(
+        &mut self,
+        configuration: &k4a_device_configuration_t,
+    ) -> Result<CameraSession<'_>, Error> {
+        self.start_cameras_shared(configuration)
     }
 
-    /// Reads a sensor capture into cap.  Returns true if a capture was read, false if the read timed out.
-    pub fn get_capture_wait_infinite(&self) -> Result<Capture, Error> {
-        self.get_capture(K4A_WAIT_INFINITE)
+    /// Starts the cameras without borrowing the device mutably, for the owners of a shared device
+    /// that make sure they are started only once.
+    pub(crate) fn start_cameras_shared(
+        &self,
+        configuration: &k4a_device_configuration_t,
+    ) -> Result<CameraSession<'_>, Error> {
+        self.check("k4a_device_start_cameras", unsafe {
+            (self.api.k4a_device_start_cameras)(self.handle, configuration)
+        })
+        .map(|()| CameraSession::new(self))
     }
 
     /// Get the K4A device serial number
     pub fn get_serialnum(&self) -> Result<String, Error> {
-        get_k4a_string(&|serialnum, buffer| {
+        get_k4a_string("k4a_device_get_serialnum", &|serialnum, buffer| unsafe {
             (self.api.k4a_device_get_serialnum)(self.handle, serialnum, buffer)
         })
+        .map_err(|e| e.with_device(&self.id))
     }
 
     /// Get the K4A color sensor control value
     pub fn get_color_control(
         &self,
         command: k4a_color_control_command_t,
-    ) -> Result<(k4a_color_control_mode_t, i32), Error> {
+    ) -> Result<ColorControl, Error> {
         let mut mode: k4a_color_control_mode_t =
             k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO;
         let mut value: i32 = 0;
-        Error::from((self.api.k4a_device_get_color_control)(
-            self.handle,
-            command,
-            &mut mode,
-            &mut value,
-        ))
-        .to_result((mode, value))
-    }
-
-    /// Set the K4A color sensor control value
-    pub fn set_color_control(
-        &self,
-        command: k4a_color_control_command_t,
-        mode: k4a_color_control_mode_t,
-        value: i32,
-    ) -> Result<(), Error> {
-        Error::from((self.api.k4a_device_set_color_control)(
-            self.handle,
-            command,
-            mode,
-            value,
-        ))
-        .to_result(())
+        self.check("k4a_device_get_color_control", unsafe {
+            (self.api.k4a_device_get_color_control)(self.handle, command, &mut mode, &mut value)
+        })?;
+        ColorControl::from_raw(command, mode, value).map_err(|e| e.with_device(&self.id))
+    }
+
+    /// Set the K4A color sensor control value, after checking it against the capabilities of the
+    /// control.
+    pub fn set_color_control(&self, control: ColorControl) -> Result<(), Error> {
+        let command = control.command();
+        self.get_color_control_capabilities(command)?
+            .check(&control)
+            .map_err(|e| e.with_device(&self.id))?;
+        let (mode, value) = control.to_raw();
+        self.check("k4a_device_set_color_control", unsafe {
+            (self.api.k4a_device_set_color_control)(self.handle, command, mode, value)
+        })
     }
 
+    /// Get the range of values a color sensor control accepts.
     pub fn get_color_control_capabilities(
         &self,
         command: k4a_color_control_command_t,
     ) -> Result<ColorControlCapabilities, Error> {
         let mut capabilties = ColorControlCapabilities::default();
-        Error::from((self.api.k4a_device_get_color_control_capabilities)(
-            self.handle,
-            command,
-            &mut capabilties.supports_auto,
-            &mut capabilties.min_value,
-            &mut capabilties.max_value,
-            &mut capabilties.step_value,
-            &mut capabilties.default_value,
-            &mut capabilties.default_mode,
-        ))
-        .to_result(capabilties)
+        self.check("k4a_device_get_color_control_capabilities", unsafe {
+            (self.api.k4a_device_get_color_control_capabilities)(
+                self.handle,
+                command,
+                &mut capabilties.supports_auto,
+                &mut capabilties.min_value,
+                &mut capabilties.max_value,
+                &mut capabilties.step_value,
+                &mut capabilties.default_value,
+                &mut capabilties.default_mode,
+            )
+        })
+        .map(|()| capabilties)
     }
 
     /// Get the raw calibration blob for the entire K4A device.
     pub fn get_raw_calibration(&self) -> Result<Vec<u8>, Error> {
-        get_k4a_binary_data(&|calibration, buffer| {
-            (self.api.k4a_device_get_raw_calibration)(self.handle, calibration, buffer)
-        })
+        get_k4a_binary_data(
+            "k4a_device_get_raw_calibration",
+            &|calibration, buffer| unsafe {
+                (self.api.k4a_device_get_raw_calibration)(self.handle, calibration, buffer)
+            },
+        )
+        .map_err(|e| e.with_device(&self.id))
     }
 
     /// Get the camera calibration for the entire K4A device, which is used for all transformation functions.
@@ -131,25 +176,29 @@ impl Device {
         color_resolution: k4a_color_resolution_t,
     ) -> Result<Calibration, Error> {
         let mut calibaraion = k4a_calibration_t::default();
-        Error::from((self.api.k4a_device_get_calibration)(
-            self.handle,
-            depth_mode,
-            color_resolution,
-            &mut calibaraion,
-        ))
-        .to_result_fn(|| Calibration::from_handle(self.api.clone(), calibaraion))
+        self.check("k4a_device_get_calibration", unsafe {
+            (self.api.k4a_device_get_calibration)(
+                self.handle,
+                depth_mode,
+                color_resolution,
+                &mut calibaraion,
+            )
+        })
+        .map(|()| Calibration::from_handle(self.api.clone(), calibaraion))
     }
 
     /// Get the device jack status for the synchronization connectors
     pub fn is_sync_connected(&self) -> Result<(bool, bool), Error> {
         let mut sync_in_jack_connected = false;
         let mut sync_out_jack_connected = false;
-        Error::from((self.api.k4a_device_get_sync_jack)(
-            self.handle,
-            &mut sync_in_jack_connected,
-            &mut sync_out_jack_connected,
-        ))
-        .to_result((sync_in_jack_connected, sync_out_jack_connected))
+        self.check("k4a_device_get_sync_jack", unsafe {
+            (self.api.k4a_device_get_sync_jack)(
+                self.handle,
+                &mut sync_in_jack_connected,
+                &mut sync_out_jack_connected,
+            )
+        })
+        .map(|()| (sync_in_jack_connected, sync_out_jack_connected))
     }
 
     /// Get the device jack status for the synchronization in connector
@@ -165,33 +214,40 @@ impl Device {
     /// Get the version numbers of the K4A subsystems' firmware
     pub fn get_version(&self) -> Result<k4a_hardware_version_t, Error> {
         let mut version = k4a_hardware_version_t::default();
-        Error::from((self.api.k4a_device_get_version)(self.handle, &mut version)).to_result(version)
-    }
-
-    /// Starts the K4A IMU
-    pub fn start_imu(&self) -> Result<(), Error> {
-        Error::from((self.api.k4a_device_start_imu)(self.handle)).to_result(())
+        self.check("k4a_device_get_version", unsafe {
+            (self.api.k4a_device_get_version)(self.handle, &mut version)
+        })
+        .map(|()| version)
     }
 
-    /// Reads an IMU sample.  Returns true if a sample was read, false if the read timed out.
-    pub fn get_imu_sample(&self, timeout_in_ms: i32) -> Result<k4a_imu_sample_t, Error> {
-        let mut imu_sample = k4a_imu_sample_t::default();
-        Error::from((self.api.k4a_device_get_imu_sample)(
-            self.handle,
-            &mut imu_sample,
-            timeout_in_ms,
-        ))
-        .to_result(imu_sample)
+    /// The firmware versions of the subsystems, with their build and signature types.
+    pub fn get_firmware(&self) -> Result<DeviceFirmware, Error> {
+        self.get_version().map(DeviceFirmware::from)
     }
 
-    pub fn get_imu_sample_wait_infinite(&self) -> Result<k4a_imu_sample_t, Error> {
-        self.get_imu_sample(K4A_WAIT_INFINITE)
+    /// Checks the firmware against `requirement`, and fails with
+    /// [`ErrorKind::UnsupportedFirmware`] listing every mismatch if it is not met.
+    pub fn check_firmware(
+        &self,
+        requirement: &FirmwareRequirement,
+    ) -> Result<DeviceFirmware, Error> {
+        let firmware = self.get_firmware()?;
+        let mismatches = requirement.check(&firmware);
+        if mismatches.is_empty() {
+            return Ok(firmware);
+        }
+        let message = mismatches
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(Error::new(ErrorKind::UnsupportedFirmware(message)).with_device(&self.id))
     }
 }
 
 impl Drop for Device {
     fn drop(&mut self) {
-        (self.api.k4a_device_close)(self.handle);
+        unsafe { (self.api.k4a_device_close)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
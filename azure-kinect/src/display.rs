@@ -1,5 +1,5 @@
 use super::*;
-use crate::bindings::k4a_version_t;
+use crate::bindings::{k4a_calibration_intrinsic_parameters_t, k4a_version_t};
 use std::fmt::{Display, Formatter, Result};
 
 impl Display for k4a_imu_sample_t {
@@ -21,3 +21,9 @@ impl Display for k4a_version_t {
         write!(f, "{}.{}.{}", self.major, self.minor, self.iteration)
     }
 }
+
+impl std::fmt::Debug for k4a_calibration_intrinsic_parameters_t {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ", unsafe { self.v })
+    }
+}
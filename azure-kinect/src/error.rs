@@ -1,78 +1,297 @@
 use super::*;
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
 
-#[derive(Clone, Copy, Debug)]
-pub enum Error {
-    Succeeded,
+/// What went wrong. Match on [`Error::kind`] to handle a specific failure. New kinds may be
+/// added, so matches need a wildcard arm.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
     Failed,
     TooSmall,
     Timeout,
     Win32Error(u32),
+    /// A `dlopen`/`dlsym` failure, with the message reported by `dlerror()`.
+    DlError(String),
+    /// The loaded SDK version does not export the named entry point.
+    Unsupported(&'static str),
+    /// None of the locations searched for an SDK library could be loaded.
+    LibraryNotFound(SearchReport),
     Eof,
+    /// A device configuration breaks a rule of the SDK, described by the message.
+    InvalidConfiguration(String),
+    /// No attached device could be opened with the given serial number.
+    DeviceNotFound(String),
+    /// A color control setting is outside the capabilities of the control.
+    InvalidColorControl(String),
+    /// The firmware of a device does not meet a `FirmwareRequirement`, or a firmware version or
+    /// requirement could not be parsed.
+    UnsupportedFirmware(String),
+    /// An image does not have the format or layout of a typed view of its pixels, or cannot be
+    /// written to because other images may share its buffer.
+    InvalidImage(String),
+}
+
+/// The device an error happened on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceId {
+    /// Index the device was opened with.
+    pub index: u32,
+    /// Serial number, if it could be read when the device was opened.
+    pub serial: Option<String>,
+}
+
+/// A debug message of the SDK, kept for the errors that follow it.
+#[derive(Clone, Debug)]
+pub struct SdkMessage {
+    pub level: k4a_log_level_t,
+    pub file: String,
+    pub line: i32,
+    pub message: String,
+}
+
+/// An error of the SDK or of this crate, with the context it happened in.
+#[derive(Clone, Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    call: Option<&'static str>,
+    // Boxed to keep `Result`s small; timeouts, the most frequent errors, don't allocate.
+    context: Option<Box<Context>>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Context {
+    device: Option<DeviceId>,
+    path: Option<PathBuf>,
+    sdk_messages: Vec<SdkMessage>,
+}
+
+/// Number of debug messages kept for [`Error::sdk_messages`].
+const RECENT_SDK_MESSAGES: usize = 8;
+
+/// Debug messages not attached to an error yet, with the thread that logged them.
+static SDK_MESSAGES: Mutex<VecDeque<(ThreadId, SdkMessage)>> = Mutex::new(VecDeque::new());
+
+/// Keeps a debug message of the SDK for the next error on the same thread. Called for every
+/// message passed to the debug message handler.
+pub(crate) fn record_sdk_message(level: k4a_log_level_t, file: &str, line: i32, message: &str) {
+    let mut messages = SDK_MESSAGES.lock().unwrap_or_else(|e| e.into_inner());
+    if messages.len() == RECENT_SDK_MESSAGES {
+        messages.pop_front();
+    }
+    let message = SdkMessage {
+        level,
+        file: file.to_string(),
+        line,
+        message: message.to_string(),
+    };
+    messages.push_back((thread::current().id(), message));
+}
+
+/// Takes the messages logged on this thread since its last error, so that they are attached to
+/// one error only.
+fn take_sdk_messages() -> Vec<SdkMessage> {
+    let current = thread::current().id();
+    let mut messages = SDK_MESSAGES.lock().unwrap_or_else(|e| e.into_inner());
+    let (taken, others) = mem::take(&mut *messages)
+        .into_iter()
+        .partition::<Vec<_>, _>(|(thread, _)| *thread == current);
+    *messages = others.into();
+    taken.into_iter().map(|(_, message)| message).collect()
+}
+
+/// A status code returned by an SDK function.
+pub(crate) trait Status {
+    /// The error the status stands for, or `None` for success.
+    fn error_kind(self) -> Option<ErrorKind>;
 }
 
 impl Error {
-    pub(crate) fn to_result<T>(self, ok: T) -> Result<T, Error> {
-        match self {
-            Error::Succeeded => Ok(ok),
-            _ => Err(self),
+    pub fn new(kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            call: None,
+            context: None,
         }
     }
 
-    pub(crate) fn to_result_fn<T, F: FnOnce() -> T>(self, ok: F) -> Result<T, Error> {
-        match self {
-            Error::Succeeded => Ok(ok()),
-            _ => Err(self),
+    /// Turns the status returned by the SDK function `call` into a result. Failures keep the
+    /// recent debug messages of the SDK; timeouts and the end of a stream are expected and don't.
+    pub(crate) fn check<S: Status>(call: &'static str, status: S) -> Result<(), Error> {
+        match status.error_kind() {
+            None => Ok(()),
+            Some(kind @ ErrorKind::Timeout) | Some(kind @ ErrorKind::Eof) => {
+                Err(Error::new(kind).with_call(call))
+            }
+            Some(kind) => {
+                let mut error = Error::new(kind).with_call(call);
+                error.context_mut().sdk_messages = take_sdk_messages();
+                Err(error)
+            }
+        }
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// True if a wait ran out of time before a capture, sample or result was available.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.kind, ErrorKind::Timeout)
+    }
+
+    /// True if a playback reached the end or the beginning of the recording.
+    pub fn is_eof(&self) -> bool {
+        matches!(self.kind, ErrorKind::Eof)
+    }
+
+    /// The SDK function that failed.
+    pub fn call(&self) -> Option<&'static str> {
+        self.call
+    }
+
+    pub fn device(&self) -> Option<&DeviceId> {
+        self.context.as_ref()?.device.as_ref()
+    }
+
+    /// The recording read or written.
+    pub fn path(&self) -> Option<&Path> {
+        self.context.as_ref()?.path.as_deref()
+    }
+
+    /// The debug messages the SDK logged on the thread of the failed call since the previous
+    /// failure on that thread, oldest first. Messages only reach this crate while a debug message
+    /// handler is installed, for example with [`Api::replace_debug_message_handler`], and only at
+    /// the levels it was installed for.
+    pub fn sdk_messages(&self) -> &[SdkMessage] {
+        match &self.context {
+            Some(context) => &context.sdk_messages,
+            None => &[],
         }
     }
+
+    fn context_mut(&mut self) -> &mut Context {
+        self.context.get_or_insert_with(Default::default)
+    }
+
+    pub(crate) fn with_call(mut self, call: &'static str) -> Error {
+        self.call = Some(call);
+        self
+    }
+
+    pub(crate) fn with_device(mut self, device: &DeviceId) -> Error {
+        self.context_mut().device = Some(device.clone());
+        self
+    }
+
+    pub(crate) fn with_path(mut self, path: &Path) -> Error {
+        self.context_mut().path = Some(path.to_path_buf());
+        self
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error::new(kind)
+    }
 }
 
 impl std::error::Error for Error {}
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::LibraryNotFound(report) => write!(f, "{}", report),
+            ErrorKind::InvalidConfiguration(message) => {
+                write!(f, "invalid device configuration: {}", message)
+            }
+            ErrorKind::InvalidColorControl(message) => {
+                write!(f, "invalid color control: {}", message)
+            }
+            ErrorKind::UnsupportedFirmware(message) => {
+                write!(f, "unsupported firmware: {}", message)
+            }
+            ErrorKind::InvalidImage(message) => write!(f, "invalid image: {}", message),
+            ErrorKind::DeviceNotFound(serial) => {
+                write!(f, "no device with serial number {} could be opened", serial)
+            }
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
-pub(crate) trait ToResult: Sized {
-    fn to_result(&self) -> Result<Self, Error>;
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "device {}", self.index)?;
+        if let Some(serial) = &self.serial {
+            write!(f, " ({})", serial)?;
+        }
+        Ok(())
+    }
 }
 
-impl From<k4a_result_t> for Error {
-    fn from(s: k4a_result_t) -> Error {
-        match s {
-            k4a_result_t::K4A_RESULT_SUCCEEDED => Error::Succeeded,
-            k4a_result_t::K4A_RESULT_FAILED => Error::Failed,
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(call) = self.call {
+            write!(f, "{}: ", call)?;
         }
+        write!(f, "{}", self.kind)?;
+        if let Some(device) = self.device() {
+            write!(f, " on {}", device)?;
+        }
+        if let Some(path) = self.path() {
+            write!(f, " for {}", path.display())?;
+        }
+        for message in self.sdk_messages() {
+            write!(
+                f,
+                "\n  [{:?}] {} ({}:{})",
+                message.level, message.message, message.file, message.line
+            )?;
+        }
+        Ok(())
     }
 }
 
-impl From<k4a_buffer_result_t> for Error {
-    fn from(s: k4a_buffer_result_t) -> Error {
-        match s {
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Error::Succeeded,
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED => Error::Failed,
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => Error::TooSmall,
+impl Status for k4a_result_t {
+    fn error_kind(self) -> Option<ErrorKind> {
+        match self {
+            k4a_result_t::K4A_RESULT_SUCCEEDED => None,
+            k4a_result_t::K4A_RESULT_FAILED => Some(ErrorKind::Failed),
         }
     }
 }
 
-impl From<k4a_wait_result_t> for Error {
-    fn from(s: k4a_wait_result_t) -> Error {
-        match s {
-            k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED => Error::Succeeded,
-            k4a_wait_result_t::K4A_WAIT_RESULT_FAILED => Error::Failed,
-            k4a_wait_result_t::K4A_WAIT_RESULT_TIMEOUT => Error::Timeout,
+impl Status for k4a_buffer_result_t {
+    fn error_kind(self) -> Option<ErrorKind> {
+        match self {
+            k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => None,
+            k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED => Some(ErrorKind::Failed),
+            k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => Some(ErrorKind::TooSmall),
         }
     }
 }
 
-impl From<k4a_stream_result_t> for Error {
-    fn from(s: k4a_stream_result_t) -> Error {
-        match s {
-            k4a_stream_result_t::K4A_STREAM_RESULT_SUCCEEDED => Error::Succeeded,
-            k4a_stream_result_t::K4A_STREAM_RESULT_FAILED => Error::Failed,
-            k4a_stream_result_t::K4A_STREAM_RESULT_EOF => Error::Eof,
+impl Status for k4a_wait_result_t {
+    fn error_kind(self) -> Option<ErrorKind> {
+        match self {
+            k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED => None,
+            k4a_wait_result_t::K4A_WAIT_RESULT_FAILED => Some(ErrorKind::Failed),
+            k4a_wait_result_t::K4A_WAIT_RESULT_TIMEOUT => Some(ErrorKind::Timeout),
+        }
+    }
+}
+
+impl Status for k4a_stream_result_t {
+    fn error_kind(self) -> Option<ErrorKind> {
+        match self {
+            k4a_stream_result_t::K4A_STREAM_RESULT_SUCCEEDED => None,
+            k4a_stream_result_t::K4A_STREAM_RESULT_FAILED => Some(ErrorKind::Failed),
+            k4a_stream_result_t::K4A_STREAM_RESULT_EOF => Some(ErrorKind::Eof),
         }
     }
 }
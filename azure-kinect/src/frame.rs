@@ -2,13 +2,16 @@ use super::*;
 use std::ptr;
 use std::sync::Arc;
 
+// Body frames are reference counted by the SDK, which releases them from any thread.
+unsafe impl Send for Frame {}
+
 pub struct Frame {
     api_tracker: Arc<ApiTracker>,
-    pub handle: k4abt_frame_t,
+    pub(crate) handle: k4abt_frame_t,
 }
 
 impl Frame {
-    pub fn from_handle(api_tracker: Arc<ApiTracker>, handle: k4abt_frame_t) -> Frame {
+    pub(crate) fn from_handle(api_tracker: Arc<ApiTracker>, handle: k4abt_frame_t) -> Frame {
         Frame {
             api_tracker,
             handle: handle,
@@ -17,7 +20,7 @@ impl Frame {
 }
 impl Drop for Frame {
     fn drop(&mut self) {
-        (self.api_tracker.k4abt_frame_release)(self.handle);
+        unsafe { (self.api_tracker.k4abt_frame_release)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
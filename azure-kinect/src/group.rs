@@ -0,0 +1,260 @@
+//! Devices chained with sync cables, streamed together as one.
+//!
+//! The master drives the sync out jack and each subordinate follows its sync in jack. A
+//! [`DeviceGroup`] works out which device is which from the jacks, starts the subordinates
+//! before the master so that none misses the first trigger, and gives each device its own
+//! `subordinate_delay_off_master_usec` and `depth_delay_off_color_usec`, spaced by
+//! [`DeviceGroup::DEPTH_SPACING_USEC`], so the depth cameras do not see each other's lasers.
+//! Captures are then matched across the devices by device timestamp.
+//!
+//! ```no_run
+//! # use azure_kinect::*;
+//! # fn run(api: std::sync::Arc<Api>) -> Result<(), Error> {
+//! let devices = (0..api.device_get_installed_count())
+//!     .map(|index| Device::new(api.clone(), index))
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! let config = DeviceConfiguration::new()
+//!     .color(
+//!         k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32,
+//!         k4a_color_resolution_t::K4A_COLOR_RESOLUTION_720P,
+//!     )
+//!     .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED);
+//! let mut group = DeviceGroup::start(devices, config, 1_000)?;
+//! let captures = group.get_capture_set(1000)?;
+//! assert_eq!(captures.len(), group.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+/// Captures queued per device while a set is being matched.
+const QUEUE_CAPACITY: usize = 4;
+
+/// Streams of a master and its subordinates, started with [`DeviceGroup::start`].
+pub struct DeviceGroup {
+    /// The master first, then the subordinates in the order they were given.
+    streams: Vec<CaptureStream>,
+    /// Configuration each stream was started with.
+    configurations: Vec<k4a_device_configuration_t>,
+    /// The oldest capture of each stream not matched yet.
+    pending: Vec<Option<Capture>>,
+    tolerance_usec: u64,
+    unmatched: u64,
+}
+
+impl DeviceGroup {
+    /// Delay added between the captures of consecutive devices. The depth camera fires its laser
+    /// for 125 µs, so a spacing of 160 µs keeps the exposures apart.
+    pub const DEPTH_SPACING_USEC: u32 = 160;
+
+    /// Checks that one device drives a sync cable and every other one is fed by a sync cable,
+    /// then starts them all with `configuration`, as subordinates first and as master last.
+    ///
+    /// Device `i`, counting the master as 0, is delayed by `i` times
+    /// [`DeviceGroup::DEPTH_SPACING_USEC`] off the master, and its depth camera by as much again
+    /// on top of the `depth_delay_off_color_usec` of `configuration`. Fails with
+    /// [`ErrorKind::InvalidConfiguration`] if the depth exposures of all the devices do not fit
+    /// in one frame period.
+    ///
+    /// Captures whose device timestamps, less the delay of their device, are at most
+    /// `tolerance_usec` apart are matched into a set.
+    pub fn start(
+        devices: Vec<Device>,
+        configuration: DeviceConfiguration,
+        tolerance_usec: u64,
+    ) -> Result<DeviceGroup, Error> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidConfiguration(message));
+        if devices.len() < 2 {
+            return Err(invalid(format!(
+                "a device group needs at least two devices, not {}",
+                devices.len()
+            )));
+        }
+        let mut master: Option<Device> = None;
+        let mut subordinates = Vec::new();
+        for device in devices {
+            let (sync_in, sync_out) = device.is_sync_connected()?;
+            if sync_in {
+                subordinates.push(device);
+            } else if !sync_out {
+                return Err(invalid(format!(
+                    "device {} has no sync cable connected",
+                    device.get_serialnum()?
+                )));
+            } else if let Some(other) = &master {
+                return Err(invalid(format!(
+                    "devices {} and {} both drive sync out without a sync in, only the master \
+                     should",
+                    other.get_serialnum()?,
+                    device.get_serialnum()?
+                )));
+            } else {
+                master = Some(device);
+            }
+        }
+        let master = master.ok_or_else(|| {
+            invalid("no device drives sync out without a sync in, so none can be master".into())
+        })?;
+
+        let master_configuration = configuration.master().build()?;
+        let depth_delay_usec = master_configuration.depth_delay_off_color_usec as i64;
+        let period_usec = 1_000_000 / master_configuration.camera_fps.get_u32() as i64;
+        let spacing_usec = Self::DEPTH_SPACING_USEC as i64;
+        // The last device fires its laser twice the spacing per device after the master.
+        let spread_usec = 2 * subordinates.len() as i64 * spacing_usec + spacing_usec;
+        if spread_usec > period_usec {
+            return Err(invalid(format!(
+                "the depth exposures of {} devices span {} us, more than the frame period of {} us",
+                subordinates.len() + 1,
+                spread_usec,
+                period_usec
+            )));
+        }
+        let mut configurations = vec![master_configuration];
+        for index in 1..=subordinates.len() as i64 {
+            configurations.push(
+                configuration
+                    .subordinate((index * spacing_usec) as u32)
+                    .depth_delay_off_color_usec((depth_delay_usec + index * spacing_usec) as i32)
+                    .build()?,
+            );
+        }
+
+        // The subordinates must be waiting for the trigger before the master sends it.
+        let mut subordinate_streams = Vec::new();
+        for (device, configuration) in subordinates.into_iter().zip(&configurations[1..]) {
+            subordinate_streams.push(CaptureStream::start(
+                device,
+                configuration,
+                QUEUE_CAPACITY,
+                OverflowPolicy::DropOldest,
+            )?);
+        }
+        let mut streams = vec![CaptureStream::start(
+            master,
+            &configurations[0],
+            QUEUE_CAPACITY,
+            OverflowPolicy::DropOldest,
+        )?];
+        streams.extend(subordinate_streams);
+
+        Ok(DeviceGroup {
+            pending: streams.iter().map(|_| None).collect(),
+            streams,
+            configurations,
+            tolerance_usec,
+            unmatched: 0,
+        })
+    }
+
+    /// Number of devices, master included.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    /// The device at `index`: the master at 0, then the subordinates in the order they were
+    /// given.
+    pub fn device(&self, index: usize) -> Option<&Device> {
+        self.streams.get(index).map(CaptureStream::device)
+    }
+
+    /// The configuration the device at `index` was started with, with its sync mode and delay.
+    pub fn configuration(&self, index: usize) -> Option<&k4a_device_configuration_t> {
+        self.configurations.get(index)
+    }
+
+    /// Takes the next set of matching captures, one per device in the order of
+    /// [`DeviceGroup::device`]. Captures that cannot be matched are dropped. Fails with
+    /// [`ErrorKind::Timeout`] if no set was complete in time.
+    pub fn get_capture_set(&mut self, timeout_in_ms: i32) -> Result<Vec<Capture>, Error> {
+        let deadline = match timeout_in_ms {
+            K4A_WAIT_INFINITE => None,
+            timeout => Some(Instant::now() + Duration::from_millis(timeout.max(0) as u64)),
+        };
+        loop {
+            for (stream, pending) in self.streams.iter().zip(&mut self.pending) {
+                if pending.is_none() {
+                    let timeout = match deadline {
+                        None => K4A_WAIT_INFINITE,
+                        Some(deadline) => deadline
+                            .saturating_duration_since(Instant::now())
+                            .as_millis() as i32,
+                    };
+                    *pending = Some(stream.get_capture(timeout)?);
+                }
+            }
+            let timestamps: Vec<Option<u64>> = self
+                .pending
+                .iter()
+                .zip(&self.configurations)
+                .map(|(pending, configuration)| {
+                    master_timestamp_usec(pending.as_ref().unwrap(), configuration)
+                })
+                .collect();
+            let newest = timestamps.iter().flatten().copied().max().unwrap_or(0);
+            let mut complete = true;
+            for (pending, timestamp) in self.pending.iter_mut().zip(timestamps) {
+                // Later captures of this device are newer still, so an old one can never match.
+                match timestamp {
+                    Some(timestamp) if newest - timestamp <= self.tolerance_usec => {}
+                    _ => {
+                        *pending = None;
+                        self.unmatched += 1;
+                        complete = false;
+                    }
+                }
+            }
+            if complete {
+                return Ok(self
+                    .pending
+                    .iter_mut()
+                    .map(|pending| pending.take().unwrap())
+                    .collect());
+            }
+        }
+    }
+
+    /// Takes the next set of matching captures, waiting for as long as it takes.
+    pub fn get_capture_set_wait_infinite(&mut self) -> Result<Vec<Capture>, Error> {
+        self.get_capture_set(K4A_WAIT_INFINITE)
+    }
+
+    /// Number of captures dropped because no capture of another device matched them.
+    pub fn unmatched(&self) -> u64 {
+        self.unmatched
+    }
+
+    /// Stops every device, and returns them in the order of [`DeviceGroup::device`].
+    pub fn stop(self) -> Vec<Device> {
+        self.streams.into_iter().map(CaptureStream::stop).collect()
+    }
+}
+
+/// When the master started the frame of `capture`, from the device timestamp of its color image,
+/// or of its depth or IR image less the depth delay.
+fn master_timestamp_usec(
+    capture: &Capture,
+    configuration: &k4a_device_configuration_t,
+) -> Option<u64> {
+    let color = capture.get_color_image();
+    let timestamp_usec = if !color.handle.is_null() {
+        color.get_device_timestamp_usec() as i64
+    } else {
+        // Depth and IR images come from the same exposure.
+        let depth = Some(capture.get_depth_image())
+            .filter(|image| !image.handle.is_null())
+            .unwrap_or_else(|| capture.get_ir_image());
+        if depth.handle.is_null() {
+            return None;
+        }
+        depth.get_device_timestamp_usec() as i64 - configuration.depth_delay_off_color_usec as i64
+    };
+    u64::try_from(timestamp_usec - configuration.subordinate_delay_off_master_usec as i64).ok()
+}
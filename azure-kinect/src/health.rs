@@ -0,0 +1,257 @@
+//! Health of the camera and IMU streams, measured from their timestamps.
+//!
+//! A [`StreamHealth`] takes the captures and IMU samples of a device or of a recording and tells
+//! whether frames were dropped, how regular they were and how long they took to reach the host.
+//! [`CaptureStream::health`] measures a running device, and [`StreamHealth::scan`] a whole
+//! recording:
+//!
+//! ```no_run
+//! # use azure_kinect::*;
+//! # fn check(playback: &playback::Playback) -> Result<(), Error> {
+//! let report = StreamHealth::scan(playback)?;
+//! println!(
+//!     "depth: {:?} fps, {} frames missed",
+//!     report.depth.fps, report.depth.missed
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+
+/// The IMU reports samples at 1.6kHz.
+const IMU_PERIOD_USEC: u64 = 625;
+
+/// Mean, standard deviation and extremes of a series of values.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spread {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Timing of the frames of one camera, or of the IMU samples.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TimingReport {
+    /// Number of frames or samples.
+    pub count: u64,
+    /// Frames or samples per second, between the first and the last device timestamp.
+    pub fps: Option<f64>,
+    /// Time between consecutive device timestamps. Its standard deviation is the jitter.
+    pub interval_usec: Option<Spread>,
+    /// Frames or samples missing from the gaps between device timestamps, against the
+    /// configured frame rate.
+    pub missed: u64,
+    /// System timestamp less device timestamp. The two clocks start at different times, so only
+    /// changes are meaningful: a growing value is a growing USB latency. `None` for IMU samples
+    /// and for recordings without system timestamps.
+    pub latency_usec: Option<Spread>,
+}
+
+/// Timing of every stream, returned by [`StreamHealth::report`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HealthReport {
+    pub color: TimingReport,
+    pub depth: TimingReport,
+    pub ir: TimingReport,
+    pub imu: TimingReport,
+    /// Depth device timestamp less color device timestamp, in captures that have both. Follows
+    /// `depth_delay_off_color_usec`.
+    pub color_to_depth_usec: Option<Spread>,
+}
+
+/// Collects the timestamps of captures and IMU samples into a [`HealthReport`].
+#[derive(Clone, Debug)]
+pub struct StreamHealth {
+    color: Timing,
+    depth: Timing,
+    ir: Timing,
+    imu: Timing,
+    color_to_depth: Accumulator,
+}
+
+impl StreamHealth {
+    /// For cameras running at `fps`.
+    pub fn new(fps: k4a_fps_t) -> StreamHealth {
+        let period_usec = 1_000_000 / fps.get_u32() as u64;
+        StreamHealth {
+            color: Timing::new(period_usec),
+            depth: Timing::new(period_usec),
+            ir: Timing::new(period_usec),
+            imu: Timing::new(IMU_PERIOD_USEC),
+            color_to_depth: Accumulator::default(),
+        }
+    }
+
+    /// For the streams of a recording, at the frame rate it was recorded with.
+    pub fn for_playback(playback: &playback::Playback) -> Result<StreamHealth, Error> {
+        Ok(Self::new(playback.get_record_configuration()?.camera_fps))
+    }
+
+    /// Reads every capture and IMU sample of a recording, from its beginning, and leaves it at
+    /// the beginning again.
+    pub fn scan(playback: &playback::Playback) -> Result<HealthReport, Error> {
+        let mut health = Self::for_playback(playback)?;
+        let begin = k4a_playback_seek_origin_t::K4A_PLAYBACK_SEEK_BEGIN;
+        playback.seek_timestamp(0, begin)?;
+        loop {
+            match playback.get_next_capture() {
+                Ok(capture) => health.add_capture(&capture),
+                Err(e) if e.is_eof() => break,
+                Err(e) => return Err(e),
+            }
+        }
+        loop {
+            match playback.get_next_imu_sample() {
+                Ok(sample) => health.add_imu_sample(&sample),
+                Err(e) if e.is_eof() => break,
+                Err(e) => return Err(e),
+            }
+        }
+        playback.seek_timestamp(0, begin)?;
+        Ok(health.report())
+    }
+
+    /// Adds the images of a capture. Images older than the last one of their camera are ignored.
+    pub fn add_capture(&mut self, capture: &Capture) {
+        let color = timestamps(&capture.get_color_image());
+        let depth = timestamps(&capture.get_depth_image());
+        if let Some((device_usec, system_nsec)) = color {
+            self.color.add(device_usec, system_nsec);
+        }
+        if let Some((device_usec, system_nsec)) = depth {
+            self.depth.add(device_usec, system_nsec);
+        }
+        if let Some((device_usec, system_nsec)) = timestamps(&capture.get_ir_image()) {
+            self.ir.add(device_usec, system_nsec);
+        }
+        if let (Some((color_usec, _)), Some((depth_usec, _))) = (color, depth) {
+            self.color_to_depth
+                .add(depth_usec as f64 - color_usec as f64);
+        }
+    }
+
+    /// Adds an IMU sample, by its accelerometer timestamp.
+    pub fn add_imu_sample(&mut self, sample: &k4a_imu_sample_t) {
+        self.imu.add(sample.acc_timestamp_usec, None);
+    }
+
+    pub fn report(&self) -> HealthReport {
+        HealthReport {
+            color: self.color.report(),
+            depth: self.depth.report(),
+            ir: self.ir.report(),
+            imu: self.imu.report(),
+            color_to_depth_usec: self.color_to_depth.spread(),
+        }
+    }
+}
+
+/// Device timestamp of an image, and its system timestamp unless it is missing.
+fn timestamps(image: &Image) -> Option<(u64, Option<u64>)> {
+    if image.handle.is_null() {
+        return None;
+    }
+    let system_nsec = Some(image.get_system_timestamp_nsec()).filter(|&nsec| nsec != 0);
+    Some((image.get_device_timestamp_usec(), system_nsec))
+}
+
+#[derive(Clone, Debug)]
+struct Timing {
+    period_usec: u64,
+    first_usec: Option<u64>,
+    last_usec: Option<u64>,
+    count: u64,
+    missed: u64,
+    intervals: Accumulator,
+    latency: Accumulator,
+}
+
+impl Timing {
+    fn new(period_usec: u64) -> Timing {
+        Timing {
+            period_usec,
+            first_usec: None,
+            last_usec: None,
+            count: 0,
+            missed: 0,
+            intervals: Accumulator::default(),
+            latency: Accumulator::default(),
+        }
+    }
+
+    fn add(&mut self, device_usec: u64, system_nsec: Option<u64>) {
+        match self.last_usec {
+            Some(last_usec) if device_usec <= last_usec => return,
+            Some(last_usec) => {
+                let interval_usec = device_usec - last_usec;
+                self.intervals.add(interval_usec as f64);
+                // Anything longer than one and a half periods is a gap.
+                if 2 * interval_usec > 3 * self.period_usec {
+                    self.missed += (interval_usec + self.period_usec / 2) / self.period_usec - 1;
+                }
+            }
+            None => self.first_usec = Some(device_usec),
+        }
+        self.last_usec = Some(device_usec);
+        self.count += 1;
+        if let Some(system_nsec) = system_nsec {
+            self.latency
+                .add(system_nsec as f64 / 1000.0 - device_usec as f64);
+        }
+    }
+
+    fn report(&self) -> TimingReport {
+        let fps = match (self.first_usec, self.last_usec) {
+            (Some(first), Some(last)) if last > first => {
+                Some((self.count - 1) as f64 * 1_000_000.0 / (last - first) as f64)
+            }
+            _ => None,
+        };
+        TimingReport {
+            count: self.count,
+            fps,
+            interval_usec: self.intervals.spread(),
+            missed: self.missed,
+            latency_usec: self.latency.spread(),
+        }
+    }
+}
+
+/// Running mean and variance, with Welford's algorithm.
+#[derive(Copy, Clone, Debug, Default)]
+struct Accumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Accumulator {
+    fn add(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn spread(&self) -> Option<Spread> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(Spread {
+            mean: self.mean,
+            std_dev: (self.m2 / self.count as f64).sqrt(),
+            min: self.min,
+            max: self.max,
+        })
+    }
+}
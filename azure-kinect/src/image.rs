@@ -1,10 +1,21 @@
 use super::*;
+use std::ffi::c_void;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+// Images are reference counted by the SDK, which releases them from any thread.
+unsafe impl Send for Image {}
+
 pub struct Image {
-    api: Arc<Api>,
+    pub(crate) api: Arc<Api>,
     pub(crate) handle: k4a_image_t,
+    /// Shared by an image created with `with_format` or `with_buffer` and its clones, and set
+    /// for good once one of them is set on a capture, which the SDK may hand out again. Images
+    /// taken from a capture have none, as other `Image`s may refer to the same buffer.
+    owner: Option<Arc<AtomicBool>>,
 }
 
 impl Image {
@@ -12,6 +23,28 @@ impl Image {
         Image {
             api: api,
             handle: handle,
+            owner: None,
+        }
+    }
+
+    fn owned(api: Arc<Api>, handle: k4a_image_t) -> Image {
+        Image {
+            api,
+            handle,
+            owner: Some(Arc::new(AtomicBool::new(false))),
+        }
+    }
+
+    /// True if no other `Image`, capture or tracker refers to the buffer.
+    pub(crate) fn is_unique(&self) -> bool {
+        matches!(&self.owner, Some(owner)
+            if Arc::strong_count(owner) == 1 && !owner.load(Ordering::SeqCst))
+    }
+
+    /// Records that the SDK holds a reference to the buffer that `is_unique` cannot count.
+    pub(crate) fn mark_shared(&self) {
+        if let Some(owner) = &self.owner {
+            owner.store(true, Ordering::SeqCst);
         }
     }
 
@@ -24,18 +57,27 @@ impl Image {
         stride_bytes: i32,
     ) -> Result<Image, Error> {
         let mut handle: k4a_image_t = ptr::null_mut();
-        Error::from((api.k4a_image_create)(
-            format,
-            width_pixels,
-            height_pixels,
-            stride_bytes,
-            &mut handle,
-        ))
-        .to_result_fn(|| Image::from_handle(api, handle))
+        Error::check("k4a_image_create", unsafe {
+            (api.k4a_image_create)(
+                format,
+                width_pixels,
+                height_pixels,
+                stride_bytes,
+                &mut handle,
+            )
+        })
+        .map(|()| Image::owned(api, handle))
     }
 
     /// Create an image from a pre-allocated buffer
-    pub fn with_buffer(
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must point to `buffer_size` bytes that stay valid until `buffer_release_cb` is
+    /// called with `buffer` and `buffer_release_cb_context`, or until the last reference to the
+    /// image is released if no callback is given.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn with_buffer(
         api: Arc<Api>,
         format: k4a_image_format_t,
         width_pixels: i32,
@@ -47,116 +89,246 @@ impl Image {
         buffer_release_cb_context: *mut (),
     ) -> Result<Image, Error> {
         let mut handle: k4a_image_t = ptr::null_mut();
-        Error::from((api.k4a_image_create_from_buffer)(
-            format,
-            width_pixels,
-            height_pixels,
-            stride_bytes,
-            buffer,
-            buffer_size,
-            buffer_release_cb,
-            buffer_release_cb_context,
-            &mut handle,
-        ))
-        .to_result_fn(|| Image::from_handle(api, handle))
+        Error::check(
+            "k4a_image_create_from_buffer",
+            (api.k4a_image_create_from_buffer)(
+                format,
+                width_pixels,
+                height_pixels,
+                stride_bytes,
+                buffer,
+                buffer_size,
+                buffer_release_cb,
+                buffer_release_cb_context,
+                &mut handle,
+            ),
+        )
+        .map(|()| Image::owned(api, handle))
+    }
+
+    /// Create an image from a buffer owned by Rust, such as a `Vec<u8>` or a `Box<[u16]>`,
+    /// without copying it
+    ///
+    /// `buffer` is kept until the last reference to the image is released, and dropped then, on
+    /// the thread that released it. The image can be written to with the `_mut` views of
+    /// [`Image::as_depth16_mut`] and friends while it has no clones, and never again once it or a
+    /// clone of it has been set on a capture.
+    pub fn with_owned_buffer<T, B>(
+        api: Arc<Api>,
+        format: k4a_image_format_t,
+        width_pixels: i32,
+        height_pixels: i32,
+        stride_bytes: i32,
+        buffer: B,
+    ) -> Result<Image, Error>
+    where
+        T: Pixel,
+        B: AsMut<[T]> + Send + 'static,
+    {
+        // Boxed before taking the pixels, which must not move with the owner of an array.
+        let owner = Box::into_raw(Box::new(buffer));
+        let pixels = unsafe { (*owner).as_mut() };
+        let image = unsafe {
+            Image::with_buffer(
+                api,
+                format,
+                width_pixels,
+                height_pixels,
+                stride_bytes,
+                pixels.as_mut_ptr() as *mut u8,
+                mem::size_of_val(pixels),
+                Some(release_owned_buffer::<B>),
+                owner as *mut (),
+            )
+        };
+        if image.is_err() {
+            // The SDK only calls the release callback of the images it created.
+            drop(unsafe { Box::from_raw(owner) });
+        }
+        image
     }
 
     /// Get the image buffer
     pub fn get_buffer(&self) -> *const u8 {
-        (self.api.k4a_image_get_buffer)(self.handle)
+        unsafe { (self.api.k4a_image_get_buffer)(self.handle) }
     }
 
     /// Get the mutable image buffer
     pub fn get_mut_buffer(&mut self) -> *mut u8 {
-        (self.api.k4a_image_get_buffer)(self.handle)
+        unsafe { (self.api.k4a_image_get_buffer)(self.handle) }
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_DEPTH16` image, in millimeters.
+    pub fn as_depth16(&self) -> Result<ImageView<'_, u16>, Error> {
+        image_view::view(self, k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_IR16` image.
+    pub fn as_ir16(&self) -> Result<ImageView<'_, u16>, Error> {
+        image_view::view(self, k4a_image_format_t::K4A_IMAGE_FORMAT_IR16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_CUSTOM16` image.
+    pub fn as_custom16(&self) -> Result<ImageView<'_, u16>, Error> {
+        image_view::view(self, k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_CUSTOM8` image.
+    pub fn as_custom8(&self) -> Result<ImageView<'_, u8>, Error> {
+        image_view::view(self, k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM8)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_COLOR_BGRA32` image, as blue, green, red and alpha.
+    pub fn as_bgra32(&self) -> Result<ImageView<'_, [u8; 4]>, Error> {
+        image_view::view(self, k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_DEPTH16` image, in millimeters.
+    ///
+    /// Only the images created with [`Image::with_format`] or [`Image::with_buffer`], and not
+    /// cloned, can be written to. Images of a capture may share their buffer with other `Image`s
+    /// and fail with [`ErrorKind::InvalidImage`]; so do all `_mut` views.
+    pub fn as_depth16_mut(&mut self) -> Result<ImageViewMut<'_, u16>, Error> {
+        image_view::view_mut(self, k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_IR16` image.
+    pub fn as_ir16_mut(&mut self) -> Result<ImageViewMut<'_, u16>, Error> {
+        image_view::view_mut(self, k4a_image_format_t::K4A_IMAGE_FORMAT_IR16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_CUSTOM16` image.
+    pub fn as_custom16_mut(&mut self) -> Result<ImageViewMut<'_, u16>, Error> {
+        image_view::view_mut(self, k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM16)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_CUSTOM8` image.
+    pub fn as_custom8_mut(&mut self) -> Result<ImageViewMut<'_, u8>, Error> {
+        image_view::view_mut(self, k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM8)
+    }
+
+    /// The pixels of a `K4A_IMAGE_FORMAT_COLOR_BGRA32` image, as blue, green, red and alpha.
+    pub fn as_bgra32_mut(&mut self) -> Result<ImageViewMut<'_, [u8; 4]>, Error> {
+        image_view::view_mut(self, k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32)
+    }
+
+    /// Converts a color image to `format`, in Rust and on the calling thread, except that MJPG
+    /// images are decoded on one thread per color component. MJPG, NV12 and YUY2 images convert
+    /// to BGRA32, the format [`Transformation::color_image_to_depth_camera`] needs; MJPG only
+    /// with the `mjpg` feature. An image already in `format` is returned as is.
+    ///
+    /// The timestamps, exposure, white balance and ISO speed are copied to the converted image.
+    ///
+    /// [`Transformation::color_image_to_depth_camera`]: transformation::Transformation::color_image_to_depth_camera
+    pub fn convert_to(&self, format: k4a_image_format_t) -> Result<Image, Error> {
+        convert::convert(self, format, 1)
+    }
+
+    /// Like [`Image::convert_to`], with the rows converted on `threads` threads. MJPG images are
+    /// decoded first, on one thread per color component whatever `threads` is.
+    pub fn convert_to_parallel(
+        &self,
+        format: k4a_image_format_t,
+        threads: usize,
+    ) -> Result<Image, Error> {
+        convert::convert(self, format, threads)
     }
 
     /// Get the image buffer size in bytes
     pub fn get_size(&self) -> usize {
-        (self.api.k4a_image_get_size)(self.handle)
+        unsafe { (self.api.k4a_image_get_size)(self.handle) }
     }
 
     /// Get the image format of the image
     pub fn get_format(&self) -> k4a_image_format_t {
-        (self.api.k4a_image_get_format)(self.handle)
+        unsafe { (self.api.k4a_image_get_format)(self.handle) }
     }
 
     /// Get the image width in pixels
     pub fn get_width_pixels(&self) -> i32 {
-        (self.api.k4a_image_get_width_pixels)(self.handle)
+        unsafe { (self.api.k4a_image_get_width_pixels)(self.handle) }
     }
 
     /// Get the image height in pixels
     pub fn get_height_pixels(&self) -> i32 {
-        (self.api.k4a_image_get_height_pixels)(self.handle)
+        unsafe { (self.api.k4a_image_get_height_pixels)(self.handle) }
     }
 
     /// Get the image stride in bytes
     pub fn get_stride_bytes(&self) -> i32 {
-        (self.api.k4a_image_get_stride_bytes)(self.handle)
+        unsafe { (self.api.k4a_image_get_stride_bytes)(self.handle) }
     }
 
     /// Get the image's device timestamp in microseconds
     pub fn get_device_timestamp_usec(&self) -> u64 {
-        (self.api.k4a_image_get_device_timestamp_usec)(self.handle)
+        unsafe { (self.api.k4a_image_get_device_timestamp_usec)(self.handle) }
     }
 
     /// Get the image's system timestamp in nanoseconds
     pub fn get_system_timestamp_nsec(&self) -> u64 {
-        (self.api.k4a_image_get_system_timestamp_nsec)(self.handle)
+        unsafe { (self.api.k4a_image_get_system_timestamp_nsec)(self.handle) }
     }
 
     /// Get the image exposure time in microseconds
     pub fn get_exposure_usec(&self) -> u64 {
-        (self.api.k4a_image_get_exposure_usec)(self.handle)
+        unsafe { (self.api.k4a_image_get_exposure_usec)(self.handle) }
     }
 
     /// Get the image white balance in Kelvin (color images only)
     pub fn get_white_balance(&self) -> u32 {
-        (self.api.k4a_image_get_white_balance)(self.handle)
+        unsafe { (self.api.k4a_image_get_white_balance)(self.handle) }
     }
 
     /// Get the image's ISO speed (color images only)
     pub fn get_iso_speed(&self) -> u32 {
-        (self.api.k4a_image_get_iso_speed)(self.handle)
+        unsafe { (self.api.k4a_image_get_iso_speed)(self.handle) }
     }
 
     /// Set the image's device timestamp in microseconds
     pub fn set_device_timestamp_usec(&mut self, timestamp: u64) {
-        (self.api.k4a_image_set_device_timestamp_usec)(self.handle, timestamp)
+        unsafe { (self.api.k4a_image_set_device_timestamp_usec)(self.handle, timestamp) }
     }
 
     /// Set the image's system timestamp in nanoseconds
     pub fn set_system_timestamp_nsec(&self, timestamp: u64) {
-        (self.api.k4a_image_set_system_timestamp_nsec)(self.handle, timestamp)
+        unsafe { (self.api.k4a_image_set_system_timestamp_nsec)(self.handle, timestamp) }
     }
 
     /// Set the image exposure time in microseconds
     pub fn set_exposure_usec(&mut self, exposure: u64) {
-        (self.api.k4a_image_set_exposure_usec)(self.handle, exposure)
+        unsafe { (self.api.k4a_image_set_exposure_usec)(self.handle, exposure) }
     }
 
     /// Set the image white balance in Kelvin (color images only)
     pub fn set_white_balance(&mut self, white_balance: u32) {
-        (self.api.k4a_image_set_white_balance)(self.handle, white_balance)
+        unsafe { (self.api.k4a_image_set_white_balance)(self.handle, white_balance) }
     }
 
     /// Set the image's ISO speed (color images only)
     pub fn set_iso_speed(&mut self, iso_speed: u32) {
-        (self.api.k4a_image_set_iso_speed)(self.handle, iso_speed)
+        unsafe { (self.api.k4a_image_set_iso_speed)(self.handle, iso_speed) }
     }
 }
 
+unsafe extern "C" fn release_owned_buffer<B>(_buffer: *mut c_void, context: *mut c_void) {
+    // Unwinding into the SDK is undefined behavior.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(context as *mut B))));
+}
+
 impl Drop for Image {
     fn drop(&mut self) {
-        (self.api.k4a_image_release)(self.handle);
+        unsafe { (self.api.k4a_image_release)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
 
 impl Clone for Image {
     fn clone(&self) -> Self {
-        (self.api.k4a_image_reference)(self.handle);
-        Image::from_handle(self.api.clone(), self.handle)
+        unsafe { (self.api.k4a_image_reference)(self.handle) };
+        Image {
+            api: self.api.clone(),
+            handle: self.handle,
+            owner: self.owner.clone(),
+        }
     }
 }
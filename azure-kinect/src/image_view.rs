@@ -0,0 +1,334 @@
+//! Typed access to the pixels of an [`Image`], row by row or by `(x, y)`.
+//!
+//! Rows of an image may be padded, so a view steps through its buffer by the stride of the image
+//! rather than by its width:
+//!
+//! ```no_run
+//! # use azure_kinect::*;
+//! # fn nearest(capture: &Capture) -> Result<(), Error> {
+//! let depth = capture.get_depth_image();
+//! let view = depth.as_depth16()?;
+//! let nearest = view.rows().flatten().filter(|&&mm| mm != 0).min();
+//! println!("nearest: {:?} mm, center: {} mm", nearest, view[(view.width() / 2, view.height() / 2)]);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+/// Types of the pixels an image buffer is made of: `u8`, `u16` and `[u8; 4]`. Any bit pattern is
+/// a valid pixel, so a buffer can be read as one of these, and one of these as a buffer.
+pub trait Pixel: sealed::Sealed + Copy + Send + 'static {}
+
+impl Pixel for u8 {}
+impl Pixel for u16 {}
+impl Pixel for [u8; 4] {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for [u8; 4] {}
+}
+
+/// Pixels of an image, borrowed from it with [`Image::as_depth16`] and similar methods.
+pub struct ImageView<'a, T> {
+    pixels: &'a [T],
+    width: usize,
+    height: usize,
+    /// Pixels from the start of a row to the start of the next.
+    stride: usize,
+}
+
+/// Pixels of an image, borrowed mutably with [`Image::as_depth16_mut`] and similar methods.
+pub struct ImageViewMut<'a, T> {
+    pixels: &'a mut [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a, T> ImageView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Pixels of row `y`, without the padding at its end.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is not less than the height.
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(
+            y < self.height,
+            "row {} of an image {} high",
+            y,
+            self.height
+        );
+        &self.pixels[y * self.stride..][..self.width]
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// The pixel at column `x` of row `y`, or `None` outside of the image.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            Some(&self.pixels[y * self.stride + x])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ImageViewMut<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Reborrows the pixels as an [`ImageView`].
+    pub fn as_view(&self) -> ImageView<'_, T> {
+        ImageView {
+            pixels: self.pixels,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+        }
+    }
+
+    /// Pixels of row `y`, without the padding at its end.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is not less than the height.
+    pub fn row(&self, y: usize) -> &[T] {
+        self.as_view().row(y)
+    }
+
+    /// Pixels of row `y`, without the padding at its end.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is not less than the height.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(
+            y < self.height,
+            "row {} of an image {} high",
+            y,
+            self.height
+        );
+        &mut self.pixels[y * self.stride..][..self.width]
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        RowsMut {
+            rest: &mut *self.pixels,
+            width: self.width,
+            stride: self.stride,
+            remaining: self.height,
+        }
+    }
+
+    /// The pixel at column `x` of row `y`, or `None` outside of the image.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.pixels[y * self.stride + x])
+        } else {
+            None
+        }
+    }
+
+    /// The pixel at column `x` of row `y`, or `None` outside of the image.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.pixels[y * self.stride + x])
+        } else {
+            None
+        }
+    }
+}
+
+struct RowsMut<'a, T> {
+    rest: &'a mut [T],
+    width: usize,
+    stride: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let rest = mem::take(&mut self.rest);
+        // The last row may end without its padding.
+        let (row, rest) = if self.remaining > 0 {
+            rest.split_at_mut(self.stride)
+        } else {
+            (rest, Default::default())
+        };
+        self.rest = rest;
+        Some(&mut row[..self.width])
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for ImageView<'a, T> {
+    type Output = T;
+
+    /// The pixel at `(x, y)`. Panics outside of the image.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| out_of_bounds(x, y, self.width, self.height))
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for ImageViewMut<'a, T> {
+    type Output = T;
+
+    /// The pixel at `(x, y)`. Panics outside of the image.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| out_of_bounds(x, y, self.width, self.height))
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for ImageViewMut<'a, T> {
+    /// The pixel at `(x, y)`. Panics outside of the image.
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| out_of_bounds(x, y, width, height))
+    }
+}
+
+fn out_of_bounds(x: usize, y: usize, width: usize, height: usize) -> ! {
+    panic!(
+        "pixel ({}, {}) outside of a {}x{} image",
+        x, y, width, height
+    )
+}
+
+/// Where the pixels of an image are in its buffer.
+struct Layout {
+    width: usize,
+    height: usize,
+    stride: usize,
+    /// Pixels from the first of the first row to the last of the last row.
+    len: usize,
+}
+
+/// Checks that `image` has `format` and a buffer that holds its rows of `T`.
+fn layout<T: Pixel>(image: &Image, format: k4a_image_format_t) -> Result<Layout, Error> {
+    let invalid = |message: String| Err(Error::new(ErrorKind::InvalidImage(message)));
+    if image.handle.is_null() {
+        return invalid("the image is missing from the capture".into());
+    }
+    let actual = image.get_format();
+    if actual != format {
+        return invalid(format!("{:?} image, not {:?}", actual, format));
+    }
+    let size = mem::size_of::<T>();
+    let (width, height, stride_bytes) = (
+        image.get_width_pixels().max(0) as usize,
+        image.get_height_pixels().max(0) as usize,
+        image.get_stride_bytes().max(0) as usize,
+    );
+    if stride_bytes % size != 0 || stride_bytes / size < width {
+        return invalid(format!(
+            "a stride of {} bytes does not hold {} pixels of {} bytes",
+            stride_bytes, width, size
+        ));
+    }
+    let stride = stride_bytes / size;
+    let len = match height {
+        0 => 0,
+        height => (height - 1) * stride + width,
+    };
+    if len * size > image.get_size() {
+        return invalid(format!(
+            "a buffer of {} bytes does not hold {} rows of {} bytes",
+            image.get_size(),
+            height,
+            stride_bytes
+        ));
+    }
+    if len > 0 && image.get_buffer() as usize & (mem::align_of::<T>() - 1) != 0 {
+        return invalid(format!(
+            "the buffer is not aligned to {} bytes",
+            mem::align_of::<T>()
+        ));
+    }
+    Ok(Layout {
+        width,
+        height,
+        stride,
+        len,
+    })
+}
+
+pub(crate) fn view<T: Pixel>(
+    image: &Image,
+    format: k4a_image_format_t,
+) -> Result<ImageView<'_, T>, Error> {
+    let layout = layout::<T>(image, format)?;
+    let pixels = match layout.len {
+        0 => &[],
+        // The layout checks size and alignment, and any bytes are a valid `T`.
+        len => unsafe { slice::from_raw_parts(image.get_buffer() as *const T, len) },
+    };
+    Ok(ImageView {
+        pixels,
+        width: layout.width,
+        height: layout.height,
+        stride: layout.stride,
+    })
+}
+
+pub(crate) fn view_mut<T: Pixel>(
+    image: &mut Image,
+    format: k4a_image_format_t,
+) -> Result<ImageViewMut<'_, T>, Error> {
+    let layout = layout::<T>(image, format)?;
+    if !image.is_unique() {
+        return Err(Error::new(ErrorKind::InvalidImage(
+            "the buffer may be shared with other images; only an image created with \
+             `Image::with_format` or `Image::with_buffer`, not cloned and never set on a capture, \
+             can be written to"
+                .into(),
+        )));
+    }
+    let pixels = match layout.len {
+        0 => &mut [],
+        // No other `Image` refers to the buffer, and `image` stays borrowed with the view.
+        len => unsafe { slice::from_raw_parts_mut(image.get_mut_buffer() as *mut T, len) },
+    };
+    Ok(ImageViewMut {
+        pixels,
+        width: layout.width,
+        height: layout.height,
+        stride: layout.stride,
+    })
+}
@@ -0,0 +1,233 @@
+use super::*;
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Accelerometer and gyroscope readings at one instant, interpolated between IMU samples.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ImuReading {
+    pub timestamp_usec: u64,
+    /// Celsius.
+    pub temperature: f32,
+    /// Meters per second squared.
+    pub acc: [f32; 3],
+    /// Radians per second.
+    pub gyro: [f32; 3],
+}
+
+/// The latest IMU samples, ordered by timestamp. Once it holds `capacity` samples, each new one
+/// replaces the oldest.
+///
+/// Samples are found by accelerometer timestamp. The accelerometer and gyroscope are sampled at
+/// slightly different times, so [`ImuBuffer::interpolate`] uses the timestamps of each.
+#[derive(Clone)]
+pub struct ImuBuffer {
+    samples: VecDeque<k4a_imu_sample_t>,
+    capacity: usize,
+}
+
+impl ImuBuffer {
+    pub fn new(capacity: usize) -> ImuBuffer {
+        ImuBuffer {
+            samples: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Adds a sample. Samples that are not newer than the newest one are ignored.
+    pub fn push(&mut self, sample: k4a_imu_sample_t) {
+        if let Some(newest) = self.samples.back() {
+            if sample.acc_timestamp_usec <= newest.acc_timestamp_usec {
+                return;
+            }
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Accelerometer timestamps of the oldest and the newest sample.
+    pub fn time_range(&self) -> Option<(u64, u64)> {
+        match (self.samples.front(), self.samples.back()) {
+            (Some(oldest), Some(newest)) => {
+                Some((oldest.acc_timestamp_usec, newest.acc_timestamp_usec))
+            }
+            _ => None,
+        }
+    }
+
+    /// The samples with an accelerometer timestamp from `start_usec` up to, but excluding,
+    /// `end_usec`, oldest first.
+    pub fn between(
+        &self,
+        start_usec: u64,
+        end_usec: u64,
+    ) -> impl Iterator<Item = &k4a_imu_sample_t> + '_ {
+        let start = self
+            .samples
+            .partition_point(|s| s.acc_timestamp_usec < start_usec);
+        let end = self
+            .samples
+            .partition_point(|s| s.acc_timestamp_usec < end_usec)
+            .max(start);
+        self.samples.range(start..end)
+    }
+
+    /// Readings at `timestamp_usec`, interpolated linearly between the samples around it. `None`
+    /// if no sample is buffered on either side of it yet, or anymore.
+    pub fn interpolate(&self, timestamp_usec: u64) -> Option<ImuReading> {
+        let (acc, temperature) = self.lerp(
+            timestamp_usec,
+            |s| s.acc_timestamp_usec,
+            |s| (unsafe { s.acc_sample.v }, s.temperature),
+        )?;
+        let (gyro, _) = self.lerp(
+            timestamp_usec,
+            |s| s.gyro_timestamp_usec,
+            |s| (unsafe { s.gyro_sample.v }, s.temperature),
+        )?;
+        Some(ImuReading {
+            timestamp_usec,
+            temperature,
+            acc,
+            gyro,
+        })
+    }
+
+    fn lerp(
+        &self,
+        timestamp_usec: u64,
+        timestamp: impl Fn(&k4a_imu_sample_t) -> u64,
+        value: impl Fn(&k4a_imu_sample_t) -> ([f32; 3], f32),
+    ) -> Option<([f32; 3], f32)> {
+        let after = self
+            .samples
+            .partition_point(|s| timestamp(s) < timestamp_usec);
+        let next = self.samples.get(after)?;
+        if timestamp(next) == timestamp_usec {
+            return Some(value(next));
+        }
+        let previous = self.samples.get(after.checked_sub(1)?)?;
+        let t = (timestamp_usec - timestamp(previous)) as f32
+            / (timestamp(next) - timestamp(previous)) as f32;
+        let ((a, a_temperature), (b, b_temperature)) = (value(previous), value(next));
+        Some((
+            [
+                a[0] + (b[0] - a[0]) * t,
+                a[1] + (b[1] - a[1]) * t,
+                a[2] + (b[2] - a[2]) * t,
+            ],
+            a_temperature + (b_temperature - a_temperature) * t,
+        ))
+    }
+}
+
+/// An [`ImuBuffer`] filled by the IMU thread of a [`CaptureStream`].
+pub(crate) struct ImuHistory {
+    state: Mutex<(ImuBuffer, Option<Error>)>,
+    /// Signalled when a sample is added or the IMU thread has finished.
+    pushed: Condvar,
+}
+
+impl ImuHistory {
+    pub(crate) fn new(capacity: usize) -> ImuHistory {
+        ImuHistory {
+            state: Mutex::new((ImuBuffer::new(capacity), None)),
+            pushed: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn push(&self, sample: k4a_imu_sample_t) {
+        self.state.lock().unwrap().0.push(sample);
+        self.pushed.notify_all();
+    }
+
+    pub(crate) fn finish(&self, error: Error) {
+        self.state.lock().unwrap().1 = Some(error);
+        self.pushed.notify_all();
+    }
+
+    /// Waits until a sample at or after `end_usec` has arrived, so that none of the samples
+    /// between `start_usec` and `end_usec` are still to come, and returns them.
+    pub(crate) fn between(
+        &self,
+        start_usec: u64,
+        end_usec: u64,
+        timeout_in_ms: i32,
+    ) -> Result<Vec<k4a_imu_sample_t>, Error> {
+        self.wait(timeout_in_ms, |buffer| match buffer.time_range() {
+            Some((_, newest)) if newest >= end_usec => {
+                Some(buffer.between(start_usec, end_usec).copied().collect())
+            }
+            _ => None,
+        })
+    }
+
+    /// Waits until the samples after `timestamp_usec` have arrived, and interpolates them.
+    pub(crate) fn interpolate(
+        &self,
+        timestamp_usec: u64,
+        timeout_in_ms: i32,
+    ) -> Result<ImuReading, Error> {
+        let reading = self.wait(timeout_in_ms, |buffer| {
+            if let Some(reading) = buffer.interpolate(timestamp_usec) {
+                return Some(Some(reading));
+            }
+            // Samples that old are gone for good.
+            match buffer.time_range() {
+                Some((oldest, _)) if timestamp_usec < oldest => Some(None),
+                _ => None,
+            }
+        })?;
+        reading.ok_or_else(|| Error::new(ErrorKind::Failed))
+    }
+
+    /// Calls `ready` with the buffer each time a sample is added, until it returns a value.
+    fn wait<R>(
+        &self,
+        timeout_in_ms: i32,
+        mut ready: impl FnMut(&ImuBuffer) -> Option<R>,
+    ) -> Result<R, Error> {
+        let deadline = match timeout_in_ms {
+            K4A_WAIT_INFINITE => None,
+            timeout => Some(Instant::now() + Duration::from_millis(timeout.max(0) as u64)),
+        };
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let (buffer, finished) = &*state;
+            if let Some(value) = ready(buffer) {
+                return Ok(value);
+            }
+            if let Some(error) = finished {
+                return Err(error.clone());
+            }
+            state = match deadline {
+                None => self.pushed.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::new(ErrorKind::Timeout));
+                    }
+                    self.pushed.wait_timeout(state, deadline - now).unwrap().0
+                }
+            };
+        }
+    }
+}
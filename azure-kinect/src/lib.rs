@@ -2,51 +2,104 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
+pub mod allocator;
 pub mod api;
+#[cfg(feature = "async")]
+pub mod async_stream;
 pub mod calibration;
 pub mod capture;
+pub mod color_control;
+pub mod configuration;
+mod convert;
 pub mod default;
 pub mod device;
 pub mod display;
 pub mod error;
 pub mod format;
 pub mod frame;
+pub mod group;
+pub mod health;
 pub mod image;
+pub mod image_view;
+pub mod imu;
 pub mod k4a_functions;
 pub mod k4abt_functions;
+#[cfg(feature = "link")]
+mod linked;
+mod loader;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub mod logging;
 pub mod playback;
 pub mod playback_data_block;
 pub mod playback_track;
 pub mod record;
+pub mod search;
+pub mod session;
+pub mod simulated;
+pub mod stream;
+pub mod supervisor;
 pub mod tracker;
 pub mod transformation;
 pub mod utility;
+pub mod version;
 
+/// Types and constants of the SDK headers. Pre-generated for SDK 1.4 with the `sdk-1-4` feature,
+/// the only version shipped pre-generated, or generated at build time with the `bindgen` feature.
+// `linked` declares the same functions with placeholder signatures.
+#[allow(clippy::all, clashing_extern_declarations)]
 pub mod bindings {
-    include!("bindings.rs");
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+    #[cfg(all(not(feature = "bindgen"), feature = "sdk-1-4", windows))]
+    include!("bindings/v1_4_windows.rs");
+    #[cfg(all(not(feature = "bindgen"), feature = "sdk-1-4", not(windows)))]
+    include!("bindings/v1_4_linux.rs");
+
+    #[cfg(not(any(feature = "bindgen", feature = "sdk-1-4")))]
+    compile_error!("enable an `sdk-*` feature for pre-generated bindings, or `bindgen`");
 }
 
+pub use allocator::{AllocationStats, Allocator, SystemAllocator};
 pub use api::{Api, ApiRecord, ApiTracker, DebugMessageHandler};
 pub use calibration::Calibration;
 pub use capture::Capture;
-pub use device::Device;
-pub use error::Error;
+pub use color_control::{
+    ColorControl, ColorControlCapabilities, ColorControlProfile, ControlMode, PowerlineFrequency,
+};
+pub use configuration::DeviceConfiguration;
+pub use device::{Device, DeviceInfo};
+pub(crate) use error::Status;
+pub use error::{DeviceId, Error, ErrorKind, SdkMessage};
 pub use format::{Dimension, Range};
 pub use frame::Frame;
+pub use group::DeviceGroup;
+pub use health::{HealthReport, Spread, StreamHealth, TimingReport};
 pub use image::Image;
+pub use image_view::{ImageView, ImageViewMut, Pixel};
+pub use imu::{ImuBuffer, ImuReading};
+pub use search::{LibrarySearch, SearchAttempt, SearchReport};
+pub use session::{CameraSession, ImuSession};
+pub use stream::{CaptureStream, OverflowPolicy};
+pub use supervisor::{DeviceEvent, DeviceEventHandler, Supervisor, SupervisorBuilder};
 pub use tracker::Tracker;
 pub use transformation::Transformation;
+pub use version::{
+    Capability, DeviceFirmware, FirmwareBuild, FirmwareMismatch, FirmwareRequirement,
+    FirmwareSignature, FirmwareSubsystem, FirmwareVersion, SdkVersion,
+};
 
 pub use bindings::{
     k4a_buffer_result_t, k4a_calibration_camera_t, k4a_calibration_extrinsics_t,
     k4a_calibration_intrinsics_t, k4a_calibration_t, k4a_calibration_type_t, k4a_capture_t,
     k4a_color_control_command_t, k4a_color_control_mode_t, k4a_color_resolution_t,
-    k4a_depth_mode_t, k4a_device_configuration_t, k4a_device_t, k4a_firmware_build_t, k4a_float2_t,
-    k4a_float3_t, k4a_fps_t, k4a_hardware_version_t, k4a_image_format_t, k4a_image_t,
-    k4a_imu_sample_t, k4a_log_level_t, k4a_memory_destroy_cb_t, k4a_playback_data_block_t,
-    k4a_playback_seek_origin_t, k4a_playback_t, k4a_record_configuration_t,
-    k4a_record_subtitle_settings_t, k4a_record_t, k4a_record_video_settings_t, k4a_result_t,
-    k4a_stream_result_t, k4a_transformation_interpolation_type_t, k4a_transformation_t,
+    k4a_depth_mode_t, k4a_device_configuration_t, k4a_device_t, k4a_firmware_build_t,
+    k4a_firmware_signature_t, k4a_float2_t, k4a_float3_t, k4a_fps_t, k4a_hardware_version_t,
+    k4a_image_format_t, k4a_image_t, k4a_imu_sample_t, k4a_log_level_t, k4a_memory_destroy_cb_t,
+    k4a_playback_data_block_t, k4a_playback_seek_origin_t, k4a_playback_t,
+    k4a_record_configuration_t, k4a_record_subtitle_settings_t, k4a_record_t,
+    k4a_record_video_settings_t, k4a_result_t, k4a_stream_result_t,
+    k4a_transformation_interpolation_type_t, k4a_transformation_t, k4a_version_t,
     k4a_wait_result_t, k4a_wired_sync_mode_t, k4abt_body_t, k4abt_frame_t, k4abt_joint_id_t,
     k4abt_sensor_orientation_t, k4abt_skeleton_t, k4abt_tracker_configuration_t,
     k4abt_tracker_processing_mode_t, k4abt_tracker_t, size_t, K4A_DEVICE_DEFAULT,
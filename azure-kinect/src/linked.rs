@@ -0,0 +1,172 @@
+//! Entry points resolved by the linker instead of `dlopen`/`LoadLibrary`, for the `link` and
+//! `link-k4abt` features. The tables of [`Api`](crate::Api), [`ApiRecord`](crate::ApiRecord) and
+//! [`ApiTracker`](crate::ApiTracker) are filled from these exactly like from a loaded library, so
+//! a missing entry point is a link error rather than a runtime one.
+
+use std::ffi::c_void;
+
+/// Declares the entry points of a library with a placeholder signature. Only their addresses
+/// are taken; they are called through the typed tables in `k4a_functions` and `k4abt_functions`.
+macro_rules! linked_library {
+    ($module:ident, $library:literal, [$($name:ident),* $(,)?]) => {
+        mod $module {
+            #[link(name = $library)]
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                $(fn $name();)*
+            }
+
+            pub(super) fn symbol(name: &str) -> Option<*const super::c_void> {
+                match name {
+                    $(stringify!($name) => Some($name as *const super::c_void),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+linked_library!(
+    k4a,
+    "k4a",
+    [
+        k4a_device_get_installed_count,
+        k4a_set_debug_message_handler,
+        k4a_set_allocator,
+        k4a_device_open,
+        k4a_device_close,
+        k4a_device_get_capture,
+        k4a_device_get_imu_sample,
+        k4a_capture_create,
+        k4a_capture_release,
+        k4a_capture_reference,
+        k4a_capture_get_color_image,
+        k4a_capture_get_depth_image,
+        k4a_capture_get_ir_image,
+        k4a_capture_set_color_image,
+        k4a_capture_set_depth_image,
+        k4a_capture_set_ir_image,
+        k4a_capture_set_temperature_c,
+        k4a_capture_get_temperature_c,
+        k4a_image_create,
+        k4a_image_create_from_buffer,
+        k4a_image_get_buffer,
+        k4a_image_get_size,
+        k4a_image_get_format,
+        k4a_image_get_width_pixels,
+        k4a_image_get_height_pixels,
+        k4a_image_get_stride_bytes,
+        k4a_image_get_device_timestamp_usec,
+        k4a_image_get_system_timestamp_nsec,
+        k4a_image_get_exposure_usec,
+        k4a_image_get_white_balance,
+        k4a_image_get_iso_speed,
+        k4a_image_set_device_timestamp_usec,
+        k4a_image_set_system_timestamp_nsec,
+        k4a_image_set_exposure_usec,
+        k4a_image_set_white_balance,
+        k4a_image_set_iso_speed,
+        k4a_image_reference,
+        k4a_image_release,
+        k4a_device_start_cameras,
+        k4a_device_stop_cameras,
+        k4a_device_start_imu,
+        k4a_device_stop_imu,
+        k4a_device_get_serialnum,
+        k4a_device_get_version,
+        k4a_device_get_color_control_capabilities,
+        k4a_device_get_color_control,
+        k4a_device_set_color_control,
+        k4a_device_get_raw_calibration,
+        k4a_device_get_calibration,
+        k4a_device_get_sync_jack,
+        k4a_calibration_get_from_raw,
+        k4a_calibration_3d_to_3d,
+        k4a_calibration_2d_to_3d,
+        k4a_calibration_3d_to_2d,
+        k4a_calibration_2d_to_2d,
+        k4a_calibration_color_2d_to_depth_2d,
+        k4a_transformation_create,
+        k4a_transformation_destroy,
+        k4a_transformation_depth_image_to_color_camera,
+        k4a_transformation_depth_image_to_color_camera_custom,
+        k4a_transformation_color_image_to_depth_camera,
+        k4a_transformation_depth_image_to_point_cloud,
+    ]
+);
+
+linked_library!(
+    k4arecord,
+    "k4arecord",
+    [
+        k4a_playback_open,
+        k4a_playback_get_raw_calibration,
+        k4a_playback_get_calibration,
+        k4a_playback_get_record_configuration,
+        k4a_playback_check_track_exists,
+        k4a_playback_get_track_count,
+        k4a_playback_get_track_name,
+        k4a_playback_track_is_builtin,
+        k4a_playback_track_get_video_settings,
+        k4a_playback_track_get_codec_id,
+        k4a_playback_track_get_codec_context,
+        k4a_playback_get_tag,
+        k4a_playback_set_color_conversion,
+        k4a_playback_get_attachment,
+        k4a_playback_get_next_capture,
+        k4a_playback_get_previous_capture,
+        k4a_playback_get_next_imu_sample,
+        k4a_playback_get_previous_imu_sample,
+        k4a_playback_get_next_data_block,
+        k4a_playback_get_previous_data_block,
+        k4a_playback_data_block_get_device_timestamp_usec,
+        k4a_playback_data_block_get_buffer_size,
+        k4a_playback_data_block_get_buffer,
+        k4a_playback_data_block_release,
+        k4a_playback_seek_timestamp,
+        k4a_playback_get_recording_length_usec,
+        k4a_playback_close,
+        k4a_record_create,
+        k4a_record_add_tag,
+        k4a_record_add_imu_track,
+        k4a_record_add_attachment,
+        k4a_record_add_custom_video_track,
+        k4a_record_add_custom_subtitle_track,
+        k4a_record_write_header,
+        k4a_record_write_capture,
+        k4a_record_write_imu_sample,
+        k4a_record_write_custom_track_data,
+        k4a_record_flush,
+        k4a_record_close,
+    ]
+);
+
+#[cfg(feature = "link-k4abt")]
+linked_library!(
+    k4abt,
+    "k4abt",
+    [
+        k4abt_tracker_create,
+        k4abt_tracker_destroy,
+        k4abt_tracker_set_temporal_smoothing,
+        k4abt_tracker_enqueue_capture,
+        k4abt_tracker_pop_result,
+        k4abt_tracker_shutdown,
+        k4abt_frame_release,
+        k4abt_frame_reference,
+        k4abt_frame_get_num_bodies,
+        k4abt_frame_get_body_skeleton,
+        k4abt_frame_get_body_id,
+        k4abt_frame_get_device_timestamp_usec,
+        k4abt_frame_get_body_index_map,
+        k4abt_frame_get_capture,
+    ]
+);
+
+/// Looks up a linked entry point by name.
+pub(crate) fn symbol(name: &str) -> Option<*const c_void> {
+    let symbol = k4a::symbol(name).or_else(|| k4arecord::symbol(name));
+    #[cfg(feature = "link-k4abt")]
+    let symbol = symbol.or_else(|| k4abt::symbol(name));
+    symbol
+}
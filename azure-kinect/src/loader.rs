@@ -0,0 +1,474 @@
+use super::*;
+use crate::search::{LibrarySearch, SearchAttempt, SearchReport};
+use std::ffi::c_void;
+use std::path::{Path, PathBuf};
+
+/// Stands in for the handle of a library whose entry points were resolved by the linker, see
+/// the `link` feature.
+#[cfg(feature = "link")]
+pub(crate) const LINKED: *const c_void = usize::MAX as *const c_void;
+
+/// An SDK library and the places it is installed to.
+pub(crate) struct Library {
+    pub(crate) name: &'static str,
+    /// Candidate file names, preferred first.
+    pub(crate) file_names: &'static [&'static str],
+    /// Environment variable holding a list of directories to search.
+    pub(crate) environment_variable: &'static str,
+    /// Prefix of the install folders of the SDK under `%ProgramFiles%`.
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) install_folder: &'static str,
+}
+
+#[cfg(windows)]
+const K4A_LIBNAMES: &[&str] = &["k4a.dll"];
+#[cfg(windows)]
+const K4ARECORD_LIBNAMES: &[&str] = &["k4arecord.dll"];
+#[cfg(windows)]
+const K4ABT_LIBNAMES: &[&str] = &["k4abt.dll"];
+#[cfg(windows)]
+const DEPTHENGINE_LIBNAMES: &[&str] = &["depthengine_2_0.dll"];
+
+#[cfg(unix)]
+const K4A_LIBNAMES: &[&str] = &["libk4a.so.1.4", "libk4a.so.1.3", "libk4a.so"];
+#[cfg(unix)]
+const K4ARECORD_LIBNAMES: &[&str] = &[
+    "libk4arecord.so.1.4",
+    "libk4arecord.so.1.3",
+    "libk4arecord.so",
+];
+#[cfg(unix)]
+const K4ABT_LIBNAMES: &[&str] = &["libk4abt.so.1.1", "libk4abt.so.1.0", "libk4abt.so"];
+#[cfg(unix)]
+const DEPTHENGINE_LIBNAMES: &[&str] = &["libdepthengine.so.2.0"];
+
+pub(crate) const K4A: Library = Library {
+    name: "k4a",
+    file_names: K4A_LIBNAMES,
+    environment_variable: "K4A_SDK_DIR",
+    install_folder: "Azure Kinect SDK",
+};
+
+pub(crate) const K4ARECORD: Library = Library {
+    name: "k4arecord",
+    file_names: K4ARECORD_LIBNAMES,
+    environment_variable: "K4A_SDK_DIR",
+    install_folder: "Azure Kinect SDK",
+};
+
+pub(crate) const K4ABT: Library = Library {
+    name: "k4abt",
+    file_names: K4ABT_LIBNAMES,
+    environment_variable: "K4ABT_SDK_DIR",
+    install_folder: "Azure Kinect Body Tracking SDK",
+};
+
+pub(crate) const DEPTHENGINE: Library = Library {
+    name: "depthengine",
+    file_names: DEPTHENGINE_LIBNAMES,
+    environment_variable: "K4A_SDK_DIR",
+    install_folder: "Azure Kinect SDK",
+};
+
+#[cfg(windows)]
+mod platform {
+    use super::*;
+    use std::ptr;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetLastError() -> u32;
+        fn LoadLibraryExW(
+            lpLibFileName: *const u16,
+            hFile: *const c_void,
+            dwFlags: u32,
+        ) -> *const c_void;
+        fn FreeLibrary(hLibModule: *const c_void) -> i32;
+        fn GetProcAddress(hModule: *const c_void, lpProcName: *const u8) -> *const c_void;
+        fn GetModuleFileNameW(hModule: *const c_void, lpFilename: *mut u16, nSize: u32) -> u32;
+        fn GetModuleHandleExW(
+            dwFlags: u32,
+            lpModuleName: *const c_void,
+            phModule: *mut *const c_void,
+        ) -> i32;
+    }
+
+    const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+    const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+
+    #[link(name = "version")]
+    extern "system" {
+        fn GetFileVersionInfoSizeW(lptstrFilename: *const u16, lpdwHandle: *mut u32) -> u32;
+        fn GetFileVersionInfoW(
+            lptstrFilename: *const u16,
+            dwHandle: u32,
+            dwLen: u32,
+            lpData: *mut c_void,
+        ) -> i32;
+        fn VerQueryValueW(
+            pBlock: *const c_void,
+            lpSubBlock: *const u16,
+            lplpBuffer: *mut *mut c_void,
+            puLen: *mut u32,
+        ) -> i32;
+    }
+
+    #[repr(C)]
+    struct VS_FIXEDFILEINFO {
+        dwSignature: u32,
+        dwStrucVersion: u32,
+        dwFileVersionMS: u32,
+        dwFileVersionLS: u32,
+        dwProductVersionMS: u32,
+        dwProductVersionLS: u32,
+        dwFileFlagsMask: u32,
+        dwFileFlags: u32,
+        dwFileOS: u32,
+        dwFileType: u32,
+        dwFileSubtype: u32,
+        dwFileDateMS: u32,
+        dwFileDateLS: u32,
+    }
+
+    /// Resolves the dependencies of a library loaded from an absolute path, such as
+    /// `k4arecord.dll` needing `k4a.dll`, next to it first.
+    const LOAD_WITH_ALTERED_SEARCH_PATH: u32 = 0x008;
+
+    pub(crate) fn open(path: &Path) -> Result<*const c_void, Error> {
+        let flags = if path.is_absolute() {
+            LOAD_WITH_ALTERED_SEARCH_PATH
+        } else {
+            0
+        };
+        let path = path
+            .to_str()
+            .ok_or(Error::new(ErrorKind::Failed))?
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<u16>>();
+        unsafe { check(LoadLibraryExW(path.as_ptr(), ptr::null(), flags)) }
+    }
+
+    pub(crate) unsafe fn symbol(handle: *const c_void, name: &str) -> Result<*const c_void, Error> {
+        check(GetProcAddress(handle, name.as_ptr()))
+    }
+
+    pub(crate) unsafe fn close(handle: *const c_void) {
+        FreeLibrary(handle);
+    }
+
+    /// Finds the module that defines `symbol`, which also covers libraries resolved by the
+    /// linker rather than loaded through a handle.
+    pub(crate) unsafe fn module_path(
+        _handle: *const c_void,
+        symbol: *const c_void,
+    ) -> Option<PathBuf> {
+        use std::os::windows::ffi::OsStringExt;
+        let mut handle = ptr::null();
+        if GetModuleHandleExW(
+            GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+            symbol,
+            &mut handle,
+        ) == 0
+        {
+            return None;
+        }
+        let mut path = vec![0u16; 32768];
+        let len = GetModuleFileNameW(handle, path.as_mut_ptr(), path.len() as u32) as usize;
+        if len == 0 || len >= path.len() {
+            return None;
+        }
+        Some(std::ffi::OsString::from_wide(&path[..len]).into())
+    }
+
+    /// Reads the file version resource of a library.
+    pub(crate) unsafe fn file_version(path: &Path) -> Option<SdkVersion> {
+        let path = path
+            .to_str()?
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<u16>>();
+
+        let mut unused = 0;
+        let size = GetFileVersionInfoSizeW(path.as_ptr(), &mut unused);
+        if size == 0 {
+            return None;
+        }
+        let mut data = vec![0u8; size as usize];
+        if GetFileVersionInfoW(path.as_ptr(), 0, size, data.as_mut_ptr() as *mut c_void) == 0 {
+            return None;
+        }
+        let root = [b'\\' as u16, 0];
+        let mut info: *mut c_void = ptr::null_mut();
+        let mut info_len = 0;
+        if VerQueryValueW(
+            data.as_ptr() as *const c_void,
+            root.as_ptr(),
+            &mut info,
+            &mut info_len,
+        ) == 0
+            || (info_len as usize) < std::mem::size_of::<VS_FIXEDFILEINFO>()
+        {
+            return None;
+        }
+        let info = &*(info as *const VS_FIXEDFILEINFO);
+        Some(SdkVersion::new(
+            info.dwFileVersionMS >> 16,
+            info.dwFileVersionMS & 0xffff,
+            info.dwFileVersionLS >> 16,
+        ))
+    }
+
+    /// The `bin` folders of the installed SDKs, newest version first.
+    pub(crate) fn standard_directories(library: &Library) -> Vec<Option<PathBuf>> {
+        let program_files = match std::env::var_os("ProgramFiles") {
+            Some(program_files) => PathBuf::from(program_files),
+            None => return Vec::new(),
+        };
+        let mut folders = std::fs::read_dir(program_files)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| name.starts_with(library.install_folder))
+            })
+            .collect::<Vec<_>>();
+        // Folders are named like `Azure Kinect SDK v1.4.1`.
+        folders.sort_by_key(|folder| {
+            std::cmp::Reverse(
+                folder
+                    .to_str()
+                    .and_then(|name| name.rsplit_once(" v"))
+                    .and_then(|(_, version)| SdkVersion::parse(version)),
+            )
+        });
+        folders
+            .into_iter()
+            .map(|folder| Some(folder.join(r"sdk\windows-desktop\amd64\release\bin")))
+            .collect()
+    }
+
+    unsafe fn check(p: *const c_void) -> Result<*const c_void, Error> {
+        if p.is_null() {
+            Err(Error::new(ErrorKind::Win32Error(GetLastError())))
+        } else {
+            Ok(p)
+        }
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use super::*;
+    use std::ffi::{CStr, CString};
+    use std::os::raw;
+
+    const RTLD_NOW: raw::c_int = 2;
+
+    #[link(name = "dl")]
+    extern "C" {
+        fn dlopen(filename: *const raw::c_char, flag: raw::c_int) -> *mut c_void;
+        fn dlsym(handle: *mut c_void, symbol: *const raw::c_char) -> *mut c_void;
+        fn dlclose(handle: *mut c_void) -> raw::c_int;
+        fn dlerror() -> *mut raw::c_char;
+        fn dladdr(addr: *const c_void, info: *mut Dl_info) -> raw::c_int;
+    }
+
+    #[repr(C)]
+    struct Dl_info {
+        dli_fname: *const raw::c_char,
+        dli_fbase: *mut c_void,
+        dli_sname: *const raw::c_char,
+        dli_saddr: *mut c_void,
+    }
+
+    pub(crate) fn open(path: &Path) -> Result<*const c_void, Error> {
+        use std::os::unix::ffi::OsStrExt;
+        let path =
+            CString::new(path.as_os_str().as_bytes()).map_err(|_| Error::new(ErrorKind::Failed))?;
+        unsafe {
+            dlerror();
+            check(dlopen(path.as_ptr(), RTLD_NOW))
+        }
+    }
+
+    pub(crate) unsafe fn symbol(handle: *const c_void, name: &str) -> Result<*const c_void, Error> {
+        dlerror();
+        check(dlsym(
+            handle as *mut c_void,
+            name.as_ptr() as *const raw::c_char,
+        ))
+    }
+
+    pub(crate) unsafe fn close(handle: *const c_void) {
+        dlclose(handle as *mut c_void);
+    }
+
+    /// Finds the file that defines `symbol`.
+    pub(crate) unsafe fn module_path(
+        _handle: *const c_void,
+        symbol: *const c_void,
+    ) -> Option<PathBuf> {
+        use std::os::unix::ffi::OsStrExt;
+        let mut info = std::mem::zeroed::<Dl_info>();
+        if dladdr(symbol, &mut info) == 0 || info.dli_fname.is_null() {
+            return None;
+        }
+        Some(std::ffi::OsStr::from_bytes(CStr::from_ptr(info.dli_fname).to_bytes()).into())
+    }
+
+    /// Parses the version from the file name the symlinks resolve to, e.g. `libk4a.so.1.4` ->
+    /// `libk4a.so.1.4.1`.
+    pub(crate) unsafe fn file_version(path: &Path) -> Option<SdkVersion> {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.into());
+        SdkVersion::from_file_name(path.file_name()?.to_str()?)
+    }
+
+    /// `None` hands the bare file name to `dlopen`, which then uses the system search path
+    /// (`LD_LIBRARY_PATH`, `ld.so.cache`, ...) the SDK packages install into.
+    pub(crate) fn standard_directories(_library: &Library) -> Vec<Option<PathBuf>> {
+        vec![None]
+    }
+
+    unsafe fn check(p: *mut c_void) -> Result<*const c_void, Error> {
+        if p.is_null() {
+            let message = dlerror();
+            Err(Error::new(ErrorKind::DlError(if message.is_null() {
+                String::from("unknown dynamic loader error")
+            } else {
+                CStr::from_ptr(message).to_string_lossy().into_owned()
+            })))
+        } else {
+            Ok(p)
+        }
+    }
+}
+
+/// The directories `search` covers for `library`, in order and without duplicates. `None`
+/// stands for the search path of the dynamic loader.
+fn directories(search: &LibrarySearch, library: &Library) -> Vec<Option<PathBuf>> {
+    let mut directories = search
+        .directories
+        .iter()
+        .cloned()
+        .map(Some)
+        .collect::<Vec<_>>();
+    if search.environment {
+        if let Some(value) = std::env::var_os(library.environment_variable) {
+            directories.extend(
+                std::env::split_paths(&value)
+                    .filter(|directory| !directory.as_os_str().is_empty())
+                    .map(Some),
+            );
+        }
+    }
+    if search.executable_directory {
+        if let Some(directory) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        {
+            directories.push(Some(directory));
+        }
+    }
+    if search.standard_locations {
+        directories.extend(platform::standard_directories(library));
+    }
+    let mut unique = Vec::with_capacity(directories.len());
+    for directory in directories {
+        if !unique.contains(&directory) {
+            unique.push(directory);
+        }
+    }
+    unique
+}
+
+/// Loads the first file of `library` found in the locations of `search`, and returns its handle
+/// and the path it was loaded from.
+pub(crate) fn load_library(
+    search: &LibrarySearch,
+    library: &Library,
+) -> Result<(*const c_void, PathBuf), Error> {
+    let mut report = SearchReport {
+        library: library.name,
+        attempts: Vec::new(),
+    };
+    for directory in directories(search, library) {
+        for file_name in library.file_names {
+            let path = match &directory {
+                Some(directory) => directory.join(file_name),
+                None => PathBuf::from(file_name),
+            };
+            match platform::open(&path) {
+                Ok(handle) => return Ok((handle, path)),
+                Err(error) => report.attempts.push(SearchAttempt { path, error }),
+            }
+        }
+    }
+    Err(Error::new(ErrorKind::LibraryNotFound(report)))
+}
+
+/// Preloads the depth engine plugin of the `k4a` library in `k4a_directory`, so that `k4a`
+/// finds it when the depth camera starts even if it is not on the loader search path.
+pub(crate) fn load_depth_engine(
+    search: &LibrarySearch,
+    k4a_directory: Option<&Path>,
+) -> Result<*const c_void, Error> {
+    let mut search = search.clone();
+    if let Some(k4a_directory) = k4a_directory {
+        let mut first = vec![k4a_directory.to_path_buf()];
+        // The Linux packages put the plugin in a versioned folder, e.g. `libk4a1.4`.
+        if cfg!(unix) {
+            first.extend(
+                std::fs::read_dir(k4a_directory)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.file_name().to_string_lossy().starts_with("libk4a1."))
+                    .map(|entry| entry.path()),
+            );
+        }
+        search.directories.splice(0..0, first);
+    }
+    load_library(&search, &DEPTHENGINE).map(|(handle, _)| handle)
+}
+
+/// Resolves a symbol. `name` must be nul terminated.
+pub(crate) unsafe fn get_proc_address(
+    handle: *const c_void,
+    name: &str,
+) -> Result<*const c_void, Error> {
+    #[cfg(feature = "link")]
+    if handle == LINKED {
+        let name = name.trim_end_matches('\0');
+        return crate::linked::symbol(name)
+            .ok_or_else(|| Error::new(ErrorKind::DlError(format!("{} is not linked", name))));
+    }
+    platform::symbol(handle, name)
+}
+
+/// Finds the file of a loaded library. `symbol` must be an address inside the library.
+pub(crate) unsafe fn library_path(handle: *const c_void, symbol: *const c_void) -> Option<PathBuf> {
+    platform::module_path(handle, symbol)
+}
+
+/// Reads the version of the loaded library from its file. `symbol` must be an address inside
+/// the library.
+pub(crate) unsafe fn library_version(
+    handle: *const c_void,
+    symbol: *const c_void,
+) -> Option<SdkVersion> {
+    platform::file_version(&platform::module_path(handle, symbol)?)
+}
+
+pub(crate) unsafe fn free_library(handle: *const c_void) {
+    #[cfg(feature = "link")]
+    if handle == LINKED {
+        return;
+    }
+    if !handle.is_null() {
+        platform::close(handle);
+    }
+}
@@ -0,0 +1,128 @@
+//! Forwards the debug messages of the SDK to the `log` and `tracing` crates, behind the features
+//! of the same names. Messages are emitted with the target `k4a` and keep the source file and
+//! line reported by the SDK.
+//!
+//! | `k4a_log_level_t` | `log`   | `tracing` |
+//! |-------------------|---------|-----------|
+//! | `CRITICAL`        | `Error` | `ERROR`   |
+//! | `ERROR`           | `Error` | `ERROR`   |
+//! | `WARNING`         | `Warn`  | `WARN`    |
+//! | `INFO`            | `Info`  | `INFO`    |
+//! | `TRACE`           | `Trace` | `TRACE`   |
+
+use super::*;
+
+/// Target of the forwarded messages.
+pub const TARGET: &str = "k4a";
+
+#[cfg(feature = "log")]
+pub fn log_level(level: k4a_log_level_t) -> Option<log::Level> {
+    match level {
+        k4a_log_level_t::K4A_LOG_LEVEL_CRITICAL | k4a_log_level_t::K4A_LOG_LEVEL_ERROR => {
+            Some(log::Level::Error)
+        }
+        k4a_log_level_t::K4A_LOG_LEVEL_WARNING => Some(log::Level::Warn),
+        k4a_log_level_t::K4A_LOG_LEVEL_INFO => Some(log::Level::Info),
+        k4a_log_level_t::K4A_LOG_LEVEL_TRACE => Some(log::Level::Trace),
+        k4a_log_level_t::K4A_LOG_LEVEL_OFF => None,
+    }
+}
+
+/// The least severe SDK level that `filter` lets through.
+#[cfg(feature = "log")]
+pub fn k4a_log_level_from_log(filter: log::LevelFilter) -> k4a_log_level_t {
+    match filter {
+        log::LevelFilter::Off => k4a_log_level_t::K4A_LOG_LEVEL_OFF,
+        log::LevelFilter::Error => k4a_log_level_t::K4A_LOG_LEVEL_ERROR,
+        log::LevelFilter::Warn => k4a_log_level_t::K4A_LOG_LEVEL_WARNING,
+        log::LevelFilter::Info => k4a_log_level_t::K4A_LOG_LEVEL_INFO,
+        log::LevelFilter::Debug | log::LevelFilter::Trace => k4a_log_level_t::K4A_LOG_LEVEL_TRACE,
+    }
+}
+
+/// A handler that emits every message as a `log` record.
+#[cfg(feature = "log")]
+pub fn log_handler() -> DebugMessageHandler {
+    Box::new(|level, file, line, message| {
+        if let Some(level) = log_level(level) {
+            log::logger().log(
+                &log::Record::builder()
+                    .level(level)
+                    .target(TARGET)
+                    .file(Some(file))
+                    .line(Some(line as u32))
+                    .args(format_args!("{}", message))
+                    .build(),
+            );
+        }
+    })
+}
+
+#[cfg(feature = "tracing")]
+pub fn tracing_level(level: k4a_log_level_t) -> Option<tracing::Level> {
+    match level {
+        k4a_log_level_t::K4A_LOG_LEVEL_CRITICAL | k4a_log_level_t::K4A_LOG_LEVEL_ERROR => {
+            Some(tracing::Level::ERROR)
+        }
+        k4a_log_level_t::K4A_LOG_LEVEL_WARNING => Some(tracing::Level::WARN),
+        k4a_log_level_t::K4A_LOG_LEVEL_INFO => Some(tracing::Level::INFO),
+        k4a_log_level_t::K4A_LOG_LEVEL_TRACE => Some(tracing::Level::TRACE),
+        k4a_log_level_t::K4A_LOG_LEVEL_OFF => None,
+    }
+}
+
+/// The least severe SDK level that `filter` lets through.
+#[cfg(feature = "tracing")]
+pub fn k4a_log_level_from_tracing(filter: tracing::level_filters::LevelFilter) -> k4a_log_level_t {
+    use tracing::level_filters::LevelFilter;
+    if filter >= LevelFilter::TRACE {
+        k4a_log_level_t::K4A_LOG_LEVEL_TRACE
+    } else if filter >= LevelFilter::INFO {
+        k4a_log_level_t::K4A_LOG_LEVEL_INFO
+    } else if filter >= LevelFilter::WARN {
+        k4a_log_level_t::K4A_LOG_LEVEL_WARNING
+    } else if filter >= LevelFilter::ERROR {
+        k4a_log_level_t::K4A_LOG_LEVEL_ERROR
+    } else {
+        k4a_log_level_t::K4A_LOG_LEVEL_OFF
+    }
+}
+
+/// A handler that emits every message as a `tracing` event.
+#[cfg(feature = "tracing")]
+pub fn tracing_handler() -> DebugMessageHandler {
+    Box::new(|level, file, line, message| {
+        // `event!` needs the level as a constant.
+        match tracing_level(level) {
+            Some(tracing::Level::ERROR) => {
+                tracing::error!(target: TARGET, file, line, "{}", message)
+            }
+            Some(tracing::Level::WARN) => tracing::warn!(target: TARGET, file, line, "{}", message),
+            Some(tracing::Level::INFO) => tracing::info!(target: TARGET, file, line, "{}", message),
+            Some(_) => tracing::trace!(target: TARGET, file, line, "{}", message),
+            None => (),
+        }
+    })
+}
+
+impl Api {
+    /// Forwards the debug messages of the SDK to `log`, at the levels the installed logger lets
+    /// through at the time of the call.
+    #[cfg(feature = "log")]
+    pub fn forward_debug_messages_to_log(&self) -> Result<(), Error> {
+        self.replace_debug_message_handler(
+            Some(log_handler()),
+            k4a_log_level_from_log(log::max_level()),
+        )
+    }
+
+    /// Forwards the debug messages of the SDK to `tracing`, at the levels the installed
+    /// subscribers let through at the time of the call.
+    #[cfg(feature = "tracing")]
+    pub fn forward_debug_messages_to_tracing(&self) -> Result<(), Error> {
+        self.replace_debug_message_handler(
+            Some(tracing_handler()),
+            k4a_log_level_from_tracing(tracing::level_filters::LevelFilter::current()),
+        )
+    }
+}
@@ -1,12 +1,14 @@
 use super::*;
 use crate::Capture;
 use std::ffi::CString;
+use std::path::{Path, PathBuf};
 use std::ptr;
 use std::sync::Arc;
 
 pub struct Record {
     pub(crate) api_record: Arc<ApiRecord>,
     pub(crate) handle: k4a_record_t,
+    pub(crate) path: PathBuf,
 }
 
 impl Record {
@@ -17,49 +19,67 @@ impl Record {
         device: &Device,
         device_configuration: &k4a_device_configuration_t,
     ) -> Result<Record, Error> {
+        if device.api.is_simulated() != api_record.is_simulated() {
+            return Err(Error::new(ErrorKind::Failed).with_path(Path::new(path)));
+        }
         let mut handle: k4a_record_t = ptr::null_mut();
-        let path = CString::new(path).unwrap_or_default();
-        Error::from((api_record.k4a_record_create)(
-            path.as_ptr(),
-            device.handle,
-            *device_configuration,
-            &mut handle,
-        ))
-        .to_result_fn(|| Self { api_record, handle })
+        let c_path = CString::new(path).unwrap_or_default();
+        let path = PathBuf::from(path);
+        Error::check("k4a_record_create", unsafe {
+            (api_record.k4a_record_create)(
+                c_path.as_ptr(),
+                device.handle,
+                *device_configuration,
+                &mut handle,
+            )
+        })
+        .map_err(|e| e.with_path(&path).with_device(&device.id))?;
+        Ok(Self {
+            api_record,
+            handle,
+            path,
+        })
+    }
+
+    /// Adds the recording to the error of a failed call.
+    fn check<S: Status>(&self, call: &'static str, status: S) -> Result<(), Error> {
+        Error::check(call, status).map_err(|e| e.with_path(&self.path))
     }
 
     /// Flushes all pending recording data to disk
     pub fn flush(&self) -> Result<(), Error> {
-        Error::from((self.api_record.k4a_record_flush)(self.handle)).to_result(())
+        self.check("k4a_record_flush", unsafe {
+            (self.api_record.k4a_record_flush)(self.handle)
+        })
     }
 
     /// Adds a tag to the recording
     pub fn add_tag(&self, name: &str, value: &str) -> Result<(), Error> {
         let name = CString::new(name).unwrap_or_default();
         let value = CString::new(value).unwrap_or_default();
-        Error::from((self.api_record.k4a_record_add_tag)(
-            self.handle,
-            name.as_ptr(),
-            value.as_ptr(),
-        ))
-        .to_result(())
+        self.check("k4a_record_add_tag", unsafe {
+            (self.api_record.k4a_record_add_tag)(self.handle, name.as_ptr(), value.as_ptr())
+        })
     }
 
     /// Adds the track header for recording IMU
     pub fn add_imu_track(&self) -> Result<(), Error> {
-        Error::from((self.api_record.k4a_record_add_imu_track)(self.handle)).to_result(())
+        self.check("k4a_record_add_imu_track", unsafe {
+            (self.api_record.k4a_record_add_imu_track)(self.handle)
+        })
     }
 
     /// Adds an attachment to the recording
     pub fn add_attachment(&self, attachment_name: &str, buffer: &[u8]) -> Result<(), Error> {
         let attachment_name = CString::new(attachment_name).unwrap_or_default();
-        Error::from((self.api_record.k4a_record_add_attachment)(
-            self.handle,
-            attachment_name.as_ptr(),
-            buffer.as_ptr(),
-            buffer.len() as size_t,
-        ))
-        .to_result(())
+        self.check("k4a_record_add_attachment", unsafe {
+            (self.api_record.k4a_record_add_attachment)(
+                self.handle,
+                attachment_name.as_ptr(),
+                buffer.as_ptr(),
+                buffer.len() as size_t,
+            )
+        })
     }
 
     /// Adds custom video tracks to the recording
@@ -72,15 +92,16 @@ impl Record {
     ) -> Result<(), Error> {
         let track_name = CString::new(track_name).unwrap_or_default();
         let codec_id = CString::new(codec_id).unwrap_or_default();
-        Error::from((self.api_record.k4a_record_add_custom_video_track)(
-            self.handle,
-            track_name.as_ptr(),
-            codec_id.as_ptr(),
-            codec_context.as_ptr(),
-            codec_context.len() as size_t,
-            track_settings,
-        ))
-        .to_result(())
+        self.check("k4a_record_add_custom_video_track", unsafe {
+            (self.api_record.k4a_record_add_custom_video_track)(
+                self.handle,
+                track_name.as_ptr(),
+                codec_id.as_ptr(),
+                codec_context.as_ptr(),
+                codec_context.len() as size_t,
+                track_settings,
+            )
+        })
     }
 
     /// Adds custom subtitle tracks to the recording
@@ -93,38 +114,40 @@ impl Record {
     ) -> Result<(), Error> {
         let track_name = CString::new(track_name).unwrap_or_default();
         let codec_id = CString::new(codec_id).unwrap_or_default();
-        Error::from((self.api_record.k4a_record_add_custom_subtitle_track)(
-            self.handle,
-            track_name.as_ptr(),
-            codec_id.as_ptr(),
-            codec_context.as_ptr(),
-            codec_context.len() as size_t,
-            track_settings,
-        ))
-        .to_result(())
+        self.check("k4a_record_add_custom_subtitle_track", unsafe {
+            (self.api_record.k4a_record_add_custom_subtitle_track)(
+                self.handle,
+                track_name.as_ptr(),
+                codec_id.as_ptr(),
+                codec_context.as_ptr(),
+                codec_context.len() as size_t,
+                track_settings,
+            )
+        })
     }
 
     /// Writes the recording header and metadata to file
     pub fn write_header(&self) -> Result<(), Error> {
-        Error::from((self.api_record.k4a_record_write_header)(self.handle)).to_result(())
+        self.check("k4a_record_write_header", unsafe {
+            (self.api_record.k4a_record_write_header)(self.handle)
+        })
     }
 
     /// Writes a camera capture to file
     pub fn write_capture(&self, capture: &Capture) -> Result<(), Error> {
-        Error::from((self.api_record.k4a_record_write_capture)(
-            self.handle,
-            capture.handle,
-        ))
-        .to_result(())
+        if capture.api.is_simulated() != self.api_record.is_simulated() {
+            return Err(Error::new(ErrorKind::Failed).with_path(&self.path));
+        }
+        self.check("k4a_record_write_capture", unsafe {
+            (self.api_record.k4a_record_write_capture)(self.handle, capture.handle)
+        })
     }
 
     /// Writes an imu sample to file
     pub fn write_imu_sample(&self, imu_sample: k4a_imu_sample_t) -> Result<(), Error> {
-        Error::from((self.api_record.k4a_record_write_imu_sample)(
-            self.handle,
-            imu_sample,
-        ))
-        .to_result(())
+        self.check("k4a_record_write_imu_sample", unsafe {
+            (self.api_record.k4a_record_write_imu_sample)(self.handle, imu_sample)
+        })
     }
 
     pub fn write_custom_track_data(
@@ -134,20 +157,21 @@ impl Record {
         custom_data: &[u8],
     ) -> Result<(), Error> {
         let track_name = CString::new(track_name).unwrap_or_default();
-        Error::from((self.api_record.k4a_record_write_custom_track_data)(
-            self.handle,
-            track_name.as_ptr(),
-            device_timestamp_usec,
-            custom_data.as_ptr() as *mut u8,
-            custom_data.len() as size_t,
-        ))
-        .to_result(())
+        self.check("k4a_record_write_custom_track_data", unsafe {
+            (self.api_record.k4a_record_write_custom_track_data)(
+                self.handle,
+                track_name.as_ptr(),
+                device_timestamp_usec,
+                custom_data.as_ptr() as *mut u8,
+                custom_data.len() as size_t,
+            )
+        })
     }
 }
 
 impl Drop for Record {
     fn drop(&mut self) {
-        (self.api_record.k4a_record_close)(self.handle);
+        unsafe { (self.api_record.k4a_record_close)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
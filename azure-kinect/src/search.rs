@@ -0,0 +1,115 @@
+use super::*;
+use std::path::PathBuf;
+
+/// Where [`Api`], [`ApiRecord`] and [`ApiTracker`] look for the SDK libraries.
+///
+/// Locations are tried in this order, and the first library that loads wins:
+///
+/// 1. the directories added with [`LibrarySearch::directory`], in the order they were added,
+/// 2. the directories listed in the environment variable of the library: `K4A_SDK_DIR` for
+///    `k4a`, `k4arecord` and the depth engine, `K4ABT_SDK_DIR` for `k4abt`,
+/// 3. the directory of the current executable,
+/// 4. the standard install locations: the `Azure Kinect SDK` and `Azure Kinect Body Tracking
+///    SDK` folders under `%ProgramFiles%` on Windows, the system library search path on Linux.
+#[derive(Clone, Debug)]
+pub struct LibrarySearch {
+    pub(crate) directories: Vec<PathBuf>,
+    pub(crate) environment: bool,
+    pub(crate) executable_directory: bool,
+    pub(crate) standard_locations: bool,
+    pub(crate) require_depth_engine: bool,
+}
+
+impl Default for LibrarySearch {
+    fn default() -> Self {
+        LibrarySearch {
+            directories: Vec::new(),
+            environment: true,
+            executable_directory: true,
+            standard_locations: true,
+            require_depth_engine: false,
+        }
+    }
+}
+
+impl LibrarySearch {
+    /// Searches every location.
+    pub fn new() -> LibrarySearch {
+        LibrarySearch::default()
+    }
+
+    /// Searches only the directories added with [`LibrarySearch::directory`].
+    pub fn empty() -> LibrarySearch {
+        LibrarySearch {
+            environment: false,
+            executable_directory: false,
+            standard_locations: false,
+            ..LibrarySearch::default()
+        }
+    }
+
+    /// Adds a directory, searched before the environment variable and the default locations.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directories.push(directory.into());
+        self
+    }
+
+    /// Enables or disables the `K4A_SDK_DIR` and `K4ABT_SDK_DIR` environment variables.
+    pub fn environment(mut self, enabled: bool) -> Self {
+        self.environment = enabled;
+        self
+    }
+
+    /// Enables or disables the directory of the current executable.
+    pub fn executable_directory(mut self, enabled: bool) -> Self {
+        self.executable_directory = enabled;
+        self
+    }
+
+    /// Enables or disables the standard install locations of the SDKs.
+    pub fn standard_locations(mut self, enabled: bool) -> Self {
+        self.standard_locations = enabled;
+        self
+    }
+
+    /// `k4a` loads its depth engine plugin only when the depth camera starts. When loading `k4a`,
+    /// the plugin is searched for and preloaded, first next to `k4a` and then in the locations
+    /// above, so that the SDK finds it later. By default a missing plugin is ignored; when
+    /// `required` is set loading `k4a` fails with a [`SearchReport`] instead.
+    pub fn require_depth_engine(mut self, required: bool) -> Self {
+        self.require_depth_engine = required;
+        self
+    }
+}
+
+/// Every location tried while searching for a library, returned in
+/// [`ErrorKind::LibraryNotFound`] when none of them could be loaded.
+#[derive(Clone, Debug)]
+pub struct SearchReport {
+    /// Name of the library that was searched for, such as `k4a` or `depthengine`.
+    pub library: &'static str,
+    pub attempts: Vec<SearchAttempt>,
+}
+
+/// One path tried while searching for a library and why loading it failed.
+#[derive(Clone, Debug)]
+pub struct SearchAttempt {
+    /// The path handed to the dynamic loader. On Linux a bare file name means the system
+    /// library search path.
+    pub path: PathBuf,
+    pub error: Error,
+}
+
+impl std::fmt::Display for SearchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not load {}", self.library)?;
+        if self.attempts.is_empty() {
+            return write!(f, ": no locations were searched");
+        }
+        write!(f, ", tried:")?;
+        for attempt in &self.attempts {
+            write!(f, "\n  {}: {}", attempt.path.display(), attempt.error)?;
+        }
+        Ok(())
+    }
+}
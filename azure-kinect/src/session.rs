@@ -0,0 +1,101 @@
+use super::*;
+use std::ops::Deref;
+use std::ptr;
+
+/// The running cameras of a device, returned by [`Device::start_cameras`]. The cameras stop when
+/// the session is dropped.
+///
+/// The session borrows the device mutably, so the cameras can't be started twice, and captures
+/// can only be read while they run. The rest of the device stays available through `Deref`.
+pub struct CameraSession<'a> {
+    device: &'a Device,
+}
+
+/// The running IMU of a device, returned by [`CameraSession::start_imu`]. The IMU stops when the
+/// session is dropped. Captures can still be read through `Deref`.
+pub struct ImuSession<'a> {
+    cameras: &'a CameraSession<'a>,
+}
+
+impl<'a> CameraSession<'a> {
+    pub(crate) fn new(device: &'a Device) -> CameraSession<'a> {
+        CameraSession { device }
+    }
+
+    /// Reads a sensor capture. Fails with [`ErrorKind::Timeout`] if none arrived in time.
+    pub fn get_capture(&self, timeout_in_ms: i32) -> Result<Capture, Error> {
+        let device = self.device;
+        let mut handle: k4a_capture_t = ptr::null_mut();
+        device
+            .check("k4a_device_get_capture", unsafe {
+                (device.api.k4a_device_get_capture)(device.handle, &mut handle, timeout_in_ms)
+            })
+            .map(|()| Capture::from_handle(device.api.clone(), handle))
+    }
+
+    /// Reads a sensor capture, waiting for as long as it takes.
+    pub fn get_capture_wait_infinite(&self) -> Result<Capture, Error> {
+        self.get_capture(K4A_WAIT_INFINITE)
+    }
+
+    /// Starts the IMU, which needs the cameras to be running.
+    pub fn start_imu(&mut self) -> Result<ImuSession<'_>, Error> {
+        let device = self.device;
+        device
+            .check("k4a_device_start_imu", unsafe {
+                (device.api.k4a_device_start_imu)(device.handle)
+            })
+            .map(move |()| ImuSession { cameras: self })
+    }
+}
+
+impl Deref for CameraSession<'_> {
+    type Target = Device;
+
+    fn deref(&self) -> &Device {
+        self.device
+    }
+}
+
+impl Drop for CameraSession<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.device.api.k4a_device_stop_cameras)(self.device.handle) };
+    }
+}
+
+impl ImuSession<'_> {
+    /// Reads an IMU sample. Fails with [`ErrorKind::Timeout`] if none arrived in time.
+    pub fn get_imu_sample(&self, timeout_in_ms: i32) -> Result<k4a_imu_sample_t, Error> {
+        let device = self.cameras.device;
+        let mut imu_sample = k4a_imu_sample_t::default();
+        device
+            .check("k4a_device_get_imu_sample", unsafe {
+                (device.api.k4a_device_get_imu_sample)(
+                    device.handle,
+                    &mut imu_sample,
+                    timeout_in_ms,
+                )
+            })
+            .map(|()| imu_sample)
+    }
+
+    /// Reads an IMU sample, waiting for as long as it takes.
+    pub fn get_imu_sample_wait_infinite(&self) -> Result<k4a_imu_sample_t, Error> {
+        self.get_imu_sample(K4A_WAIT_INFINITE)
+    }
+}
+
+impl<'a> Deref for ImuSession<'a> {
+    type Target = CameraSession<'a>;
+
+    fn deref(&self) -> &CameraSession<'a> {
+        self.cameras
+    }
+}
+
+impl Drop for ImuSession<'_> {
+    fn drop(&mut self) {
+        let device = self.cameras.device;
+        unsafe { (device.api.k4a_device_stop_imu)(device.handle) };
+    }
+}
@@ -0,0 +1,764 @@
+use super::image::{image_ref, SimImage};
+use super::*;
+
+/// Prefix of the raw calibration blobs produced by simulated devices.
+const RAW_CALIBRATION_PREFIX: &str = "{\"simulated_calibration\":";
+
+type Vec3 = [f32; 3];
+type Mat3 = [f32; 9];
+
+const IDENTITY: Mat3 = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+fn mul(a: &Mat3, b: &Mat3) -> Mat3 {
+    let mut m = [0.0; 9];
+    for r in 0..3 {
+        for c in 0..3 {
+            m[r * 3 + c] = (0..3).map(|k| a[r * 3 + k] * b[k * 3 + c]).sum();
+        }
+    }
+    m
+}
+
+fn transpose(a: &Mat3) -> Mat3 {
+    [a[0], a[3], a[6], a[1], a[4], a[7], a[2], a[5], a[8]]
+}
+
+fn apply(r: &Mat3, t: &Vec3, p: &Vec3) -> Vec3 {
+    let mut q = [0.0; 3];
+    for (i, q) in q.iter_mut().enumerate() {
+        *q = r[i * 3] * p[0] + r[i * 3 + 1] * p[1] + r[i * 3 + 2] * p[2] + t[i];
+    }
+    q
+}
+
+/// Pose of every sensor relative to the depth camera, indexed by `k4a_calibration_type_t`.
+fn sensor_poses() -> [(Mat3, Vec3); 4] {
+    // The color camera sits 32mm to the side of the depth camera and is tilted down by 6°.
+    let (s, c) = 6f32.to_radians().sin_cos();
+    let color = [1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c];
+    [
+        (IDENTITY, [0.0, 0.0, 0.0]),
+        (color, [-32.0, -2.0, 4.0]),
+        (IDENTITY, [-51.0, 3.0, 1.0]),
+        (IDENTITY, [-51.0, 3.0, 1.0]),
+    ]
+}
+
+fn extrinsics(rotation: Mat3, translation: Vec3) -> k4a_calibration_extrinsics_t {
+    k4a_calibration_extrinsics_t {
+        rotation,
+        translation,
+    }
+}
+
+fn camera(
+    width: i32,
+    height: i32,
+    horizontal_fov_deg: f32,
+    pose: (Mat3, Vec3),
+) -> k4a_calibration_camera_t {
+    let f = width as f32 / 2.0 / (horizontal_fov_deg.to_radians() / 2.0).tan();
+    let mut v = [0.0; 15];
+    v[0] = width as f32 / 2.0 - 0.5;
+    v[1] = height as f32 / 2.0 - 0.5;
+    v[2] = f;
+    v[3] = f;
+    k4a_calibration_camera_t {
+        extrinsics: extrinsics(pose.0, pose.1),
+        intrinsics: k4a_calibration_intrinsics_t {
+            type_:
+                k4a_calibration_model_type_t::K4A_CALIBRATION_LENS_DISTORTION_MODEL_BROWN_CONRADY,
+            parameter_count: 14,
+            parameters: k4a_calibration_intrinsic_parameters_t { v },
+        },
+        resolution_width: width,
+        resolution_height: height,
+        metric_radius: 1.7,
+    }
+}
+
+/// Builds the ideal pinhole calibration of a simulated device.
+pub(crate) fn calibration(
+    depth_mode: k4a_depth_mode_t,
+    color_resolution: k4a_color_resolution_t,
+) -> k4a_calibration_t {
+    let poses = sensor_poses();
+    let depth = depth_mode.get_dimension();
+    let depth_fov = match depth_mode {
+        k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED
+        | k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED => 75.0,
+        _ => 120.0,
+    };
+    let color = color_resolution.get_dimension();
+    let mut all = [[extrinsics(IDENTITY, [0.0; 3]); 4]; 4];
+    for (source, (rs, ts)) in poses.iter().enumerate() {
+        for (target, (rt, tt)) in poses.iter().enumerate() {
+            // p_t = R_t * R_s^T * (p_s - t_s) + t_t
+            let rotation = mul(rt, &transpose(rs));
+            let offset = apply(&rotation, &[0.0; 3], ts);
+            let translation = [tt[0] - offset[0], tt[1] - offset[1], tt[2] - offset[2]];
+            all[source][target] = extrinsics(rotation, translation);
+        }
+    }
+    k4a_calibration_t {
+        depth_camera_calibration: camera(depth.width, depth.height, depth_fov, poses[0]),
+        color_camera_calibration: camera(color.width, color.height, 90.0, poses[1]),
+        extrinsics: all,
+        depth_mode,
+        color_resolution,
+    }
+}
+
+/// The raw calibration blob of the device with the given serial number, nul terminated like
+/// the JSON blob stored on real devices.
+pub(crate) fn raw_calibration(serial_number: &str) -> Vec<u8> {
+    format!("{}\"{}\"}}\0", RAW_CALIBRATION_PREFIX, serial_number).into_bytes()
+}
+
+pub(crate) fn calibration_from_raw(
+    raw: &[u8],
+    depth_mode: k4a_depth_mode_t,
+    color_resolution: k4a_color_resolution_t,
+) -> Option<k4a_calibration_t> {
+    if raw.starts_with(RAW_CALIBRATION_PREFIX.as_bytes()) {
+        Some(calibration(depth_mode, color_resolution))
+    } else {
+        None
+    }
+}
+
+//--------------------------------------------------//
+//---------------------- MATH ----------------------//
+//--------------------------------------------------//
+
+fn camera_of(
+    calibration: &k4a_calibration_t,
+    camera: k4a_calibration_type_t,
+) -> Option<&k4a_calibration_camera_t> {
+    match camera {
+        k4a_calibration_type_t::K4A_CALIBRATION_TYPE_DEPTH => {
+            Some(&calibration.depth_camera_calibration)
+        }
+        k4a_calibration_type_t::K4A_CALIBRATION_TYPE_COLOR => {
+            Some(&calibration.color_camera_calibration)
+        }
+        _ => None,
+    }
+}
+
+fn transform_3d(
+    calibration: &k4a_calibration_t,
+    point: &Vec3,
+    source: k4a_calibration_type_t,
+    target: k4a_calibration_type_t,
+) -> Option<Vec3> {
+    let (s, t) = (source as i32, target as i32);
+    if !(0..4).contains(&s) || !(0..4).contains(&t) {
+        return None;
+    }
+    let e = &calibration.extrinsics[s as usize][t as usize];
+    Some(apply(&e.rotation, &e.translation, point))
+}
+
+fn unproject(camera: &k4a_calibration_camera_t, point: [f32; 2], depth: f32) -> Vec3 {
+    let p = unsafe { camera.intrinsics.parameters.param };
+    [
+        (point[0] - p.cx) / p.fx * depth,
+        (point[1] - p.cy) / p.fy * depth,
+        depth,
+    ]
+}
+
+/// Projects a point onto the image plane; the point is only valid when it lies in front of
+/// the camera and inside the image.
+fn project(camera: &k4a_calibration_camera_t, point: &Vec3) -> ([f32; 2], bool) {
+    let p = unsafe { camera.intrinsics.parameters.param };
+    if point[2] <= 0.0 {
+        return ([0.0, 0.0], false);
+    }
+    let u = p.fx * point[0] / point[2] + p.cx;
+    let v = p.fy * point[1] / point[2] + p.cy;
+    let valid = u >= -0.5
+        && v >= -0.5
+        && u < camera.resolution_width as f32 - 0.5
+        && v < camera.resolution_height as f32 - 0.5;
+    ([u, v], valid)
+}
+
+fn point_2d_to_3d(
+    calibration: &k4a_calibration_t,
+    point: [f32; 2],
+    depth: f32,
+    source: k4a_calibration_type_t,
+    target: k4a_calibration_type_t,
+) -> Option<(Vec3, bool)> {
+    let camera = camera_of(calibration, source)?;
+    let p = transform_3d(
+        calibration,
+        &unproject(camera, point, depth),
+        source,
+        target,
+    )?;
+    Some((p, depth > 0.0))
+}
+
+fn point_3d_to_2d(
+    calibration: &k4a_calibration_t,
+    point: &Vec3,
+    source: k4a_calibration_type_t,
+    target: k4a_calibration_type_t,
+) -> Option<([f32; 2], bool)> {
+    let camera = camera_of(calibration, target)?;
+    Some(project(
+        camera,
+        &transform_3d(calibration, point, source, target)?,
+    ))
+}
+
+pub(super) extern "C" fn k4a_calibration_get_from_raw(
+    raw_calibration: *mut i8,
+    raw_calibration_size: usize,
+    depth_mode: k4a_depth_mode_t,
+    color_resolution: k4a_color_resolution_t,
+    calibration: *mut k4a_calibration_t,
+) -> k4a_result_t {
+    if raw_calibration.is_null() || calibration.is_null() {
+        return fail("Invalid raw calibration arguments");
+    }
+    let raw =
+        unsafe { std::slice::from_raw_parts(raw_calibration as *const u8, raw_calibration_size) };
+    match calibration_from_raw(raw, depth_mode, color_resolution) {
+        Some(c) => {
+            unsafe { *calibration = c };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail("Raw calibration was not produced by a simulated device"),
+    }
+}
+
+pub(super) extern "C" fn k4a_calibration_3d_to_3d(
+    calibration: *const k4a_calibration_t,
+    source_point3d_mm: *const k4a_float3_t,
+    source_camera: k4a_calibration_type_t,
+    target_camera: k4a_calibration_type_t,
+    target_point3d_mm: *mut k4a_float3_t,
+) -> k4a_result_t {
+    unsafe {
+        match transform_3d(
+            &*calibration,
+            &(*source_point3d_mm).v,
+            source_camera,
+            target_camera,
+        ) {
+            Some(v) => {
+                *target_point3d_mm = k4a_float3_t { v };
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            None => fail("Invalid calibration type"),
+        }
+    }
+}
+
+pub(super) extern "C" fn k4a_calibration_2d_to_3d(
+    calibration: *const k4a_calibration_t,
+    source_point2d: *const k4a_float2_t,
+    source_depth_mm: f32,
+    source_camera: k4a_calibration_type_t,
+    target_camera: k4a_calibration_type_t,
+    target_point3d_mm: *mut k4a_float3_t,
+    valid: *mut i32,
+) -> k4a_result_t {
+    unsafe {
+        match point_2d_to_3d(
+            &*calibration,
+            (*source_point2d).v,
+            source_depth_mm,
+            source_camera,
+            target_camera,
+        ) {
+            Some((v, is_valid)) => {
+                *target_point3d_mm = k4a_float3_t { v };
+                *valid = is_valid as i32;
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            None => fail("Invalid calibration type"),
+        }
+    }
+}
+
+pub(super) extern "C" fn k4a_calibration_3d_to_2d(
+    calibration: *const k4a_calibration_t,
+    source_point3d_mm: *const k4a_float3_t,
+    source_camera: k4a_calibration_type_t,
+    target_camera: k4a_calibration_type_t,
+    target_point2d: *mut k4a_float2_t,
+    valid: *mut i32,
+) -> k4a_result_t {
+    unsafe {
+        match point_3d_to_2d(
+            &*calibration,
+            &(*source_point3d_mm).v,
+            source_camera,
+            target_camera,
+        ) {
+            Some((v, is_valid)) => {
+                *target_point2d = k4a_float2_t { v };
+                *valid = is_valid as i32;
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            None => fail("Invalid calibration type"),
+        }
+    }
+}
+
+pub(super) extern "C" fn k4a_calibration_2d_to_2d(
+    calibration: *const k4a_calibration_t,
+    source_point2d: *const k4a_float2_t,
+    source_depth_mm: f32,
+    source_camera: k4a_calibration_type_t,
+    target_camera: k4a_calibration_type_t,
+    target_point2d: *mut k4a_float2_t,
+    valid: *mut i32,
+) -> k4a_result_t {
+    unsafe {
+        let calibration = &*calibration;
+        let result = point_2d_to_3d(
+            calibration,
+            (*source_point2d).v,
+            source_depth_mm,
+            source_camera,
+            target_camera,
+        )
+        .and_then(|(p, depth_valid)| {
+            let (v, is_valid) = point_3d_to_2d(calibration, &p, target_camera, target_camera)?;
+            Some((v, depth_valid && is_valid))
+        });
+        match result {
+            Some((v, is_valid)) => {
+                *target_point2d = k4a_float2_t { v };
+                *valid = is_valid as i32;
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            None => fail("Invalid calibration type"),
+        }
+    }
+}
+
+fn depth_pixel(image: &SimImage, x: i32, y: i32) -> u16 {
+    if x < 0 || y < 0 || x >= image.width || y >= image.height {
+        return 0;
+    }
+    let i = (y * image.stride + x * 2) as usize;
+    let data = image.data();
+    u16::from_le_bytes([data[i], data[i + 1]])
+}
+
+pub(super) extern "C" fn k4a_calibration_color_2d_to_depth_2d(
+    calibration: *const k4a_calibration_t,
+    source_point2d: *const k4a_float2_t,
+    depth_image: k4a_image_t,
+    target_point2d: *mut k4a_float2_t,
+    valid: *mut i32,
+) -> k4a_result_t {
+    use k4a_calibration_type_t::*;
+    let (calibration, source, depth) = unsafe {
+        match image_ref(depth_image) {
+            Some(depth) => (&*calibration, (*source_point2d).v, depth),
+            None => return fail("Invalid depth image"),
+        }
+    };
+    let range = calibration.depth_mode.get_range();
+    // Walk along the color pixel's ray and stop where it meets the depth surface.
+    let mut found = None;
+    for z in (range.min..=range.max).step_by(2) {
+        let (point, _) = match point_2d_to_3d(
+            calibration,
+            source,
+            z as f32,
+            K4A_CALIBRATION_TYPE_COLOR,
+            K4A_CALIBRATION_TYPE_DEPTH,
+        ) {
+            Some(p) => p,
+            None => break,
+        };
+        let (uv, in_image) = project(&calibration.depth_camera_calibration, &point);
+        if !in_image {
+            continue;
+        }
+        let d = depth_pixel(depth, uv[0].round() as i32, uv[1].round() as i32);
+        if d != 0 && (d as f32 - point[2]).abs() <= 4.0 {
+            found = Some(uv);
+            break;
+        }
+    }
+    unsafe {
+        *target_point2d = k4a_float2_t {
+            v: found.unwrap_or([0.0, 0.0]),
+        };
+        *valid = found.is_some() as i32;
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+//--------------------------------------------------//
+//------------------ TRANSFORMATION ----------------//
+//--------------------------------------------------//
+
+struct SimTransformation {
+    calibration: k4a_calibration_t,
+}
+
+pub(super) extern "C" fn k4a_transformation_create(
+    calibration: *const k4a_calibration_t,
+) -> k4a_transformation_t {
+    match unsafe { calibration.as_ref() } {
+        Some(calibration) => into_handle(SimTransformation {
+            calibration: *calibration,
+        }),
+        None => ptr::null_mut(),
+    }
+}
+
+pub(super) extern "C" fn k4a_transformation_destroy(transformation_handle: k4a_transformation_t) {
+    if !transformation_handle.is_null() {
+        drop(unsafe { Box::from_raw(transformation_handle as *mut SimTransformation) });
+    }
+}
+
+fn check_image(
+    image: Option<&SimImage>,
+    formats: &[k4a_image_format_t],
+    camera: &k4a_calibration_camera_t,
+    name: &str,
+) -> Result<(), k4a_result_t> {
+    match image {
+        Some(image)
+            if formats.contains(&image.format)
+                && image.width == camera.resolution_width
+                && image.height == camera.resolution_height =>
+        {
+            Ok(())
+        }
+        _ => Err(fail(&format!(
+            "{} must be a {:?} image of {}x{}",
+            name, formats, camera.resolution_width, camera.resolution_height
+        ))),
+    }
+}
+
+fn pixel_size(format: k4a_image_format_t) -> usize {
+    match format {
+        k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM8 => 1,
+        k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32 => 4,
+        _ => 2,
+    }
+}
+
+fn read_pixel(image: &SimImage, x: i32, y: i32) -> u32 {
+    let size = pixel_size(image.format);
+    let i = y as usize * image.stride as usize + x as usize * size;
+    let mut bytes = [0u8; 4];
+    bytes[..size].copy_from_slice(&image.data()[i..i + size]);
+    u32::from_le_bytes(bytes)
+}
+
+fn write_pixel(data: &mut [u8], image: &SimImage, x: i32, y: i32, value: u32) {
+    let size = pixel_size(image.format);
+    let i = y as usize * image.stride as usize + x as usize * size;
+    data[i..i + size].copy_from_slice(&value.to_le_bytes()[..size]);
+}
+
+/// Re-projects every depth pixel into the color camera, keeping the nearest surface and
+/// covering the footprint of each depth pixel so the output has no gaps.
+fn depth_to_color(
+    calibration: &k4a_calibration_t,
+    depth: &SimImage,
+    custom: Option<(&SimImage, &SimImage)>,
+    transformed_depth: &SimImage,
+    invalid_custom_value: u32,
+) {
+    use k4a_calibration_type_t::*;
+    let color = &calibration.color_camera_calibration;
+    let scale = unsafe {
+        color.intrinsics.parameters.param.fx
+            / calibration
+                .depth_camera_calibration
+                .intrinsics
+                .parameters
+                .param
+                .fx
+    };
+    let out = unsafe { transformed_depth.data_mut() };
+    out.iter_mut().for_each(|b| *b = 0);
+    let mut custom_out = custom.map(|(_, target)| unsafe { target.data_mut() });
+    if let (Some(out), Some((_, target))) = (custom_out.as_mut(), custom) {
+        for y in 0..target.height {
+            for x in 0..target.width {
+                write_pixel(out, target, x, y, invalid_custom_value);
+            }
+        }
+    }
+    for y in 0..depth.height {
+        for x in 0..depth.width {
+            let d = depth_pixel(depth, x, y);
+            if d == 0 {
+                continue;
+            }
+            let (p, _) = match point_2d_to_3d(
+                calibration,
+                [x as f32, y as f32],
+                d as f32,
+                K4A_CALIBRATION_TYPE_DEPTH,
+                K4A_CALIBRATION_TYPE_COLOR,
+            ) {
+                Some(p) => p,
+                None => return,
+            };
+            let (uv, valid) = project(color, &p);
+            if !valid {
+                continue;
+            }
+            let z = p[2].round().clamp(1.0, u16::MAX as f32) as u32;
+            let half = (scale * d as f32 / p[2] / 2.0).max(0.5);
+            let (x0, x1) = ((uv[0] - half).round() as i32, (uv[0] + half).round() as i32);
+            let (y0, y1) = ((uv[1] - half).round() as i32, (uv[1] + half).round() as i32);
+            for ty in y0.max(0)..y1.min(color.resolution_height) {
+                for tx in x0.max(0)..x1.min(color.resolution_width) {
+                    let current = read_pixel(transformed_depth, tx, ty);
+                    if current == 0 || z < current {
+                        write_pixel(out, transformed_depth, tx, ty, z);
+                        if let (Some(out), Some((source, target))) = (custom_out.as_mut(), custom) {
+                            write_pixel(out, target, tx, ty, read_pixel(source, x, y));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+unsafe fn transformation_ref<'a>(handle: k4a_transformation_t) -> Option<&'a k4a_calibration_t> {
+    from_handle::<SimTransformation, _>(handle).map(|t| &t.calibration)
+}
+
+pub(super) extern "C" fn k4a_transformation_depth_image_to_color_camera(
+    transformation_handle: k4a_transformation_t,
+    depth_image: k4a_image_t,
+    transformed_depth_image: k4a_image_t,
+) -> k4a_result_t {
+    use k4a_image_format_t::*;
+    let calibration = match unsafe { transformation_ref(transformation_handle) } {
+        Some(c) => c,
+        None => return fail("Invalid transformation handle"),
+    };
+    let (depth, transformed) =
+        unsafe { (image_ref(depth_image), image_ref(transformed_depth_image)) };
+    let checked = check_image(
+        depth,
+        &[K4A_IMAGE_FORMAT_DEPTH16],
+        &calibration.depth_camera_calibration,
+        "depth_image",
+    )
+    .and_then(|_| {
+        check_image(
+            transformed,
+            &[K4A_IMAGE_FORMAT_DEPTH16],
+            &calibration.color_camera_calibration,
+            "transformed_depth_image",
+        )
+    });
+    if let Err(e) = checked {
+        return e;
+    }
+    depth_to_color(calibration, depth.unwrap(), None, transformed.unwrap(), 0);
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_transformation_depth_image_to_color_camera_custom(
+    transformation_handle: k4a_transformation_t,
+    depth_image: k4a_image_t,
+    custom_image: k4a_image_t,
+    transformed_depth_image: k4a_image_t,
+    transformed_custom_image: k4a_image_t,
+    _interpolation_type: k4a_transformation_interpolation_type_t,
+    invalid_custom_value: u32,
+) -> k4a_result_t {
+    use k4a_image_format_t::*;
+    let calibration = match unsafe { transformation_ref(transformation_handle) } {
+        Some(c) => c,
+        None => return fail("Invalid transformation handle"),
+    };
+    let (depth, custom, transformed, transformed_custom) = unsafe {
+        (
+            image_ref(depth_image),
+            image_ref(custom_image),
+            image_ref(transformed_depth_image),
+            image_ref(transformed_custom_image),
+        )
+    };
+    let custom_formats = [K4A_IMAGE_FORMAT_CUSTOM8, K4A_IMAGE_FORMAT_CUSTOM16];
+    let depth_camera = &calibration.depth_camera_calibration;
+    let color_camera = &calibration.color_camera_calibration;
+    let checked = check_image(
+        depth,
+        &[K4A_IMAGE_FORMAT_DEPTH16],
+        depth_camera,
+        "depth_image",
+    )
+    .and_then(|_| check_image(custom, &custom_formats, depth_camera, "custom_image"))
+    .and_then(|_| {
+        check_image(
+            transformed,
+            &[K4A_IMAGE_FORMAT_DEPTH16],
+            color_camera,
+            "transformed_depth_image",
+        )
+    })
+    .and_then(|_| {
+        check_image(
+            transformed_custom,
+            &[custom.unwrap().format],
+            color_camera,
+            "transformed_custom_image",
+        )
+    });
+    if let Err(e) = checked {
+        return e;
+    }
+    // Nearest neighbour is used for both interpolation types.
+    depth_to_color(
+        calibration,
+        depth.unwrap(),
+        Some((custom.unwrap(), transformed_custom.unwrap())),
+        transformed.unwrap(),
+        invalid_custom_value,
+    );
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_transformation_color_image_to_depth_camera(
+    transformation_handle: k4a_transformation_t,
+    depth_image: k4a_image_t,
+    color_image: k4a_image_t,
+    transformed_color_image: k4a_image_t,
+) -> k4a_result_t {
+    use k4a_calibration_type_t::*;
+    use k4a_image_format_t::*;
+    let calibration = match unsafe { transformation_ref(transformation_handle) } {
+        Some(c) => c,
+        None => return fail("Invalid transformation handle"),
+    };
+    let (depth, color, transformed) = unsafe {
+        (
+            image_ref(depth_image),
+            image_ref(color_image),
+            image_ref(transformed_color_image),
+        )
+    };
+    let depth_camera = &calibration.depth_camera_calibration;
+    let color_camera = &calibration.color_camera_calibration;
+    let checked = check_image(
+        depth,
+        &[K4A_IMAGE_FORMAT_DEPTH16],
+        depth_camera,
+        "depth_image",
+    )
+    .and_then(|_| {
+        check_image(
+            color,
+            &[K4A_IMAGE_FORMAT_COLOR_BGRA32],
+            color_camera,
+            "color_image",
+        )
+    })
+    .and_then(|_| {
+        check_image(
+            transformed,
+            &[K4A_IMAGE_FORMAT_COLOR_BGRA32],
+            depth_camera,
+            "transformed_color_image",
+        )
+    });
+    if let Err(e) = checked {
+        return e;
+    }
+    let (depth, color, transformed) = (depth.unwrap(), color.unwrap(), transformed.unwrap());
+    let out = unsafe { transformed.data_mut() };
+    for y in 0..depth.height {
+        for x in 0..depth.width {
+            let d = depth_pixel(depth, x, y);
+            let mut value = 0;
+            if d != 0 {
+                if let Some((p, _)) = point_2d_to_3d(
+                    calibration,
+                    [x as f32, y as f32],
+                    d as f32,
+                    K4A_CALIBRATION_TYPE_DEPTH,
+                    K4A_CALIBRATION_TYPE_COLOR,
+                ) {
+                    let (uv, valid) = project(color_camera, &p);
+                    if valid {
+                        value = read_pixel(color, uv[0].round() as i32, uv[1].round() as i32);
+                    }
+                }
+            }
+            write_pixel(out, transformed, x, y, value);
+        }
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_transformation_depth_image_to_point_cloud(
+    transformation_handle: k4a_transformation_t,
+    depth_image: k4a_image_t,
+    camera: k4a_calibration_type_t,
+    xyz_image: k4a_image_t,
+) -> k4a_result_t {
+    let calibration = match unsafe { transformation_ref(transformation_handle) } {
+        Some(c) => c,
+        None => return fail("Invalid transformation handle"),
+    };
+    let camera_calibration = match camera_of(calibration, camera) {
+        Some(c) => c,
+        None => return fail("Point clouds can only be computed for the depth or color camera"),
+    };
+    let (depth, xyz) = unsafe { (image_ref(depth_image), image_ref(xyz_image)) };
+    let checked = check_image(
+        depth,
+        &[k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16],
+        camera_calibration,
+        "depth_image",
+    )
+    .and_then(|_| {
+        check_image(
+            xyz,
+            &[k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM],
+            camera_calibration,
+            "xyz_image",
+        )
+    });
+    if let Err(e) = checked {
+        return e;
+    }
+    let (depth, xyz) = (depth.unwrap(), xyz.unwrap());
+    if xyz.stride < depth.width * 6 {
+        return fail("xyz_image stride must hold three int16 values per pixel");
+    }
+    let out = unsafe { xyz.data_mut() };
+    for y in 0..depth.height {
+        for x in 0..depth.width {
+            let d = depth_pixel(depth, x, y);
+            let p = if d == 0 {
+                [0.0; 3]
+            } else {
+                unproject(camera_calibration, [x as f32, y as f32], d as f32)
+            };
+            let i = (y * xyz.stride + x * 6) as usize;
+            for (k, v) in p.iter().enumerate() {
+                let v = v.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+                out[i + k * 2..i + k * 2 + 2].copy_from_slice(&v.to_le_bytes());
+            }
+        }
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
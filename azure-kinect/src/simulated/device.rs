@@ -0,0 +1,701 @@
+use super::image::{image_ref, new_capture, new_image};
+use super::*;
+use std::sync::atomic::AtomicBool;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Serial number of the device attached when the process starts.
+pub const DEFAULT_SERIAL_NUMBER: &str = "000000000001";
+
+/// Number of captures the device buffers before dropping the oldest one.
+const CAPTURE_QUEUE_SIZE: u64 = 2;
+/// Number of IMU samples the device buffers before dropping the oldest one.
+const IMU_QUEUE_SIZE: u64 = 2000;
+/// The IMU reports samples at 1.6kHz.
+const IMU_PERIOD_USEC: u64 = 625;
+/// Delay between the end of an exposure and the frame arriving on the host.
+const USB_LATENCY_NSEC: u64 = 8_000_000;
+
+/// All simulated devices share one clock, so devices started together produce frames with
+/// matching device timestamps, as if they were connected with a sync cable.
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+fn elapsed_usec(now: Instant) -> u64 {
+    now.saturating_duration_since(epoch()).as_micros() as u64
+}
+
+//--------------------------------------------------//
+//--------------------- REGISTRY -------------------//
+//--------------------------------------------------//
+
+struct Slot {
+    serial_number: String,
+    connected: AtomicBool,
+    sync_in: AtomicBool,
+    sync_out: AtomicBool,
+}
+
+static REGISTRY: Mutex<Option<Vec<Arc<Slot>>>> = Mutex::new(None);
+
+fn with_registry<T>(f: impl FnOnce(&mut Vec<Arc<Slot>>) -> T) -> T {
+    let mut registry = REGISTRY.lock().unwrap();
+    f(registry.get_or_insert_with(|| vec![new_slot(DEFAULT_SERIAL_NUMBER)]))
+}
+
+fn new_slot(serial_number: &str) -> Arc<Slot> {
+    Arc::new(Slot {
+        serial_number: serial_number.to_string(),
+        connected: AtomicBool::new(true),
+        sync_in: AtomicBool::new(false),
+        sync_out: AtomicBool::new(false),
+    })
+}
+
+/// Plugs in a simulated device, which is enumerated after the devices already attached.
+pub fn attach_device(serial_number: &str) {
+    with_registry(|devices| devices.push(new_slot(serial_number)));
+}
+
+/// Unplugs the simulated device with the given serial number. Handles that are already open
+/// fail every call from then on. Returns `false` if no such device was attached.
+pub fn detach_device(serial_number: &str) -> bool {
+    with_registry(|devices| {
+        match devices
+            .iter()
+            .position(|d| d.serial_number == serial_number)
+        {
+            Some(index) => {
+                devices
+                    .remove(index)
+                    .connected
+                    .store(false, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+/// Plugs sync cables into, or unplugs them from, the jacks of the simulated device with the given
+/// serial number. Master mode needs the sync out jack and subordinate mode the sync in jack.
+/// Returns `false` if no such device was attached.
+pub fn set_sync_jacks(serial_number: &str, sync_in: bool, sync_out: bool) -> bool {
+    with_registry(
+        |devices| match devices.iter().find(|d| d.serial_number == serial_number) {
+            Some(device) => {
+                device.sync_in.store(sync_in, Ordering::SeqCst);
+                device.sync_out.store(sync_out, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+/// Restores the initial state of a single device with [`DEFAULT_SERIAL_NUMBER`].
+pub fn reset_devices() {
+    with_registry(|devices| {
+        for device in devices.drain(..) {
+            device.connected.store(false, Ordering::SeqCst);
+        }
+        devices.push(new_slot(DEFAULT_SERIAL_NUMBER));
+    });
+}
+
+//--------------------------------------------------//
+//---------------------- DEVICE --------------------//
+//--------------------------------------------------//
+
+struct Cameras {
+    config: k4a_device_configuration_t,
+    period_usec: u64,
+    /// Offset of the frame grid from the shared clock.
+    phase_usec: u64,
+    next_frame: u64,
+}
+
+impl Cameras {
+    fn frame_time_usec(&self, frame: u64) -> u64 {
+        frame * self.period_usec + self.phase_usec
+    }
+
+    /// Index of the last frame produced at or before `now_usec`.
+    fn latest_frame(&self, now_usec: u64) -> u64 {
+        now_usec.saturating_sub(self.phase_usec) / self.period_usec
+    }
+}
+
+#[derive(Copy, Clone)]
+struct ColorControl {
+    mode: k4a_color_control_mode_t,
+    value: i32,
+}
+
+struct DeviceState {
+    cameras: Option<Cameras>,
+    next_imu_sample: Option<u64>,
+    color_controls: [ColorControl; 10],
+}
+
+struct SimDevice {
+    slot: Arc<Slot>,
+    state: Mutex<DeviceState>,
+}
+
+impl SimDevice {
+    fn connected(&self) -> bool {
+        self.slot.connected.load(Ordering::SeqCst)
+    }
+}
+
+unsafe fn device_ref<'a>(device_handle: k4a_device_t) -> Option<&'a SimDevice> {
+    from_handle(device_handle)
+}
+
+/// Looks up an open device that is still attached, logging why the call fails otherwise.
+fn attached_device<'a>(device_handle: k4a_device_t) -> Option<&'a SimDevice> {
+    match unsafe { device_ref(device_handle) } {
+        Some(device) if device.connected() => Some(device),
+        Some(device) => {
+            log(
+                k4a_log_level_t::K4A_LOG_LEVEL_ERROR,
+                &format!("Device {} has been disconnected", device.slot.serial_number),
+            );
+            None
+        }
+        None => None,
+    }
+}
+
+/// Serial number of an open simulated device.
+pub(crate) fn serial_number(device_handle: k4a_device_t) -> Option<String> {
+    unsafe { device_ref(device_handle) }.map(|device| device.slot.serial_number.clone())
+}
+
+pub(super) extern "C" fn k4a_device_get_installed_count() -> u32 {
+    with_registry(|devices| devices.len() as u32)
+}
+
+pub(super) extern "C" fn k4a_device_open(
+    index: u32,
+    device_handle: *mut k4a_device_t,
+) -> k4a_result_t {
+    let slot = match with_registry(|devices| devices.get(index as usize).cloned()) {
+        Some(slot) => slot,
+        None => return fail(&format!("No device is attached at index {}", index)),
+    };
+    let color_controls = {
+        let mut controls = [ColorControl {
+            mode: k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL,
+            value: 0,
+        }; 10];
+        for (command, control) in controls.iter_mut().enumerate() {
+            let c = &COLOR_CONTROLS[command];
+            *control = ColorControl {
+                mode: c.default_mode,
+                value: c.default_value,
+            };
+        }
+        controls
+    };
+    unsafe {
+        *device_handle = into_handle(SimDevice {
+            slot,
+            state: Mutex::new(DeviceState {
+                cameras: None,
+                next_imu_sample: None,
+                color_controls,
+            }),
+        })
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_close(device_handle: k4a_device_t) {
+    if !device_handle.is_null() {
+        drop(unsafe { Box::from_raw(device_handle as *mut SimDevice) });
+    }
+}
+
+pub(super) extern "C" fn k4a_device_start_cameras(
+    device_handle: k4a_device_t,
+    config: *const k4a_device_configuration_t,
+) -> k4a_result_t {
+    let device = match attached_device(device_handle) {
+        Some(device) => device,
+        None => return k4a_result_t::K4A_RESULT_FAILED,
+    };
+    let config = match unsafe { config.as_ref() } {
+        Some(config) => *config,
+        None => return fail("Invalid device configuration"),
+    };
+    // The SDK checks the same rules as the builder.
+    if let Err(error) = DeviceConfiguration::from(config).build() {
+        return fail(&error.to_string());
+    }
+    match config.wired_sync_mode {
+        k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_MASTER
+            if !device.slot.sync_out.load(Ordering::SeqCst) =>
+        {
+            return fail("Failed to start in master mode, the sync out jack is not connected");
+        }
+        k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE
+            if !device.slot.sync_in.load(Ordering::SeqCst) =>
+        {
+            return fail("Failed to start in subordinate mode, the sync in jack is not connected");
+        }
+        _ => {}
+    }
+    let mut state = device.state.lock().unwrap();
+    if state.cameras.is_some() {
+        return fail("The cameras are already running");
+    }
+    let period_usec = 1_000_000 / config.camera_fps.get_u32() as u64;
+    let mut cameras = Cameras {
+        config,
+        period_usec,
+        phase_usec: config.subordinate_delay_off_master_usec as u64,
+        next_frame: 0,
+    };
+    cameras.next_frame = cameras.latest_frame(elapsed_usec(Instant::now())) + 1;
+    state.cameras = Some(cameras);
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_stop_cameras(device_handle: k4a_device_t) {
+    if let Some(device) = unsafe { device_ref(device_handle) } {
+        let mut state = device.state.lock().unwrap();
+        state.cameras = None;
+        state.next_imu_sample = None;
+    }
+}
+
+fn build_capture(cameras: &Cameras, controls: &[ColorControl; 10], frame: u64) -> k4a_capture_t {
+    let config = &cameras.config;
+    let color_usec = 1_000_000 + cameras.frame_time_usec(frame);
+    let depth_usec = (color_usec as i64 + config.depth_delay_off_color_usec as i64) as u64;
+    let system_nsec = |usec: u64| usec * 1000 + USB_LATENCY_NSEC;
+    let t = depth_usec as f32 / 1_000_000.0;
+
+    let mut color = ptr::null_mut();
+    if config.color_resolution != k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF {
+        let Dimension { width, height } = config.color_resolution.get_dimension();
+        let stride = match config.color_format {
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG => 0,
+            format => image::default_stride(format, width).unwrap_or(0),
+        };
+        if let Some(data) = pattern::color_image(config.color_format, width, height, t) {
+            color = new_image(config.color_format, width, height, stride, data);
+            let exposure = &controls
+                [k4a_color_control_command_t::K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE as usize];
+            let white_balance =
+                &controls[k4a_color_control_command_t::K4A_COLOR_CONTROL_WHITEBALANCE as usize];
+            let mut metadata = unsafe { image_ref(color) }
+                .unwrap()
+                .metadata
+                .lock()
+                .unwrap();
+            metadata.device_timestamp_usec = color_usec;
+            metadata.system_timestamp_nsec = system_nsec(color_usec);
+            metadata.exposure_usec = match exposure.mode {
+                k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO => 16_670,
+                _ => exposure.value as u64,
+            };
+            metadata.white_balance = match white_balance.mode {
+                k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO => 4_500,
+                _ => white_balance.value as u32,
+            };
+            metadata.iso_speed = 100;
+        }
+    }
+
+    let depth_image = |format: k4a_image_format_t, data: Vec<u8>| {
+        let Dimension { width, height } = config.depth_mode.get_dimension();
+        let image = new_image(format, width, height, width * 2, data);
+        let mut metadata = unsafe { image_ref(image) }
+            .unwrap()
+            .metadata
+            .lock()
+            .unwrap();
+        metadata.device_timestamp_usec = depth_usec;
+        metadata.system_timestamp_nsec = system_nsec(depth_usec);
+        metadata.exposure_usec = 12_800;
+        drop(metadata);
+        image
+    };
+    let mut depth = ptr::null_mut();
+    let mut ir = ptr::null_mut();
+    match config.depth_mode {
+        k4a_depth_mode_t::K4A_DEPTH_MODE_OFF => {}
+        k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR => {
+            ir = depth_image(
+                k4a_image_format_t::K4A_IMAGE_FORMAT_IR16,
+                pattern::ir_image(config.depth_mode, t),
+            );
+        }
+        mode => {
+            depth = depth_image(
+                k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+                pattern::depth_image(mode, t),
+            );
+            ir = depth_image(
+                k4a_image_format_t::K4A_IMAGE_FORMAT_IR16,
+                pattern::ir_image(mode, t),
+            );
+        }
+    }
+    new_capture(color, depth, ir, 31.5)
+}
+
+/// Sleeps until `due_usec` on the shared clock or `deadline`, whichever comes first.
+/// Returns `false` once the deadline has passed.
+fn wait_until(due_usec: u64, deadline: Option<Instant>) -> bool {
+    let now = Instant::now();
+    let due = epoch() + Duration::from_micros(due_usec);
+    let wake = match deadline {
+        Some(deadline) if deadline <= now => return false,
+        Some(deadline) => due.min(deadline),
+        None => due,
+    };
+    thread::sleep(wake.saturating_duration_since(now));
+    true
+}
+
+fn deadline(timeout_in_ms: i32) -> Option<Instant> {
+    if timeout_in_ms < 0 {
+        None
+    } else {
+        Some(Instant::now() + Duration::from_millis(timeout_in_ms as u64))
+    }
+}
+
+pub(super) extern "C" fn k4a_device_get_capture(
+    device_handle: k4a_device_t,
+    capture_handle: *mut k4a_capture_t,
+    timeout_in_ms: i32,
+) -> k4a_wait_result_t {
+    let deadline = deadline(timeout_in_ms);
+    loop {
+        let device = match attached_device(device_handle) {
+            Some(device) => device,
+            None => return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED,
+        };
+        let mut state = device.state.lock().unwrap();
+        let controls = state.color_controls;
+        let cameras = match state.cameras.as_mut() {
+            Some(cameras) => cameras,
+            None => {
+                log(
+                    k4a_log_level_t::K4A_LOG_LEVEL_ERROR,
+                    "The cameras are not running",
+                );
+                return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED;
+            }
+        };
+        let latest = cameras.latest_frame(elapsed_usec(Instant::now()));
+        if latest >= cameras.next_frame {
+            // A reader that falls behind only sees the newest frames, like the SDK's queue.
+            let frame = cameras
+                .next_frame
+                .max(latest + 1 - CAPTURE_QUEUE_SIZE.min(latest + 1));
+            cameras.next_frame = frame + 1;
+            let capture = build_capture(cameras, &controls, frame);
+            unsafe { *capture_handle = capture };
+            return k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED;
+        }
+        let due = cameras.frame_time_usec(cameras.next_frame);
+        drop(state);
+        if !wait_until(due, deadline) {
+            return k4a_wait_result_t::K4A_WAIT_RESULT_TIMEOUT;
+        }
+    }
+}
+
+fn imu_sample(index: u64) -> k4a_imu_sample_t {
+    let timestamp_usec = 1_000_000 + index * IMU_PERIOD_USEC;
+    let t = timestamp_usec as f32 / 1_000_000.0;
+    let wobble = (2.0 * std::f32::consts::PI * t).sin();
+    k4a_imu_sample_t {
+        temperature: 30.5,
+        acc_sample: k4a_float3_t {
+            v: [0.05 * wobble, 0.02, -9.81],
+        },
+        acc_timestamp_usec: timestamp_usec,
+        gyro_sample: k4a_float3_t {
+            v: [0.001 * wobble, -0.002 * wobble, 0.0005],
+        },
+        gyro_timestamp_usec: timestamp_usec,
+    }
+}
+
+pub(super) extern "C" fn k4a_device_get_imu_sample(
+    device_handle: k4a_device_t,
+    imu_sample: *mut k4a_imu_sample_t,
+    timeout_in_ms: i32,
+) -> k4a_wait_result_t {
+    let deadline = deadline(timeout_in_ms);
+    loop {
+        let device = match attached_device(device_handle) {
+            Some(device) => device,
+            None => return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED,
+        };
+        let mut state = device.state.lock().unwrap();
+        let next = match state.next_imu_sample.as_mut() {
+            Some(next) => next,
+            None => {
+                log(
+                    k4a_log_level_t::K4A_LOG_LEVEL_ERROR,
+                    "The IMU is not running",
+                );
+                return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED;
+            }
+        };
+        let latest = elapsed_usec(Instant::now()) / IMU_PERIOD_USEC;
+        if latest >= *next {
+            let index = (*next).max(latest + 1 - IMU_QUEUE_SIZE.min(latest + 1));
+            *next = index + 1;
+            unsafe { *imu_sample = self::imu_sample(index) };
+            return k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED;
+        }
+        let due = *next * IMU_PERIOD_USEC;
+        drop(state);
+        if !wait_until(due, deadline) {
+            return k4a_wait_result_t::K4A_WAIT_RESULT_TIMEOUT;
+        }
+    }
+}
+
+pub(super) extern "C" fn k4a_device_start_imu(device_handle: k4a_device_t) -> k4a_result_t {
+    let device = match attached_device(device_handle) {
+        Some(device) => device,
+        None => return k4a_result_t::K4A_RESULT_FAILED,
+    };
+    let mut state = device.state.lock().unwrap();
+    if state.cameras.is_none() {
+        return fail("The cameras must be started before the IMU");
+    }
+    if state.next_imu_sample.is_some() {
+        return fail("The IMU is already running");
+    }
+    state.next_imu_sample = Some(elapsed_usec(Instant::now()) / IMU_PERIOD_USEC + 1);
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_stop_imu(device_handle: k4a_device_t) {
+    if let Some(device) = unsafe { device_ref(device_handle) } {
+        device.state.lock().unwrap().next_imu_sample = None;
+    }
+}
+
+pub(super) extern "C" fn k4a_device_get_serialnum(
+    device_handle: k4a_device_t,
+    serial_number: *mut ::std::os::raw::c_char,
+    serial_number_size: *mut usize,
+) -> k4a_buffer_result_t {
+    match attached_device(device_handle) {
+        Some(device) => unsafe {
+            copy_to_string_buffer(
+                &device.slot.serial_number,
+                serial_number,
+                serial_number_size,
+            )
+        },
+        None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_device_get_version(
+    device_handle: k4a_device_t,
+    version: *mut k4a_hardware_version_t,
+) -> k4a_result_t {
+    if attached_device(device_handle).is_none() || version.is_null() {
+        return k4a_result_t::K4A_RESULT_FAILED;
+    }
+    let v = |major, minor, iteration| k4a_version_t {
+        major,
+        minor,
+        iteration,
+    };
+    unsafe {
+        *version = k4a_hardware_version_t {
+            rgb: v(1, 6, 110),
+            depth: v(1, 6, 79),
+            audio: v(1, 6, 14),
+            depth_sensor: v(6109, 7, 0),
+            firmware_build: k4a_firmware_build_t::K4A_FIRMWARE_BUILD_RELEASE,
+            firmware_signature: k4a_firmware_signature_t::K4A_FIRMWARE_SIGNATURE_MSFT,
+        }
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+struct ColorControlCapabilities {
+    supports_auto: bool,
+    min_value: i32,
+    max_value: i32,
+    step_value: i32,
+    default_value: i32,
+    default_mode: k4a_color_control_mode_t,
+}
+
+const fn capabilities(
+    supports_auto: bool,
+    min_value: i32,
+    max_value: i32,
+    step_value: i32,
+    default_value: i32,
+) -> ColorControlCapabilities {
+    ColorControlCapabilities {
+        supports_auto,
+        min_value,
+        max_value,
+        step_value,
+        default_value,
+        default_mode: if supports_auto {
+            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO
+        } else {
+            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL
+        },
+    }
+}
+
+/// Capabilities reported by the color camera, indexed by `k4a_color_control_command_t`.
+const COLOR_CONTROLS: [ColorControlCapabilities; 10] = [
+    capabilities(true, 500, 133_330, 100, 16_670),
+    capabilities(false, 0, 1, 1, 0),
+    capabilities(false, 0, 255, 1, 128),
+    capabilities(false, 0, 10, 1, 5),
+    capabilities(false, 0, 63, 1, 32),
+    capabilities(false, 0, 4, 1, 2),
+    capabilities(true, 2_500, 12_500, 10, 4_500),
+    capabilities(false, 0, 1, 1, 0),
+    capabilities(false, 0, 255, 1, 128),
+    capabilities(false, 1, 2, 1, 2),
+];
+
+#[allow(clippy::too_many_arguments)]
+pub(super) extern "C" fn k4a_device_get_color_control_capabilities(
+    device_handle: k4a_device_t,
+    command: k4a_color_control_command_t,
+    supports_auto: *mut bool,
+    min_value: *mut i32,
+    max_value: *mut i32,
+    step_value: *mut i32,
+    default_value: *mut i32,
+    default_mode: *mut k4a_color_control_mode_t,
+) -> k4a_result_t {
+    if attached_device(device_handle).is_none() {
+        return k4a_result_t::K4A_RESULT_FAILED;
+    }
+    let c = &COLOR_CONTROLS[command as usize];
+    unsafe {
+        *supports_auto = c.supports_auto;
+        *min_value = c.min_value;
+        *max_value = c.max_value;
+        *step_value = c.step_value;
+        *default_value = c.default_value;
+        *default_mode = c.default_mode;
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_get_color_control(
+    device_handle: k4a_device_t,
+    command: k4a_color_control_command_t,
+    mode: *mut k4a_color_control_mode_t,
+    value: *mut i32,
+) -> k4a_result_t {
+    let device = match attached_device(device_handle) {
+        Some(device) => device,
+        None => return k4a_result_t::K4A_RESULT_FAILED,
+    };
+    let control = device.state.lock().unwrap().color_controls[command as usize];
+    unsafe {
+        *mode = control.mode;
+        *value = control.value;
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_set_color_control(
+    device_handle: k4a_device_t,
+    command: k4a_color_control_command_t,
+    mode: k4a_color_control_mode_t,
+    value: i32,
+) -> k4a_result_t {
+    let device = match attached_device(device_handle) {
+        Some(device) => device,
+        None => return k4a_result_t::K4A_RESULT_FAILED,
+    };
+    let c = &COLOR_CONTROLS[command as usize];
+    let value = match mode {
+        k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO if !c.supports_auto => {
+            return fail(&format!("{:?} does not support automatic mode", command));
+        }
+        k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO => c.default_value,
+        k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL => {
+            if value < c.min_value
+                || value > c.max_value
+                || (value - c.min_value) % c.step_value != 0
+            {
+                return fail(&format!(
+                    "{} is not a valid value for {:?} ({}..={} step {})",
+                    value, command, c.min_value, c.max_value, c.step_value
+                ));
+            }
+            value
+        }
+    };
+    device.state.lock().unwrap().color_controls[command as usize] = ColorControl { mode, value };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_get_raw_calibration(
+    device_handle: k4a_device_t,
+    data: *mut u8,
+    data_size: *mut usize,
+) -> k4a_buffer_result_t {
+    match attached_device(device_handle) {
+        Some(device) => unsafe {
+            copy_to_buffer(
+                &calibration::raw_calibration(&device.slot.serial_number),
+                data,
+                data_size,
+            )
+        },
+        None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_device_get_calibration(
+    device_handle: k4a_device_t,
+    depth_mode: k4a_depth_mode_t,
+    color_resolution: k4a_color_resolution_t,
+    calibration: *mut k4a_calibration_t,
+) -> k4a_result_t {
+    if attached_device(device_handle).is_none() || calibration.is_null() {
+        return k4a_result_t::K4A_RESULT_FAILED;
+    }
+    unsafe { *calibration = calibration::calibration(depth_mode, color_resolution) };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_device_get_sync_jack(
+    device_handle: k4a_device_t,
+    sync_in_jack_connected: *mut bool,
+    sync_out_jack_connected: *mut bool,
+) -> k4a_result_t {
+    let device = match attached_device(device_handle) {
+        Some(device) => device,
+        None => return k4a_result_t::K4A_RESULT_FAILED,
+    };
+    unsafe {
+        *sync_in_jack_connected = device.slot.sync_in.load(Ordering::SeqCst);
+        *sync_out_jack_connected = device.slot.sync_out.load(Ordering::SeqCst);
+    }
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
@@ -0,0 +1,462 @@
+use super::*;
+use std::sync::atomic::AtomicUsize;
+
+enum Storage {
+    Owned {
+        _buffer: Box<[u8]>,
+    },
+    External {
+        release_cb: k4a_memory_destroy_cb_t,
+        release_cb_context: usize,
+    },
+}
+
+#[derive(Copy, Clone, Default)]
+pub(crate) struct ImageMetadata {
+    pub(crate) device_timestamp_usec: u64,
+    pub(crate) system_timestamp_nsec: u64,
+    pub(crate) exposure_usec: u64,
+    pub(crate) white_balance: u32,
+    pub(crate) iso_speed: u32,
+}
+
+pub(crate) struct SimImage {
+    refs: AtomicUsize,
+    pub(crate) format: k4a_image_format_t,
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+    pub(crate) stride: i32,
+    buffer: *mut u8,
+    size: usize,
+    storage: Storage,
+    pub(crate) metadata: Mutex<ImageMetadata>,
+}
+
+impl RefCounted for SimImage {
+    fn refs(&self) -> &AtomicUsize {
+        &self.refs
+    }
+}
+
+impl SimImage {
+    pub(crate) fn data(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.buffer, self.size) }
+    }
+
+    /// The caller must not hold any other reference into the buffer, which is the same
+    /// contract the SDK gives to `k4a_image_get_buffer`.
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) unsafe fn data_mut(&self) -> &mut [u8] {
+        std::slice::from_raw_parts_mut(self.buffer, self.size)
+    }
+}
+
+impl Drop for SimImage {
+    fn drop(&mut self) {
+        if let Storage::External {
+            release_cb: Some(release_cb),
+            release_cb_context,
+        } = self.storage
+        {
+            unsafe {
+                release_cb(
+                    self.buffer as *mut std::ffi::c_void,
+                    release_cb_context as *mut std::ffi::c_void,
+                )
+            };
+        }
+    }
+}
+
+/// Returns the stride the SDK picks when `k4a_image_create` is called with a stride of 0.
+pub(crate) fn default_stride(format: k4a_image_format_t, width: i32) -> Option<i32> {
+    use k4a_image_format_t::*;
+    match format {
+        K4A_IMAGE_FORMAT_COLOR_BGRA32 => Some(width * 4),
+        K4A_IMAGE_FORMAT_DEPTH16
+        | K4A_IMAGE_FORMAT_IR16
+        | K4A_IMAGE_FORMAT_CUSTOM16
+        | K4A_IMAGE_FORMAT_COLOR_YUY2 => Some(width * 2),
+        K4A_IMAGE_FORMAT_CUSTOM8 | K4A_IMAGE_FORMAT_COLOR_NV12 => Some(width),
+        _ => None,
+    }
+}
+
+fn image_size(format: k4a_image_format_t, height: i32, stride: i32) -> usize {
+    match format {
+        k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_NV12 => (height * stride * 3 / 2) as usize,
+        _ => (height * stride) as usize,
+    }
+}
+
+/// Wraps `data` into a new image handle with a reference count of one.
+pub(crate) fn new_image(
+    format: k4a_image_format_t,
+    width: i32,
+    height: i32,
+    stride: i32,
+    data: Vec<u8>,
+) -> k4a_image_t {
+    // Like the SDK, allocate through the hook of `k4a_set_allocator` when one is registered.
+    if let Some((buffer, release_cb, context)) = hooked_allocate(data.len()) {
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len()) };
+        return into_handle(SimImage {
+            refs: AtomicUsize::new(1),
+            format,
+            width,
+            height,
+            stride,
+            buffer,
+            size: data.len(),
+            storage: Storage::External {
+                release_cb: Some(release_cb),
+                release_cb_context: context as usize,
+            },
+            metadata: Mutex::new(ImageMetadata::default()),
+        });
+    }
+    let mut data = data.into_boxed_slice();
+    into_handle(SimImage {
+        refs: AtomicUsize::new(1),
+        format,
+        width,
+        height,
+        stride,
+        buffer: data.as_mut_ptr(),
+        size: data.len(),
+        storage: Storage::Owned { _buffer: data },
+        metadata: Mutex::new(ImageMetadata::default()),
+    })
+}
+
+/// Allocates a zeroed image, or returns `None` for formats that need an explicit size.
+pub(crate) fn alloc_image(
+    format: k4a_image_format_t,
+    width: i32,
+    height: i32,
+    stride: i32,
+) -> Option<k4a_image_t> {
+    if width <= 0 || height <= 0 || stride < 0 {
+        return None;
+    }
+    let stride = match stride {
+        0 => default_stride(format, width)?,
+        stride => stride,
+    };
+    let size = image_size(format, height, stride);
+    Some(new_image(format, width, height, stride, vec![0; size]))
+}
+
+pub(crate) unsafe fn image_ref<'a>(image_handle: k4a_image_t) -> Option<&'a SimImage> {
+    from_handle(image_handle)
+}
+
+fn with_metadata<T: Default>(
+    image_handle: k4a_image_t,
+    f: impl FnOnce(&mut ImageMetadata) -> T,
+) -> T {
+    match unsafe { image_ref(image_handle) } {
+        Some(image) => f(&mut image.metadata.lock().unwrap()),
+        None => T::default(),
+    }
+}
+
+pub(super) extern "C" fn k4a_image_create(
+    format: k4a_image_format_t,
+    width_pixels: i32,
+    height_pixels: i32,
+    stride_bytes: i32,
+    image_handle: *mut k4a_image_t,
+) -> k4a_result_t {
+    match alloc_image(format, width_pixels, height_pixels, stride_bytes) {
+        Some(handle) => {
+            unsafe { *image_handle = handle };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail(&format!(
+            "Invalid image parameters: {:?} {}x{} stride {}",
+            format, width_pixels, height_pixels, stride_bytes
+        )),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) extern "C" fn k4a_image_create_from_buffer(
+    format: k4a_image_format_t,
+    width_pixels: i32,
+    height_pixels: i32,
+    stride_bytes: i32,
+    buffer: *mut u8,
+    buffer_size: usize,
+    buffer_release_cb: k4a_memory_destroy_cb_t,
+    buffer_release_cb_context: *mut (),
+    image_handle: *mut k4a_image_t,
+) -> k4a_result_t {
+    if buffer.is_null() || width_pixels <= 0 || height_pixels <= 0 || stride_bytes < 0 {
+        return fail("Invalid image buffer parameters");
+    }
+    if format != k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG
+        && buffer_size < image_size(format, height_pixels, stride_bytes)
+    {
+        return fail(&format!(
+            "Image buffer of {} bytes is too small for {:?} {}x{} stride {}",
+            buffer_size, format, width_pixels, height_pixels, stride_bytes
+        ));
+    }
+    unsafe {
+        *image_handle = into_handle(SimImage {
+            refs: AtomicUsize::new(1),
+            format,
+            width: width_pixels,
+            height: height_pixels,
+            stride: stride_bytes,
+            buffer,
+            size: buffer_size,
+            storage: Storage::External {
+                release_cb: buffer_release_cb,
+                release_cb_context: buffer_release_cb_context as usize,
+            },
+            metadata: Mutex::new(ImageMetadata::default()),
+        })
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_image_get_buffer(image_handle: k4a_image_t) -> *mut u8 {
+    unsafe { image_ref(image_handle) }.map_or(ptr::null_mut(), |image| image.buffer)
+}
+
+pub(super) extern "C" fn k4a_image_get_size(image_handle: k4a_image_t) -> usize {
+    unsafe { image_ref(image_handle) }.map_or(0, |image| image.size)
+}
+
+pub(super) extern "C" fn k4a_image_get_format(image_handle: k4a_image_t) -> k4a_image_format_t {
+    unsafe { image_ref(image_handle) }
+        .map_or(k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM, |image| {
+            image.format
+        })
+}
+
+pub(super) extern "C" fn k4a_image_get_width_pixels(image_handle: k4a_image_t) -> i32 {
+    unsafe { image_ref(image_handle) }.map_or(0, |image| image.width)
+}
+
+pub(super) extern "C" fn k4a_image_get_height_pixels(image_handle: k4a_image_t) -> i32 {
+    unsafe { image_ref(image_handle) }.map_or(0, |image| image.height)
+}
+
+pub(super) extern "C" fn k4a_image_get_stride_bytes(image_handle: k4a_image_t) -> i32 {
+    unsafe { image_ref(image_handle) }.map_or(0, |image| image.stride)
+}
+
+pub(super) extern "C" fn k4a_image_get_device_timestamp_usec(image_handle: k4a_image_t) -> u64 {
+    with_metadata(image_handle, |m| m.device_timestamp_usec)
+}
+
+pub(super) extern "C" fn k4a_image_get_system_timestamp_nsec(image_handle: k4a_image_t) -> u64 {
+    with_metadata(image_handle, |m| m.system_timestamp_nsec)
+}
+
+pub(super) extern "C" fn k4a_image_get_exposure_usec(image_handle: k4a_image_t) -> u64 {
+    with_metadata(image_handle, |m| m.exposure_usec)
+}
+
+pub(super) extern "C" fn k4a_image_get_white_balance(image_handle: k4a_image_t) -> u32 {
+    with_metadata(image_handle, |m| m.white_balance)
+}
+
+pub(super) extern "C" fn k4a_image_get_iso_speed(image_handle: k4a_image_t) -> u32 {
+    with_metadata(image_handle, |m| m.iso_speed)
+}
+
+pub(super) extern "C" fn k4a_image_set_device_timestamp_usec(
+    image_handle: k4a_image_t,
+    timestamp_usec: u64,
+) {
+    with_metadata(image_handle, |m| m.device_timestamp_usec = timestamp_usec)
+}
+
+pub(super) extern "C" fn k4a_image_set_system_timestamp_nsec(
+    image_handle: k4a_image_t,
+    timestamp_nsec: u64,
+) {
+    with_metadata(image_handle, |m| m.system_timestamp_nsec = timestamp_nsec)
+}
+
+pub(super) extern "C" fn k4a_image_set_exposure_usec(
+    image_handle: k4a_image_t,
+    exposure_usec: u64,
+) {
+    with_metadata(image_handle, |m| m.exposure_usec = exposure_usec)
+}
+
+pub(super) extern "C" fn k4a_image_set_white_balance(
+    image_handle: k4a_image_t,
+    white_balance: u32,
+) {
+    with_metadata(image_handle, |m| m.white_balance = white_balance)
+}
+
+pub(super) extern "C" fn k4a_image_set_iso_speed(image_handle: k4a_image_t, iso_speed: u32) {
+    with_metadata(image_handle, |m| m.iso_speed = iso_speed)
+}
+
+pub(super) extern "C" fn k4a_image_reference(image_handle: k4a_image_t) {
+    unsafe { add_ref::<SimImage, _>(image_handle) }
+}
+
+pub(super) extern "C" fn k4a_image_release(image_handle: k4a_image_t) {
+    unsafe { release::<SimImage, _>(image_handle) }
+}
+
+//--------------------------------------------------//
+//--------------------- CAPTURE --------------------//
+//--------------------------------------------------//
+
+const COLOR: usize = 0;
+const DEPTH: usize = 1;
+const IR: usize = 2;
+
+pub(crate) struct SimCapture {
+    refs: AtomicUsize,
+    images: Mutex<[usize; 3]>,
+    temperature_c: Mutex<f32>,
+}
+
+impl RefCounted for SimCapture {
+    fn refs(&self) -> &AtomicUsize {
+        &self.refs
+    }
+}
+
+impl Drop for SimCapture {
+    fn drop(&mut self) {
+        for image in self.images.get_mut().unwrap().iter() {
+            k4a_image_release(*image as k4a_image_t);
+        }
+    }
+}
+
+/// Builds a capture that takes ownership of the given image references.
+pub(crate) fn new_capture(
+    color: k4a_image_t,
+    depth: k4a_image_t,
+    ir: k4a_image_t,
+    temperature_c: f32,
+) -> k4a_capture_t {
+    into_handle(SimCapture {
+        refs: AtomicUsize::new(1),
+        images: Mutex::new([color as usize, depth as usize, ir as usize]),
+        temperature_c: Mutex::new(temperature_c),
+    })
+}
+
+pub(crate) unsafe fn capture_ref<'a>(capture_handle: k4a_capture_t) -> Option<&'a SimCapture> {
+    from_handle(capture_handle)
+}
+
+impl SimCapture {
+    /// Returns the image in `slot` without adding a reference.
+    pub(crate) fn image(&self, slot: usize) -> k4a_image_t {
+        self.images.lock().unwrap()[slot] as k4a_image_t
+    }
+
+    pub(crate) fn color(&self) -> k4a_image_t {
+        self.image(COLOR)
+    }
+
+    pub(crate) fn depth(&self) -> k4a_image_t {
+        self.image(DEPTH)
+    }
+
+    pub(crate) fn ir(&self) -> k4a_image_t {
+        self.image(IR)
+    }
+
+    pub(crate) fn temperature_c(&self) -> f32 {
+        *self.temperature_c.lock().unwrap()
+    }
+}
+
+fn get_image(capture_handle: k4a_capture_t, slot: usize) -> k4a_image_t {
+    match unsafe { capture_ref(capture_handle) } {
+        Some(capture) => {
+            let image = capture.image(slot);
+            k4a_image_reference(image);
+            image
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+fn set_image(capture_handle: k4a_capture_t, slot: usize, image_handle: k4a_image_t) {
+    if let Some(capture) = unsafe { capture_ref(capture_handle) } {
+        k4a_image_reference(image_handle);
+        let old = std::mem::replace(
+            &mut capture.images.lock().unwrap()[slot],
+            image_handle as usize,
+        );
+        k4a_image_release(old as k4a_image_t);
+    }
+}
+
+pub(super) extern "C" fn k4a_capture_create(capture_handle: *mut k4a_capture_t) -> k4a_result_t {
+    unsafe {
+        *capture_handle = new_capture(ptr::null_mut(), ptr::null_mut(), ptr::null_mut(), f32::NAN)
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_capture_release(capture_handle: k4a_capture_t) {
+    unsafe { release::<SimCapture, _>(capture_handle) }
+}
+
+pub(super) extern "C" fn k4a_capture_reference(capture_handle: k4a_capture_t) {
+    unsafe { add_ref::<SimCapture, _>(capture_handle) }
+}
+
+pub(super) extern "C" fn k4a_capture_get_color_image(capture_handle: k4a_capture_t) -> k4a_image_t {
+    get_image(capture_handle, COLOR)
+}
+
+pub(super) extern "C" fn k4a_capture_get_depth_image(capture_handle: k4a_capture_t) -> k4a_image_t {
+    get_image(capture_handle, DEPTH)
+}
+
+pub(super) extern "C" fn k4a_capture_get_ir_image(capture_handle: k4a_capture_t) -> k4a_image_t {
+    get_image(capture_handle, IR)
+}
+
+pub(super) extern "C" fn k4a_capture_set_color_image(
+    capture_handle: k4a_capture_t,
+    image_handle: k4a_image_t,
+) {
+    set_image(capture_handle, COLOR, image_handle)
+}
+
+pub(super) extern "C" fn k4a_capture_set_depth_image(
+    capture_handle: k4a_capture_t,
+    image_handle: k4a_image_t,
+) {
+    set_image(capture_handle, DEPTH, image_handle)
+}
+
+pub(super) extern "C" fn k4a_capture_set_ir_image(
+    capture_handle: k4a_capture_t,
+    image_handle: k4a_image_t,
+) {
+    set_image(capture_handle, IR, image_handle)
+}
+
+pub(super) extern "C" fn k4a_capture_set_temperature_c(
+    capture_handle: k4a_capture_t,
+    temperature_c: f32,
+) {
+    if let Some(capture) = unsafe { capture_ref(capture_handle) } {
+        *capture.temperature_c.lock().unwrap() = temperature_c;
+    }
+}
+
+pub(super) extern "C" fn k4a_capture_get_temperature_c(capture_handle: k4a_capture_t) -> f32 {
+    unsafe { capture_ref(capture_handle) }.map_or(f32::NAN, |capture| capture.temperature_c())
+}
@@ -0,0 +1,355 @@
+//! A pure-Rust stand-in for the Azure Kinect SDK libraries.
+//!
+//! [`Api::simulated`], [`ApiRecord::simulated`] and [`ApiTracker::simulated`] fill the same
+//! function tables as the `with_library_directory` constructors, but every entry point is
+//! implemented in Rust. The simulated devices follow the configured `k4a_depth_mode_t`,
+//! color resolution, color format and `k4a_fps_t`, so code built on `Device`, `Capture`,
+//! `Image`, `Playback` and `Tracker` can be tested without hardware or SDK binaries.
+//!
+//! Recordings written through the simulated `ApiRecord` use a small private container format,
+//! so they can only be read back by the simulated playback functions.
+//!
+//! The set of attached devices is process wide, like the USB bus it stands in for. One device
+//! with serial number [`DEFAULT_SERIAL_NUMBER`] is attached until [`attach_device`] or
+//! [`detach_device`] change it. Their sync jacks are unplugged until [`set_sync_jacks`] plugs
+//! them in.
+
+mod calibration;
+mod device;
+mod image;
+mod pattern;
+mod record;
+mod tracker;
+
+pub use device::{
+    attach_device, detach_device, reset_devices, set_sync_jacks, DEFAULT_SERIAL_NUMBER,
+};
+
+use super::bindings::*;
+use super::*;
+use std::ffi::CString;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+impl Api {
+    /// Creates an `Api` backed by the simulated k4a implementation.
+    pub fn simulated() -> Arc<Api> {
+        Arc::new(Api {
+            handle: ptr::null(),
+            require_free_library: false,
+            depth_engine: ptr::null(),
+            sdk_version: SdkVersion::BINDINGS,
+            k4a_device_get_installed_count: device::k4a_device_get_installed_count,
+            k4a_set_debug_message_handler,
+            k4a_set_allocator: Some(k4a_set_allocator),
+            k4a_device_open: device::k4a_device_open,
+            k4a_device_close: device::k4a_device_close,
+            k4a_device_get_capture: device::k4a_device_get_capture,
+            k4a_device_get_imu_sample: device::k4a_device_get_imu_sample,
+            k4a_capture_create: image::k4a_capture_create,
+            k4a_capture_release: image::k4a_capture_release,
+            k4a_capture_reference: image::k4a_capture_reference,
+            k4a_capture_get_color_image: image::k4a_capture_get_color_image,
+            k4a_capture_get_depth_image: image::k4a_capture_get_depth_image,
+            k4a_capture_get_ir_image: image::k4a_capture_get_ir_image,
+            k4a_capture_set_color_image: image::k4a_capture_set_color_image,
+            k4a_capture_set_depth_image: image::k4a_capture_set_depth_image,
+            k4a_capture_set_ir_image: image::k4a_capture_set_ir_image,
+            k4a_capture_set_temperature_c: image::k4a_capture_set_temperature_c,
+            k4a_capture_get_temperature_c: image::k4a_capture_get_temperature_c,
+            k4a_image_create: image::k4a_image_create,
+            k4a_image_create_from_buffer: image::k4a_image_create_from_buffer,
+            k4a_image_get_buffer: image::k4a_image_get_buffer,
+            k4a_image_get_size: image::k4a_image_get_size,
+            k4a_image_get_format: image::k4a_image_get_format,
+            k4a_image_get_width_pixels: image::k4a_image_get_width_pixels,
+            k4a_image_get_height_pixels: image::k4a_image_get_height_pixels,
+            k4a_image_get_stride_bytes: image::k4a_image_get_stride_bytes,
+            k4a_image_get_device_timestamp_usec: image::k4a_image_get_device_timestamp_usec,
+            k4a_image_get_system_timestamp_nsec: image::k4a_image_get_system_timestamp_nsec,
+            k4a_image_get_exposure_usec: image::k4a_image_get_exposure_usec,
+            k4a_image_get_white_balance: image::k4a_image_get_white_balance,
+            k4a_image_get_iso_speed: image::k4a_image_get_iso_speed,
+            k4a_image_set_device_timestamp_usec: image::k4a_image_set_device_timestamp_usec,
+            k4a_image_set_system_timestamp_nsec: image::k4a_image_set_system_timestamp_nsec,
+            k4a_image_set_exposure_usec: image::k4a_image_set_exposure_usec,
+            k4a_image_set_white_balance: image::k4a_image_set_white_balance,
+            k4a_image_set_iso_speed: image::k4a_image_set_iso_speed,
+            k4a_image_reference: image::k4a_image_reference,
+            k4a_image_release: image::k4a_image_release,
+            k4a_device_start_cameras: device::k4a_device_start_cameras,
+            k4a_device_stop_cameras: device::k4a_device_stop_cameras,
+            k4a_device_start_imu: device::k4a_device_start_imu,
+            k4a_device_stop_imu: device::k4a_device_stop_imu,
+            k4a_device_get_serialnum: device::k4a_device_get_serialnum,
+            k4a_device_get_version: device::k4a_device_get_version,
+            k4a_device_get_color_control_capabilities:
+                device::k4a_device_get_color_control_capabilities,
+            k4a_device_get_color_control: device::k4a_device_get_color_control,
+            k4a_device_set_color_control: device::k4a_device_set_color_control,
+            k4a_device_get_raw_calibration: device::k4a_device_get_raw_calibration,
+            k4a_device_get_calibration: device::k4a_device_get_calibration,
+            k4a_device_get_sync_jack: device::k4a_device_get_sync_jack,
+            k4a_calibration_get_from_raw: calibration::k4a_calibration_get_from_raw,
+            k4a_calibration_3d_to_3d: calibration::k4a_calibration_3d_to_3d,
+            k4a_calibration_2d_to_3d: calibration::k4a_calibration_2d_to_3d,
+            k4a_calibration_3d_to_2d: calibration::k4a_calibration_3d_to_2d,
+            k4a_calibration_2d_to_2d: calibration::k4a_calibration_2d_to_2d,
+            k4a_calibration_color_2d_to_depth_2d: calibration::k4a_calibration_color_2d_to_depth_2d,
+            k4a_transformation_create: calibration::k4a_transformation_create,
+            k4a_transformation_destroy: calibration::k4a_transformation_destroy,
+            k4a_transformation_depth_image_to_color_camera:
+                calibration::k4a_transformation_depth_image_to_color_camera,
+            k4a_transformation_depth_image_to_color_camera_custom: Some(
+                calibration::k4a_transformation_depth_image_to_color_camera_custom,
+            ),
+            k4a_transformation_color_image_to_depth_camera:
+                calibration::k4a_transformation_color_image_to_depth_camera,
+            k4a_transformation_depth_image_to_point_cloud:
+                calibration::k4a_transformation_depth_image_to_point_cloud,
+        })
+    }
+}
+
+impl ApiRecord {
+    /// Creates an `ApiRecord` backed by the simulated k4arecord implementation.
+    pub fn simulated() -> Arc<ApiRecord> {
+        Arc::new(ApiRecord {
+            handle: ptr::null(),
+            k4a_playback_open: record::k4a_playback_open,
+            k4a_playback_get_raw_calibration: record::k4a_playback_get_raw_calibration,
+            k4a_playback_get_calibration: record::k4a_playback_get_calibration,
+            k4a_playback_get_record_configuration: record::k4a_playback_get_record_configuration,
+            k4a_playback_check_track_exists: record::k4a_playback_check_track_exists,
+            k4a_playback_get_track_count: record::k4a_playback_get_track_count,
+            k4a_playback_get_track_name: record::k4a_playback_get_track_name,
+            k4a_playback_track_is_builtin: record::k4a_playback_track_is_builtin,
+            k4a_playback_track_get_video_settings: record::k4a_playback_track_get_video_settings,
+            k4a_playback_track_get_codec_id: record::k4a_playback_track_get_codec_id,
+            k4a_playback_track_get_codec_context: record::k4a_playback_track_get_codec_context,
+            k4a_playback_get_tag: record::k4a_playback_get_tag,
+            k4a_playback_set_color_conversion: record::k4a_playback_set_color_conversion,
+            k4a_playback_get_attachment: record::k4a_playback_get_attachment,
+            k4a_playback_get_next_capture: record::k4a_playback_get_next_capture,
+            k4a_playback_get_previous_capture: record::k4a_playback_get_previous_capture,
+            k4a_playback_get_next_imu_sample: record::k4a_playback_get_next_imu_sample,
+            k4a_playback_get_previous_imu_sample: record::k4a_playback_get_previous_imu_sample,
+            k4a_playback_get_next_data_block: record::k4a_playback_get_next_data_block,
+            k4a_playback_get_previous_data_block: record::k4a_playback_get_previous_data_block,
+            k4a_playback_data_block_get_device_timestamp_usec:
+                record::k4a_playback_data_block_get_device_timestamp_usec,
+            k4a_playback_data_block_get_buffer_size:
+                record::k4a_playback_data_block_get_buffer_size,
+            k4a_playback_data_block_get_buffer: record::k4a_playback_data_block_get_buffer,
+            k4a_playback_data_block_release: record::k4a_playback_data_block_release,
+            k4a_playback_seek_timestamp: record::k4a_playback_seek_timestamp,
+            k4a_playback_get_recording_length_usec: record::k4a_playback_get_recording_length_usec,
+            k4a_playback_close: record::k4a_playback_close,
+            k4a_record_create: record::k4a_record_create,
+            k4a_record_add_tag: record::k4a_record_add_tag,
+            k4a_record_add_imu_track: record::k4a_record_add_imu_track,
+            k4a_record_add_attachment: record::k4a_record_add_attachment,
+            k4a_record_add_custom_video_track: record::k4a_record_add_custom_video_track,
+            k4a_record_add_custom_subtitle_track: record::k4a_record_add_custom_subtitle_track,
+            k4a_record_write_header: record::k4a_record_write_header,
+            k4a_record_write_capture: record::k4a_record_write_capture,
+            k4a_record_write_imu_sample: record::k4a_record_write_imu_sample,
+            k4a_record_write_custom_track_data: record::k4a_record_write_custom_track_data,
+            k4a_record_flush: record::k4a_record_flush,
+            k4a_record_close: record::k4a_record_close,
+        })
+    }
+}
+
+impl ApiTracker {
+    /// Creates an `ApiTracker` backed by the simulated body tracker, which reports one
+    /// synthetic body walking in front of the camera.
+    pub fn simulated() -> Arc<ApiTracker> {
+        Arc::new(ApiTracker {
+            handle: ptr::null(),
+            k4abt_tracker_create: tracker::k4abt_tracker_create,
+            k4abt_tracker_destroy: tracker::k4abt_tracker_destroy,
+            k4abt_tracker_set_temporal_smoothing: tracker::k4abt_tracker_set_temporal_smoothing,
+            k4abt_tracker_enqueue_capture: tracker::k4abt_tracker_enqueue_capture,
+            k4abt_tracker_pop_result: tracker::k4abt_tracker_pop_result,
+            k4abt_tracker_shutdown: tracker::k4abt_tracker_shutdown,
+            k4abt_frame_release: tracker::k4abt_frame_release,
+            k4abt_frame_reference: tracker::k4abt_frame_reference,
+            k4abt_frame_get_num_bodies: tracker::k4abt_frame_get_num_bodies,
+            k4abt_frame_get_body_skeleton: tracker::k4abt_frame_get_body_skeleton,
+            k4abt_frame_get_body_id: tracker::k4abt_frame_get_body_id,
+            k4abt_frame_get_device_timestamp_usec: tracker::k4abt_frame_get_device_timestamp_usec,
+            k4abt_frame_get_body_index_map: tracker::k4abt_frame_get_body_index_map,
+            k4abt_frame_get_capture: tracker::k4abt_frame_get_capture,
+        })
+    }
+}
+
+//--------------------------------------------------//
+//----------------- DEBUG MESSAGES -----------------//
+//--------------------------------------------------//
+
+struct Logger {
+    message_cb: k4a_logging_message_cb_t,
+    message_cb_context: usize,
+    min_level: k4a_log_level_t,
+}
+
+static LOGGER: Mutex<Option<Logger>> = Mutex::new(None);
+
+extern "C" fn k4a_set_debug_message_handler(
+    message_cb: k4a_logging_message_cb_t,
+    message_cb_context: *mut (),
+    min_level: k4a_log_level_t,
+) -> k4a_result_t {
+    *LOGGER.lock().unwrap() = message_cb.map(|_| Logger {
+        message_cb,
+        message_cb_context: message_cb_context as usize,
+        min_level,
+    });
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+/// Reports a message through the handler registered with `k4a_set_debug_message_handler`.
+pub(crate) fn log(level: k4a_log_level_t, message: &str) {
+    let (message_cb, context) = match LOGGER.lock().unwrap().as_ref() {
+        Some(logger) if (level as i32) <= (logger.min_level as i32) => {
+            (logger.message_cb, logger.message_cb_context)
+        }
+        _ => return,
+    };
+    let file = CString::new(file!()).unwrap_or_default();
+    let message = CString::new(message).unwrap_or_default();
+    if let Some(message_cb) = message_cb {
+        unsafe {
+            message_cb(
+                context as *mut std::ffi::c_void,
+                level,
+                file.as_ptr(),
+                0,
+                message.as_ptr(),
+            )
+        };
+    }
+}
+
+/// Logs `message` as an error and returns `K4A_RESULT_FAILED`.
+pub(crate) fn fail(message: &str) -> k4a_result_t {
+    log(k4a_log_level_t::K4A_LOG_LEVEL_ERROR, message);
+    k4a_result_t::K4A_RESULT_FAILED
+}
+
+//--------------------------------------------------//
+//-------------------- ALLOCATOR -------------------//
+//--------------------------------------------------//
+
+type AllocateCallback = unsafe extern "C" fn(
+    size: ::std::os::raw::c_int,
+    context: *mut *mut std::ffi::c_void,
+) -> *mut u8;
+type FreeCallback =
+    unsafe extern "C" fn(buffer: *mut std::ffi::c_void, context: *mut std::ffi::c_void);
+
+static ALLOCATOR: Mutex<Option<(AllocateCallback, FreeCallback)>> = Mutex::new(None);
+
+extern "C" fn k4a_set_allocator(
+    allocate: k4a_memory_allocate_cb_t,
+    free: k4a_memory_destroy_cb_t,
+) -> k4a_result_t {
+    *ALLOCATOR.lock().unwrap() = match (allocate, free) {
+        (Some(allocate), Some(free)) => Some((allocate, free)),
+        (None, None) => None,
+        _ => return fail("allocate and free must both be set or both be NULL"),
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+/// Allocates `size` bytes through the hook registered with `k4a_set_allocator`. Returns `None`
+/// when no hook is registered, and otherwise the buffer with the callback and context that
+/// release it.
+pub(crate) fn hooked_allocate(
+    size: usize,
+) -> Option<(*mut u8, FreeCallback, *mut std::ffi::c_void)> {
+    let (allocate, free) = (*ALLOCATOR.lock().unwrap())?;
+    let mut context = ptr::null_mut();
+    let buffer = unsafe { allocate(size as ::std::os::raw::c_int, &mut context) };
+    if buffer.is_null() {
+        log(
+            k4a_log_level_t::K4A_LOG_LEVEL_WARNING,
+            "Allocator hook failed, falling back to the default allocator",
+        );
+        return None;
+    }
+    Some((buffer, free, context))
+}
+
+//--------------------------------------------------//
+//--------------------- HANDLES --------------------//
+//--------------------------------------------------//
+
+/// Objects handed out to the caller as reference counted handles.
+pub(crate) trait RefCounted: Sized {
+    fn refs(&self) -> &AtomicUsize;
+}
+
+pub(crate) fn into_handle<T, H>(value: T) -> *mut H {
+    Box::into_raw(Box::new(value)) as *mut H
+}
+
+pub(crate) unsafe fn from_handle<'a, T, H>(handle: *mut H) -> Option<&'a T> {
+    (handle as *const T).as_ref()
+}
+
+pub(crate) unsafe fn add_ref<T: RefCounted, H>(handle: *mut H) {
+    if let Some(value) = from_handle::<T, H>(handle) {
+        value.refs().fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+pub(crate) unsafe fn release<T: RefCounted, H>(handle: *mut H) {
+    if let Some(value) = from_handle::<T, H>(handle) {
+        if value.refs().fetch_sub(1, Ordering::AcqRel) == 1 {
+            drop(Box::from_raw(handle as *mut T));
+        }
+    }
+}
+
+/// Copies `data` into a caller supplied buffer following the `k4a_buffer_result_t` protocol.
+pub(crate) unsafe fn copy_to_buffer(
+    data: &[u8],
+    buffer: *mut u8,
+    buffer_size: *mut usize,
+) -> k4a_buffer_result_t {
+    if buffer_size.is_null() {
+        return k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED;
+    }
+    if buffer.is_null() || *buffer_size < data.len() {
+        *buffer_size = data.len();
+        return k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL;
+    }
+    ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+    *buffer_size = data.len();
+    k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED
+}
+
+/// Like `copy_to_buffer`, but for strings which are returned with a nul terminator.
+pub(crate) unsafe fn copy_to_string_buffer(
+    value: &str,
+    buffer: *mut ::std::os::raw::c_char,
+    buffer_size: *mut usize,
+) -> k4a_buffer_result_t {
+    let mut data = Vec::with_capacity(value.len() + 1);
+    data.extend_from_slice(value.as_bytes());
+    data.push(0);
+    copy_to_buffer(&data, buffer as *mut u8, buffer_size)
+}
+
+/// Reads a nul terminated string argument.
+pub(crate) unsafe fn c_str<'a>(s: *const ::std::os::raw::c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        std::ffi::CStr::from_ptr(s).to_str().ok()
+    }
+}
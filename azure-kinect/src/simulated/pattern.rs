@@ -0,0 +1,319 @@
+//! Synthetic sensor data: a flat back wall with a ball swinging from side to side in front
+//! of it, and a color test pattern that scrolls with time.
+
+use super::*;
+use std::f32::consts::PI;
+
+/// Horizontal center and radius of the ball, as fractions of the image width and height.
+fn ball(t: f32) -> (f32, f32) {
+    (0.5 + 0.3 * (2.0 * PI * t / 4.0).sin(), 0.2)
+}
+
+/// Returns the depth in millimeters seen at pixel (x, y), or 0 when there is no valid depth.
+pub(crate) fn depth_at(
+    mode: k4a_depth_mode_t,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    t: f32,
+) -> u16 {
+    let range = mode.get_range();
+    if range.max == 0 {
+        return 0;
+    }
+    let u = (x as f32 + 0.5) / width as f32;
+    let v = (y as f32 + 0.5) / height as f32;
+    let is_wide = matches!(
+        mode,
+        k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_2X2BINNED
+            | k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_UNBINNED
+    );
+    // The wide field of view modes only produce depth inside a circle, like the real sensor.
+    if is_wide && (u - 0.5).powi(2) + (v - 0.5).powi(2) > 0.25 {
+        return 0;
+    }
+    let (min, max) = (range.min as f32, range.max as f32);
+    let wall = min + (max - min) * 0.75;
+    let (ball_u, ball_r) = ball(t);
+    let d2 = ((u - ball_u) / ball_r).powi(2)
+        + ((v - 0.5) * height as f32 / width as f32 / ball_r).powi(2);
+    if d2 < 1.0 {
+        let center = min + (max - min) * 0.3;
+        (center - (1.0 - d2).sqrt() * (max - min) * 0.05) as u16
+    } else {
+        wall as u16
+    }
+}
+
+/// Returns the IR brightness at pixel (x, y).
+pub(crate) fn ir_at(
+    mode: k4a_depth_mode_t,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    t: f32,
+) -> u16 {
+    let level = mode.get_ir_level();
+    if mode == k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR {
+        let u = x as f32 / width as f32;
+        return (level.min as f32 + (level.max - level.min) as f32 * u) as u16;
+    }
+    match depth_at(mode, x, y, width, height, t) {
+        0 => 0,
+        depth => {
+            let near = mode.get_range().min as f32;
+            (level.max as f32 * (near / depth as f32).sqrt()) as u16
+        }
+    }
+}
+
+/// Returns the RGB color of the test pattern at pixel (x, y).
+pub(crate) fn color_at(x: i32, y: i32, width: i32, height: i32, t: f32) -> [u8; 3] {
+    let u = x as f32 / width as f32;
+    let v = y as f32 / height as f32;
+    let (ball_u, ball_r) = ball(t);
+    if ((u - ball_u) / ball_r).powi(2) + ((v - 0.5) * height as f32 / width as f32 / ball_r).powi(2)
+        < 1.0
+    {
+        return [230, 40, 40];
+    }
+    let bar = ((u * 8.0 + t * 0.25) as i32).rem_euclid(8);
+    let r = if bar & 1 != 0 { 200 } else { 40 };
+    let g = if bar & 2 != 0 { 200 } else { 40 };
+    let b = if bar & 4 != 0 { 200 } else { 40 };
+    let shade = 0.5 + 0.5 * (1.0 - v);
+    [
+        (r as f32 * shade) as u8,
+        (g as f32 * shade) as u8,
+        (b as f32 * shade) as u8,
+    ]
+}
+
+/// BT.601 in the full range, as JPEG stores it.
+fn rgb_to_yuv([r, g, b]: [u8; 3]) -> [u8; 3] {
+    let (r, g, b) = (r as f32, g as f32, b as f32);
+    let y = 0.299 * r + 0.587 * g + 0.114 * b;
+    let u = 128.0 - 0.168_736 * r - 0.331_264 * g + 0.5 * b;
+    let v = 128.0 + 0.5 * r - 0.418_688 * g - 0.081_312 * b;
+    [
+        y.round().clamp(0.0, 255.0) as u8,
+        u.round().clamp(0.0, 255.0) as u8,
+        v.round().clamp(0.0, 255.0) as u8,
+    ]
+}
+
+/// BT.601 in the studio range, with 16 to 235 for luma, as the color camera outputs NV12 and
+/// YUY2.
+fn rgb_to_studio_yuv([r, g, b]: [u8; 3]) -> [u8; 3] {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let y = 16.0 + 65.481 * r + 128.553 * g + 24.966 * b;
+    let u = 128.0 - 37.797 * r - 74.203 * g + 112.0 * b;
+    let v = 128.0 + 112.0 * r - 93.786 * g - 18.214 * b;
+    [
+        y.round().clamp(0.0, 255.0) as u8,
+        u.round().clamp(0.0, 255.0) as u8,
+        v.round().clamp(0.0, 255.0) as u8,
+    ]
+}
+
+pub(crate) fn depth_image(mode: k4a_depth_mode_t, t: f32) -> Vec<u8> {
+    let Dimension { width, height } = mode.get_dimension();
+    let mut data = Vec::with_capacity((width * height * 2) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            data.extend_from_slice(&depth_at(mode, x, y, width, height, t).to_le_bytes());
+        }
+    }
+    data
+}
+
+pub(crate) fn ir_image(mode: k4a_depth_mode_t, t: f32) -> Vec<u8> {
+    let Dimension { width, height } = mode.get_dimension();
+    let mut data = Vec::with_capacity((width * height * 2) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            data.extend_from_slice(&ir_at(mode, x, y, width, height, t).to_le_bytes());
+        }
+    }
+    data
+}
+
+/// Renders the color test pattern in `format`, or returns `None` for formats the color
+/// camera cannot produce.
+pub(crate) fn color_image(
+    format: k4a_image_format_t,
+    width: i32,
+    height: i32,
+    t: f32,
+) -> Option<Vec<u8>> {
+    use k4a_image_format_t::*;
+    let (w, h) = (width as usize, height as usize);
+    let data = match format {
+        K4A_IMAGE_FORMAT_COLOR_BGRA32 => {
+            let mut data = Vec::with_capacity(w * h * 4);
+            for y in 0..height {
+                for x in 0..width {
+                    let [r, g, b] = color_at(x, y, width, height, t);
+                    data.extend_from_slice(&[b, g, r, 255]);
+                }
+            }
+            data
+        }
+        K4A_IMAGE_FORMAT_COLOR_NV12 => {
+            let mut data = vec![0; w * h * 3 / 2];
+            let (luma, chroma) = data.split_at_mut(w * h);
+            for y in 0..height {
+                for x in 0..width {
+                    let [l, u, v] = rgb_to_studio_yuv(color_at(x, y, width, height, t));
+                    luma[y as usize * w + x as usize] = l;
+                    if x % 2 == 0 && y % 2 == 0 {
+                        let i = (y as usize / 2) * w + x as usize;
+                        chroma[i] = u;
+                        chroma[i + 1] = v;
+                    }
+                }
+            }
+            data
+        }
+        K4A_IMAGE_FORMAT_COLOR_YUY2 => {
+            let mut data = Vec::with_capacity(w * h * 2);
+            for y in 0..height {
+                for x in (0..width).step_by(2) {
+                    let [y0, u, v] = rgb_to_studio_yuv(color_at(x, y, width, height, t));
+                    let [y1, _, _] = rgb_to_studio_yuv(color_at(x + 1, y, width, height, t));
+                    data.extend_from_slice(&[y0, u, y1, v]);
+                }
+            }
+            data
+        }
+        K4A_IMAGE_FORMAT_COLOR_MJPG => encode_jpeg(width, height, |x, y| {
+            rgb_to_yuv(color_at(x, y, width, height, t))
+        }),
+        _ => return None,
+    };
+    Some(data)
+}
+
+//--------------------------------------------------//
+//---------------------- MJPG ----------------------//
+//--------------------------------------------------//
+
+/// Code lengths of the DC table from Annex K.3 of the JPEG specification, used for every
+/// component. Its symbols are the magnitude categories 0 to 11.
+const DC_BITS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+
+struct BitWriter {
+    data: Vec<u8>,
+    acc: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn put(&mut self, bits: u32, len: u32) {
+        for i in (0..len).rev() {
+            self.acc = (self.acc << 1) | ((bits >> i) & 1);
+            self.count += 1;
+            if self.count == 8 {
+                let byte = self.acc as u8;
+                self.data.push(byte);
+                if byte == 0xff {
+                    self.data.push(0);
+                }
+                self.acc = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            let pad = 8 - self.count;
+            self.put((1 << pad) - 1, pad);
+        }
+        self.data
+    }
+}
+
+fn segment(out: &mut Vec<u8>, marker: u8, payload: &[u8]) {
+    out.extend_from_slice(&[0xff, marker]);
+    out.extend_from_slice(&((payload.len() + 2) as u16).to_be_bytes());
+    out.extend_from_slice(payload);
+}
+
+/// Encodes a baseline 4:2:0 JPEG in which every 8x8 block is flat.
+///
+/// Only the DC coefficients are coded, sampled at the block centers, which keeps the encoder
+/// tiny while still producing a stream any JPEG decoder accepts.
+fn encode_jpeg(width: i32, height: i32, yuv_at: impl Fn(i32, i32) -> [u8; 3]) -> Vec<u8> {
+    // Canonical Huffman codes for the DC categories.
+    let mut dc_codes = [(0u32, 0u32); 12];
+    let (mut code, mut symbol) = (0u32, 0usize);
+    for (len, &count) in DC_BITS.iter().enumerate() {
+        for _ in 0..count {
+            dc_codes[symbol] = (code, len as u32 + 1);
+            code += 1;
+            symbol += 1;
+        }
+        code <<= 1;
+    }
+
+    let mut out = vec![0xff, 0xd8];
+    // All AC coefficients are zero, and quantizing the DC by 8 maps it onto the sample range.
+    let mut dqt = vec![0u8];
+    dqt.extend_from_slice(&[8; 64]);
+    segment(&mut out, 0xdb, &dqt);
+    let (w, h) = (width as u16, height as u16);
+    let mut sof = vec![8];
+    sof.extend_from_slice(&h.to_be_bytes());
+    sof.extend_from_slice(&w.to_be_bytes());
+    sof.extend_from_slice(&[3, 1, 0x22, 0, 2, 0x11, 0, 3, 0x11, 0]);
+    segment(&mut out, 0xc0, &sof);
+    let mut dht = vec![0x00];
+    dht.extend_from_slice(&DC_BITS);
+    dht.extend(0..12u8);
+    // A single one bit code for end-of-block is the whole AC table.
+    dht.push(0x10);
+    dht.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    dht.push(0x00);
+    segment(&mut out, 0xc4, &dht);
+    segment(&mut out, 0xda, &[3, 1, 0x00, 2, 0x00, 3, 0x00, 0, 63, 0]);
+
+    let mut bits = BitWriter {
+        data: Vec::new(),
+        acc: 0,
+        count: 0,
+    };
+    let mut previous = [0i32; 3];
+    let mut put_block = |bits: &mut BitWriter, component: usize, value: u8| {
+        let dc = value as i32 - 128;
+        let diff = dc - previous[component];
+        previous[component] = dc;
+        let category = 32 - diff.unsigned_abs().leading_zeros();
+        let (code, len) = dc_codes[category as usize];
+        bits.put(code, len);
+        let magnitude = if diff < 0 {
+            diff + (1 << category) - 1
+        } else {
+            diff
+        };
+        bits.put(magnitude as u32, category);
+        // End of block.
+        bits.put(0, 1);
+    };
+    let clamp = |x: i32, y: i32| yuv_at(x.min(width - 1), y.min(height - 1));
+    for mcu_y in (0..height).step_by(16) {
+        for mcu_x in (0..width).step_by(16) {
+            for (bx, by) in [(0, 0), (8, 0), (0, 8), (8, 8)] {
+                put_block(&mut bits, 0, clamp(mcu_x + bx + 4, mcu_y + by + 4)[0]);
+            }
+            let [_, u, v] = clamp(mcu_x + 8, mcu_y + 8);
+            put_block(&mut bits, 1, u);
+            put_block(&mut bits, 2, v);
+        }
+    }
+    out.extend(bits.finish());
+    out.extend_from_slice(&[0xff, 0xd9]);
+    out
+}
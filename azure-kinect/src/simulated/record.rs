@@ -0,0 +1,1281 @@
+use super::image::{capture_ref, image_ref, new_capture, new_image, ImageMetadata, SimImage};
+use super::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::raw::c_char;
+
+const MAGIC: &[u8; 8] = b"K4ASIMRC";
+const VERSION: u32 = 1;
+
+const BUILTIN_TRACKS: [&str; 4] = ["COLOR", "DEPTH", "IR", "IMU"];
+
+//--------------------------------------------------//
+//--------------------- FORMAT ---------------------//
+//--------------------------------------------------//
+
+struct RecordedImage {
+    format: k4a_image_format_t,
+    width: i32,
+    height: i32,
+    stride: i32,
+    metadata: ImageMetadata,
+    data: Vec<u8>,
+}
+
+struct RecordedCapture {
+    images: [Option<RecordedImage>; 3],
+    temperature_c: f32,
+}
+
+impl RecordedCapture {
+    fn timestamp_usec(&self) -> u64 {
+        self.images
+            .iter()
+            .flatten()
+            .map(|image| image.metadata.device_timestamp_usec)
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+enum TrackSettings {
+    Video(k4a_record_video_settings_t),
+    Subtitle(k4a_record_subtitle_settings_t),
+}
+
+struct CustomTrack {
+    name: String,
+    codec_id: String,
+    codec_context: Vec<u8>,
+    settings: TrackSettings,
+}
+
+struct DataBlock {
+    track: String,
+    timestamp_usec: u64,
+    data: Vec<u8>,
+}
+
+struct Recording {
+    config: k4a_device_configuration_t,
+    imu_track: bool,
+    raw_calibration: Vec<u8>,
+    tags: Vec<(String, String)>,
+    attachments: Vec<(String, Vec<u8>)>,
+    tracks: Vec<CustomTrack>,
+    captures: Vec<RecordedCapture>,
+    imu_samples: Vec<k4a_imu_sample_t>,
+    blocks: Vec<DataBlock>,
+}
+
+struct Writer<W: Write>(W);
+
+impl<W: Write> Writer<W> {
+    fn u8(&mut self, v: u8) -> io::Result<()> {
+        self.0.write_all(&[v])
+    }
+    fn i32(&mut self, v: i32) -> io::Result<()> {
+        self.0.write_all(&v.to_le_bytes())
+    }
+    fn u32(&mut self, v: u32) -> io::Result<()> {
+        self.0.write_all(&v.to_le_bytes())
+    }
+    fn u64(&mut self, v: u64) -> io::Result<()> {
+        self.0.write_all(&v.to_le_bytes())
+    }
+    fn f32(&mut self, v: f32) -> io::Result<()> {
+        self.0.write_all(&v.to_le_bytes())
+    }
+    fn bytes(&mut self, v: &[u8]) -> io::Result<()> {
+        self.u64(v.len() as u64)?;
+        self.0.write_all(v)
+    }
+    fn str(&mut self, v: &str) -> io::Result<()> {
+        self.bytes(v.as_bytes())
+    }
+    fn len(&mut self, v: usize) -> io::Result<()> {
+        self.u64(v as u64)
+    }
+}
+
+struct Reader<R: Read>(R);
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl<R: Read> Reader<R> {
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.0.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+    fn i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+    fn f32(&mut self) -> io::Result<f32> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+    fn bytes(&mut self) -> io::Result<Vec<u8>> {
+        let len = self.u64()?;
+        let mut buf = Vec::new();
+        (&mut self.0).take(len).read_to_end(&mut buf)?;
+        if buf.len() as u64 != len {
+            return Err(invalid("truncated recording"));
+        }
+        Ok(buf)
+    }
+    fn str(&mut self) -> io::Result<String> {
+        String::from_utf8(self.bytes()?).map_err(|_| invalid("invalid string"))
+    }
+    fn len(&mut self) -> io::Result<usize> {
+        Ok(self.u64()? as usize)
+    }
+}
+
+/// Converts a stored discriminant back into one of the `#[repr(i32)]` binding enums.
+macro_rules! enum_from_i32 {
+    ($name:ident, $ty:ty, $max:expr) => {
+        fn $name(v: i32) -> io::Result<$ty> {
+            if (0..=$max).contains(&v) {
+                Ok(unsafe { std::mem::transmute::<i32, $ty>(v) })
+            } else {
+                Err(invalid(stringify!($ty)))
+            }
+        }
+    };
+}
+
+enum_from_i32!(image_format, k4a_image_format_t, 8);
+enum_from_i32!(color_resolution, k4a_color_resolution_t, 6);
+enum_from_i32!(depth_mode, k4a_depth_mode_t, 5);
+enum_from_i32!(fps, k4a_fps_t, 2);
+enum_from_i32!(wired_sync_mode, k4a_wired_sync_mode_t, 2);
+
+impl Recording {
+    fn write<W: Write>(&self, w: &mut Writer<W>) -> io::Result<()> {
+        w.0.write_all(MAGIC)?;
+        w.u32(VERSION)?;
+        let c = &self.config;
+        w.i32(c.color_format as i32)?;
+        w.i32(c.color_resolution as i32)?;
+        w.i32(c.depth_mode as i32)?;
+        w.i32(c.camera_fps as i32)?;
+        w.i32(c.depth_delay_off_color_usec)?;
+        w.i32(c.wired_sync_mode as i32)?;
+        w.u32(c.subordinate_delay_off_master_usec)?;
+        w.u8(self.imu_track as u8)?;
+        w.bytes(&self.raw_calibration)?;
+        w.len(self.tags.len())?;
+        for (name, value) in &self.tags {
+            w.str(name)?;
+            w.str(value)?;
+        }
+        w.len(self.attachments.len())?;
+        for (name, data) in &self.attachments {
+            w.str(name)?;
+            w.bytes(data)?;
+        }
+        w.len(self.tracks.len())?;
+        for track in &self.tracks {
+            w.str(&track.name)?;
+            w.str(&track.codec_id)?;
+            w.bytes(&track.codec_context)?;
+            match &track.settings {
+                TrackSettings::Video(s) => {
+                    w.u8(0)?;
+                    w.u64(s.width)?;
+                    w.u64(s.height)?;
+                    w.u64(s.frame_rate)?;
+                }
+                TrackSettings::Subtitle(s) => {
+                    w.u8(1)?;
+                    w.u8(s.high_freq_data as u8)?;
+                }
+            }
+        }
+        w.len(self.captures.len())?;
+        for capture in &self.captures {
+            w.f32(capture.temperature_c)?;
+            for image in &capture.images {
+                match image {
+                    None => w.u8(0)?,
+                    Some(image) => {
+                        w.u8(1)?;
+                        w.i32(image.format as i32)?;
+                        w.i32(image.width)?;
+                        w.i32(image.height)?;
+                        w.i32(image.stride)?;
+                        let m = &image.metadata;
+                        w.u64(m.device_timestamp_usec)?;
+                        w.u64(m.system_timestamp_nsec)?;
+                        w.u64(m.exposure_usec)?;
+                        w.u32(m.white_balance)?;
+                        w.u32(m.iso_speed)?;
+                        w.bytes(&image.data)?;
+                    }
+                }
+            }
+        }
+        w.len(self.imu_samples.len())?;
+        for sample in &self.imu_samples {
+            w.f32(sample.temperature)?;
+            for v in unsafe { sample.acc_sample.v }.iter() {
+                w.f32(*v)?;
+            }
+            w.u64(sample.acc_timestamp_usec)?;
+            for v in unsafe { sample.gyro_sample.v }.iter() {
+                w.f32(*v)?;
+            }
+            w.u64(sample.gyro_timestamp_usec)?;
+        }
+        w.len(self.blocks.len())?;
+        for block in &self.blocks {
+            w.str(&block.track)?;
+            w.u64(block.timestamp_usec)?;
+            w.bytes(&block.data)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut Reader<R>) -> io::Result<Recording> {
+        if &r.array::<8>()? != MAGIC || r.u32()? != VERSION {
+            return Err(invalid("not a simulated recording"));
+        }
+        let config = k4a_device_configuration_t {
+            color_format: image_format(r.i32()?)?,
+            color_resolution: color_resolution(r.i32()?)?,
+            depth_mode: depth_mode(r.i32()?)?,
+            camera_fps: fps(r.i32()?)?,
+            synchronized_images_only: false,
+            depth_delay_off_color_usec: r.i32()?,
+            wired_sync_mode: wired_sync_mode(r.i32()?)?,
+            subordinate_delay_off_master_usec: r.u32()?,
+            disable_streaming_indicator: false,
+        };
+        let imu_track = r.u8()? != 0;
+        let raw_calibration = r.bytes()?;
+        let mut tags = Vec::new();
+        for _ in 0..r.len()? {
+            tags.push((r.str()?, r.str()?));
+        }
+        let mut attachments = Vec::new();
+        for _ in 0..r.len()? {
+            attachments.push((r.str()?, r.bytes()?));
+        }
+        let mut tracks = Vec::new();
+        for _ in 0..r.len()? {
+            let name = r.str()?;
+            let codec_id = r.str()?;
+            let codec_context = r.bytes()?;
+            let settings = match r.u8()? {
+                0 => TrackSettings::Video(k4a_record_video_settings_t {
+                    width: r.u64()?,
+                    height: r.u64()?,
+                    frame_rate: r.u64()?,
+                }),
+                _ => TrackSettings::Subtitle(k4a_record_subtitle_settings_t {
+                    high_freq_data: r.u8()? != 0,
+                }),
+            };
+            tracks.push(CustomTrack {
+                name,
+                codec_id,
+                codec_context,
+                settings,
+            });
+        }
+        let mut captures = Vec::new();
+        for _ in 0..r.len()? {
+            let temperature_c = r.f32()?;
+            let mut images = [None, None, None];
+            for image in images.iter_mut() {
+                if r.u8()? == 0 {
+                    continue;
+                }
+                *image = Some(RecordedImage {
+                    format: image_format(r.i32()?)?,
+                    width: r.i32()?,
+                    height: r.i32()?,
+                    stride: r.i32()?,
+                    metadata: ImageMetadata {
+                        device_timestamp_usec: r.u64()?,
+                        system_timestamp_nsec: r.u64()?,
+                        exposure_usec: r.u64()?,
+                        white_balance: r.u32()?,
+                        iso_speed: r.u32()?,
+                    },
+                    data: r.bytes()?,
+                });
+            }
+            captures.push(RecordedCapture {
+                images,
+                temperature_c,
+            });
+        }
+        let mut imu_samples = Vec::new();
+        for _ in 0..r.len()? {
+            imu_samples.push(k4a_imu_sample_t {
+                temperature: r.f32()?,
+                acc_sample: k4a_float3_t {
+                    v: [r.f32()?, r.f32()?, r.f32()?],
+                },
+                acc_timestamp_usec: r.u64()?,
+                gyro_sample: k4a_float3_t {
+                    v: [r.f32()?, r.f32()?, r.f32()?],
+                },
+                gyro_timestamp_usec: r.u64()?,
+            });
+        }
+        let mut blocks = Vec::new();
+        for _ in 0..r.len()? {
+            blocks.push(DataBlock {
+                track: r.str()?,
+                timestamp_usec: r.u64()?,
+                data: r.bytes()?,
+            });
+        }
+        Ok(Recording {
+            config,
+            imu_track,
+            raw_calibration,
+            tags,
+            attachments,
+            tracks,
+            captures,
+            imu_samples,
+            blocks,
+        })
+    }
+}
+
+//--------------------------------------------------//
+//--------------------- RECORD ---------------------//
+//--------------------------------------------------//
+
+struct SimRecord {
+    path: String,
+    header_written: bool,
+    recording: Recording,
+}
+
+fn with_record(
+    recording_handle: k4a_record_t,
+    f: impl FnOnce(&mut SimRecord) -> k4a_result_t,
+) -> k4a_result_t {
+    match unsafe { (recording_handle as *mut SimRecord).as_mut() } {
+        Some(record) => f(record),
+        None => fail("Invalid recording handle"),
+    }
+}
+
+/// Runs `f` on a recording whose header has not been written yet.
+fn before_header(
+    recording_handle: k4a_record_t,
+    f: impl FnOnce(&mut Recording) -> k4a_result_t,
+) -> k4a_result_t {
+    with_record(recording_handle, |record| {
+        if record.header_written {
+            fail("Tracks, tags and attachments must be added before the header is written")
+        } else {
+            f(&mut record.recording)
+        }
+    })
+}
+
+/// Runs `f` on a recording whose header has been written.
+fn after_header(
+    recording_handle: k4a_record_t,
+    f: impl FnOnce(&mut Recording) -> k4a_result_t,
+) -> k4a_result_t {
+    with_record(recording_handle, |record| {
+        if record.header_written {
+            f(&mut record.recording)
+        } else {
+            fail("The header must be written before any data")
+        }
+    })
+}
+
+fn copy_recorded_image(image: k4a_image_t) -> Option<RecordedImage> {
+    let image: &SimImage = unsafe { image_ref(image) }?;
+    Some(RecordedImage {
+        format: image.format,
+        width: image.width,
+        height: image.height,
+        stride: image.stride,
+        metadata: *image.metadata.lock().unwrap(),
+        data: image.data().to_vec(),
+    })
+}
+
+pub(super) extern "C" fn k4a_record_create(
+    path: *const c_char,
+    device: k4a_device_t,
+    device_config: k4a_device_configuration_t,
+    recording_handle: *mut k4a_record_t,
+) -> k4a_result_t {
+    let path = match unsafe { c_str(path) } {
+        Some(path) => path.to_string(),
+        None => return fail("Invalid recording path"),
+    };
+    if let Err(e) = File::create(&path) {
+        return fail(&format!("Failed to create recording {}: {}", path, e));
+    }
+    let raw_calibration = device::serial_number(device)
+        .map(|serial_number| calibration::raw_calibration(&serial_number))
+        .unwrap_or_default();
+    unsafe {
+        *recording_handle = into_handle(SimRecord {
+            path,
+            header_written: false,
+            recording: Recording {
+                config: device_config,
+                imu_track: false,
+                raw_calibration,
+                tags: Vec::new(),
+                attachments: Vec::new(),
+                tracks: Vec::new(),
+                captures: Vec::new(),
+                imu_samples: Vec::new(),
+                blocks: Vec::new(),
+            },
+        })
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_record_add_tag(
+    recording_handle: k4a_record_t,
+    name: *const c_char,
+    value: *const c_char,
+) -> k4a_result_t {
+    before_header(recording_handle, |recording| {
+        match unsafe { (c_str(name), c_str(value)) } {
+            (Some(name), Some(value)) => {
+                recording.tags.push((name.to_string(), value.to_string()));
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            _ => fail("Invalid tag"),
+        }
+    })
+}
+
+pub(super) extern "C" fn k4a_record_add_imu_track(recording_handle: k4a_record_t) -> k4a_result_t {
+    before_header(recording_handle, |recording| {
+        recording.imu_track = true;
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_add_attachment(
+    recording_handle: k4a_record_t,
+    attachment_name: *const c_char,
+    buffer: *const u8,
+    buffer_size: size_t,
+) -> k4a_result_t {
+    before_header(recording_handle, |recording| {
+        match unsafe { c_str(attachment_name) } {
+            Some(name) if !buffer.is_null() => {
+                let data = unsafe { std::slice::from_raw_parts(buffer, buffer_size as usize) };
+                recording
+                    .attachments
+                    .push((name.to_string(), data.to_vec()));
+                k4a_result_t::K4A_RESULT_SUCCEEDED
+            }
+            _ => fail("Invalid attachment"),
+        }
+    })
+}
+
+fn add_custom_track(
+    recording_handle: k4a_record_t,
+    track_name: *const c_char,
+    codec_id: *const c_char,
+    codec_context: *const u8,
+    codec_context_size: size_t,
+    settings: Option<TrackSettings>,
+) -> k4a_result_t {
+    before_header(recording_handle, |recording| {
+        let (name, codec_id, settings) = match (
+            unsafe { c_str(track_name) },
+            unsafe { c_str(codec_id) },
+            settings,
+        ) {
+            (Some(name), Some(codec_id), Some(settings)) => (name, codec_id, settings),
+            _ => return fail("Invalid custom track"),
+        };
+        if BUILTIN_TRACKS.contains(&name) || recording.tracks.iter().any(|t| t.name == name) {
+            return fail(&format!("Track {} already exists", name));
+        }
+        let codec_context = if codec_context.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(codec_context, codec_context_size as usize) }
+                .to_vec()
+        };
+        recording.tracks.push(CustomTrack {
+            name: name.to_string(),
+            codec_id: codec_id.to_string(),
+            codec_context,
+            settings,
+        });
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_add_custom_video_track(
+    recording_handle: k4a_record_t,
+    track_name: *const c_char,
+    codec_id: *const c_char,
+    codec_context: *const u8,
+    codec_context_size: size_t,
+    track_settings: *const k4a_record_video_settings_t,
+) -> k4a_result_t {
+    let settings = unsafe { track_settings.as_ref() }.map(|s| TrackSettings::Video(*s));
+    add_custom_track(
+        recording_handle,
+        track_name,
+        codec_id,
+        codec_context,
+        codec_context_size,
+        settings,
+    )
+}
+
+pub(super) extern "C" fn k4a_record_add_custom_subtitle_track(
+    recording_handle: k4a_record_t,
+    track_name: *const c_char,
+    codec_id: *const c_char,
+    codec_context: *const u8,
+    codec_context_size: size_t,
+    track_settings: *const k4a_record_subtitle_settings_t,
+) -> k4a_result_t {
+    let settings = unsafe { track_settings.as_ref() }.map(|s| TrackSettings::Subtitle(*s));
+    add_custom_track(
+        recording_handle,
+        track_name,
+        codec_id,
+        codec_context,
+        codec_context_size,
+        settings,
+    )
+}
+
+pub(super) extern "C" fn k4a_record_write_header(recording_handle: k4a_record_t) -> k4a_result_t {
+    with_record(recording_handle, |record| {
+        if record.header_written {
+            return fail("The header has already been written");
+        }
+        record.header_written = true;
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_write_capture(
+    recording_handle: k4a_record_t,
+    capture_handle: k4a_capture_t,
+) -> k4a_result_t {
+    after_header(recording_handle, |recording| {
+        let capture = match unsafe { capture_ref(capture_handle) } {
+            Some(capture) => capture,
+            None => return fail("Invalid capture handle"),
+        };
+        let config = &recording.config;
+        let color_enabled =
+            config.color_resolution != k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF;
+        let depth_enabled = !matches!(
+            config.depth_mode,
+            k4a_depth_mode_t::K4A_DEPTH_MODE_OFF | k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR
+        );
+        let ir_enabled = config.depth_mode != k4a_depth_mode_t::K4A_DEPTH_MODE_OFF;
+        let images = [
+            copy_recorded_image(capture.color()).filter(|_| color_enabled),
+            copy_recorded_image(capture.depth()).filter(|_| depth_enabled),
+            copy_recorded_image(capture.ir()).filter(|_| ir_enabled),
+        ];
+        recording.captures.push(RecordedCapture {
+            images,
+            temperature_c: capture.temperature_c(),
+        });
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_write_imu_sample(
+    recording_handle: k4a_record_t,
+    imu_sample: k4a_imu_sample_t,
+) -> k4a_result_t {
+    after_header(recording_handle, |recording| {
+        if !recording.imu_track {
+            return fail("The IMU track was not added to the recording");
+        }
+        recording.imu_samples.push(imu_sample);
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_write_custom_track_data(
+    recording_handle: k4a_record_t,
+    track_name: *const c_char,
+    device_timestamp_usec: u64,
+    custom_data: *mut u8,
+    custom_data_size: size_t,
+) -> k4a_result_t {
+    after_header(recording_handle, |recording| {
+        let name = match unsafe { c_str(track_name) } {
+            Some(name) if recording.tracks.iter().any(|t| t.name == name) => name,
+            _ => return fail("Unknown custom track"),
+        };
+        if custom_data.is_null() {
+            return fail("Invalid custom track data");
+        }
+        let data = unsafe { std::slice::from_raw_parts(custom_data, custom_data_size as usize) };
+        recording.blocks.push(DataBlock {
+            track: name.to_string(),
+            timestamp_usec: device_timestamp_usec,
+            data: data.to_vec(),
+        });
+        k4a_result_t::K4A_RESULT_SUCCEEDED
+    })
+}
+
+pub(super) extern "C" fn k4a_record_flush(recording_handle: k4a_record_t) -> k4a_result_t {
+    with_record(recording_handle, |record| {
+        let result = File::create(&record.path).and_then(|file| {
+            let mut writer = Writer(io::BufWriter::new(file));
+            record.recording.write(&mut writer)?;
+            writer.0.flush()
+        });
+        match result {
+            Ok(()) => k4a_result_t::K4A_RESULT_SUCCEEDED,
+            Err(e) => fail(&format!("Failed to write recording {}: {}", record.path, e)),
+        }
+    })
+}
+
+pub(super) extern "C" fn k4a_record_close(recording_handle: k4a_record_t) {
+    if !recording_handle.is_null() {
+        k4a_record_flush(recording_handle);
+        drop(unsafe { Box::from_raw(recording_handle as *mut SimRecord) });
+    }
+}
+
+//--------------------------------------------------//
+//-------------------- PLAYBACK --------------------//
+//--------------------------------------------------//
+
+struct Cursors {
+    capture: usize,
+    imu_sample: usize,
+    blocks: HashMap<String, usize>,
+}
+
+struct SimPlayback {
+    recording: Recording,
+    start_usec: u64,
+    end_usec: u64,
+    cursors: Mutex<Cursors>,
+}
+
+unsafe fn playback_ref<'a>(playback_handle: k4a_playback_t) -> Option<&'a SimPlayback> {
+    from_handle(playback_handle)
+}
+
+impl SimPlayback {
+    fn record_configuration(&self) -> k4a_record_configuration_t {
+        let c = &self.recording.config;
+        k4a_record_configuration_t {
+            color_format: c.color_format,
+            color_resolution: c.color_resolution,
+            depth_mode: c.depth_mode,
+            camera_fps: c.camera_fps,
+            color_track_enabled: c.color_resolution
+                != k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF,
+            depth_track_enabled: !matches!(
+                c.depth_mode,
+                k4a_depth_mode_t::K4A_DEPTH_MODE_OFF | k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR
+            ),
+            ir_track_enabled: c.depth_mode != k4a_depth_mode_t::K4A_DEPTH_MODE_OFF,
+            imu_track_enabled: self.recording.imu_track,
+            depth_delay_off_color_usec: c.depth_delay_off_color_usec,
+            wired_sync_mode: c.wired_sync_mode,
+            subordinate_delay_off_master_usec: c.subordinate_delay_off_master_usec,
+            start_timestamp_offset_usec: self.start_usec as u32,
+        }
+    }
+
+    fn track_names(&self) -> Vec<&str> {
+        let config = self.record_configuration();
+        let enabled = [
+            config.color_track_enabled,
+            config.depth_track_enabled,
+            config.ir_track_enabled,
+            config.imu_track_enabled,
+        ];
+        BUILTIN_TRACKS
+            .iter()
+            .zip(enabled.iter())
+            .filter(|(_, enabled)| **enabled)
+            .map(|(name, _)| *name)
+            .chain(self.recording.tracks.iter().map(|t| t.name.as_str()))
+            .collect()
+    }
+
+    fn custom_track(&self, name: &str) -> Option<&CustomTrack> {
+        self.recording.tracks.iter().find(|t| t.name == name)
+    }
+
+    fn video_settings(&self, name: &str) -> Option<k4a_record_video_settings_t> {
+        let config = &self.recording.config;
+        let fps = config.camera_fps.get_u32() as u64;
+        let settings = |d: Dimension| k4a_record_video_settings_t {
+            width: d.width as u64,
+            height: d.height as u64,
+            frame_rate: fps,
+        };
+        if !self.track_names().contains(&name) {
+            return None;
+        }
+        match name {
+            "COLOR" => Some(settings(config.color_resolution.get_dimension())),
+            "DEPTH" | "IR" => Some(settings(config.depth_mode.get_dimension())),
+            _ => match self.custom_track(name)?.settings {
+                TrackSettings::Video(settings) => Some(settings),
+                TrackSettings::Subtitle(_) => None,
+            },
+        }
+    }
+
+    fn codec_id(&self, name: &str) -> Option<String> {
+        match name {
+            "COLOR"
+                if self.recording.config.color_format
+                    == k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG =>
+            {
+                Some("V_MJPEG".to_string())
+            }
+            "COLOR" | "DEPTH" | "IR" => Some("V_MS/VFW/FOURCC".to_string()),
+            "IMU" => Some("S_K4A/IMU".to_string()),
+            _ => self.custom_track(name).map(|t| t.codec_id.clone()),
+        }
+        .filter(|_| self.track_names().contains(&name))
+    }
+
+    fn codec_context(&self, name: &str) -> Option<Vec<u8>> {
+        if !self.track_names().contains(&name) {
+            return None;
+        }
+        match self.video_settings(name) {
+            // A BITMAPINFOHEADER, like the builtin tracks of real recordings.
+            Some(settings) if BUILTIN_TRACKS.contains(&name) => {
+                let mut header = vec![0u8; 40];
+                header[0..4].copy_from_slice(&40u32.to_le_bytes());
+                header[4..8].copy_from_slice(&(settings.width as u32).to_le_bytes());
+                header[8..12].copy_from_slice(&(settings.height as u32).to_le_bytes());
+                Some(header)
+            }
+            _ if BUILTIN_TRACKS.contains(&name) => Some(Vec::new()),
+            _ => self.custom_track(name).map(|t| t.codec_context.clone()),
+        }
+    }
+
+    fn capture(&self, index: usize) -> k4a_capture_t {
+        let recorded = &self.recording.captures[index];
+        let image = |image: &Option<RecordedImage>| match image {
+            Some(image) => {
+                let handle = new_image(
+                    image.format,
+                    image.width,
+                    image.height,
+                    image.stride,
+                    image.data.clone(),
+                );
+                *unsafe { image_ref(handle) }
+                    .unwrap()
+                    .metadata
+                    .lock()
+                    .unwrap() = image.metadata;
+                handle
+            }
+            None => ptr::null_mut(),
+        };
+        new_capture(
+            image(&recorded.images[0]),
+            image(&recorded.images[1]),
+            image(&recorded.images[2]),
+            recorded.temperature_c,
+        )
+    }
+
+    fn blocks(&self, track: &str) -> Vec<&DataBlock> {
+        self.recording
+            .blocks
+            .iter()
+            .filter(|b| b.track == track)
+            .collect()
+    }
+
+    fn seek(&self, target_usec: u64) {
+        let recording = &self.recording;
+        let mut cursors = self.cursors.lock().unwrap();
+        cursors.capture = recording
+            .captures
+            .iter()
+            .position(|c| c.timestamp_usec() >= target_usec)
+            .unwrap_or(recording.captures.len());
+        cursors.imu_sample = recording
+            .imu_samples
+            .iter()
+            .position(|s| s.acc_timestamp_usec >= target_usec)
+            .unwrap_or(recording.imu_samples.len());
+        for track in &recording.tracks {
+            let blocks = self.blocks(&track.name);
+            let index = blocks
+                .iter()
+                .position(|b| b.timestamp_usec >= target_usec)
+                .unwrap_or(blocks.len());
+            cursors.blocks.insert(track.name.clone(), index);
+        }
+    }
+}
+
+/// Steps a playback cursor, which sits between two items, forwards or backwards.
+fn step(cursor: &mut usize, len: usize, forward: bool) -> Option<usize> {
+    if forward && *cursor < len {
+        *cursor += 1;
+        Some(*cursor - 1)
+    } else if !forward && *cursor > 0 {
+        *cursor -= 1;
+        Some(*cursor)
+    } else {
+        None
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_open(
+    path: *const c_char,
+    playback_handle: *mut k4a_playback_t,
+) -> k4a_result_t {
+    let path = match unsafe { c_str(path) } {
+        Some(path) => path,
+        None => return fail("Invalid playback path"),
+    };
+    let recording = match File::open(path)
+        .and_then(|file| Recording::read(&mut Reader(io::BufReader::new(file))))
+    {
+        Ok(recording) => recording,
+        Err(e) => return fail(&format!("Failed to open recording {}: {}", path, e)),
+    };
+    let timestamps = recording
+        .captures
+        .iter()
+        .map(|c| c.timestamp_usec())
+        .chain(recording.imu_samples.iter().map(|s| s.acc_timestamp_usec))
+        .chain(recording.blocks.iter().map(|b| b.timestamp_usec));
+    let (start_usec, end_usec) =
+        timestamps.fold((u64::MAX, 0), |(start, end), t| (start.min(t), end.max(t)));
+    unsafe {
+        *playback_handle = into_handle(SimPlayback {
+            recording,
+            start_usec: if start_usec == u64::MAX {
+                0
+            } else {
+                start_usec
+            },
+            end_usec,
+            cursors: Mutex::new(Cursors {
+                capture: 0,
+                imu_sample: 0,
+                blocks: HashMap::new(),
+            }),
+        })
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_playback_close(playback_handle: k4a_playback_t) {
+    if !playback_handle.is_null() {
+        drop(unsafe { Box::from_raw(playback_handle as *mut SimPlayback) });
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_raw_calibration(
+    playback_handle: k4a_playback_t,
+    data: *mut u8,
+    data_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    match unsafe { playback_ref(playback_handle) } {
+        Some(p) if !p.recording.raw_calibration.is_empty() => unsafe {
+            copy_to_buffer(&p.recording.raw_calibration, data, data_size as *mut usize)
+        },
+        _ => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_calibration(
+    playback_handle: k4a_playback_t,
+    calibration: *mut k4a_calibration_t,
+) -> k4a_result_t {
+    let playback = match unsafe { playback_ref(playback_handle) } {
+        Some(p) => p,
+        None => return fail("Invalid playback handle"),
+    };
+    let config = &playback.recording.config;
+    match calibration::calibration_from_raw(
+        &playback.recording.raw_calibration,
+        config.depth_mode,
+        config.color_resolution,
+    ) {
+        Some(c) => {
+            unsafe { *calibration = c };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail("The recording has no calibration"),
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_record_configuration(
+    playback_handle: k4a_playback_t,
+    config: *mut k4a_record_configuration_t,
+) -> k4a_result_t {
+    match unsafe { playback_ref(playback_handle) } {
+        Some(p) => {
+            unsafe { *config = p.record_configuration() };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail("Invalid playback handle"),
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_check_track_exists(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+) -> bool {
+    match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) => p.track_names().contains(&name),
+        _ => false,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_track_count(playback_handle: k4a_playback_t) -> size_t {
+    unsafe { playback_ref(playback_handle) }.map_or(0, |p| p.track_names().len() as size_t)
+}
+
+pub(super) extern "C" fn k4a_playback_get_track_name(
+    playback_handle: k4a_playback_t,
+    track_index: size_t,
+    track_name: *mut c_char,
+    track_name_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    let name = unsafe { playback_ref(playback_handle) }.and_then(|p| {
+        p.track_names()
+            .get(track_index as usize)
+            .map(|n| n.to_string())
+    });
+    match name {
+        Some(name) => unsafe {
+            copy_to_string_buffer(&name, track_name, track_name_size as *mut usize)
+        },
+        None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_track_is_builtin(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+) -> bool {
+    match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) => BUILTIN_TRACKS.contains(&name) && p.track_names().contains(&name),
+        _ => false,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_track_get_video_settings(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    video_settings: *mut k4a_record_video_settings_t,
+) -> k4a_result_t {
+    let settings = match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) => p.video_settings(name),
+        _ => None,
+    };
+    match settings {
+        Some(settings) => {
+            unsafe { *video_settings = settings };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail("Not a video track"),
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_track_get_codec_id(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    codec_id: *mut c_char,
+    codec_id_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) => match p.codec_id(name) {
+            Some(id) => unsafe {
+                copy_to_string_buffer(&id, codec_id, codec_id_size as *mut usize)
+            },
+            None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+        },
+        _ => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_track_get_codec_context(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    codec_context: *mut u8,
+    codec_context_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) => match p.codec_context(name) {
+            Some(context) => unsafe {
+                copy_to_buffer(&context, codec_context, codec_context_size as *mut usize)
+            },
+            None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+        },
+        _ => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_tag(
+    playback_handle: k4a_playback_t,
+    name: *const c_char,
+    value: *mut c_char,
+    value_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    let tag = match unsafe { (playback_ref(playback_handle), c_str(name)) } {
+        (Some(p), Some(name)) => p.recording.tags.iter().find(|(n, _)| n == name),
+        _ => None,
+    };
+    match tag {
+        Some((_, tag)) => unsafe { copy_to_string_buffer(tag, value, value_size as *mut usize) },
+        None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_set_color_conversion(
+    playback_handle: k4a_playback_t,
+    target_format: k4a_image_format_t,
+) -> k4a_result_t {
+    match unsafe { playback_ref(playback_handle) } {
+        Some(p) if p.recording.config.color_format == target_format => {
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        Some(p) => fail(&format!(
+            "Converting {:?} to {:?} is not supported by the simulated playback",
+            p.recording.config.color_format, target_format
+        )),
+        None => fail("Invalid playback handle"),
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_attachment(
+    playback_handle: k4a_playback_t,
+    file_name: *const c_char,
+    data: *mut u8,
+    data_size: *mut size_t,
+) -> k4a_buffer_result_t {
+    let attachment = match unsafe { (playback_ref(playback_handle), c_str(file_name)) } {
+        (Some(p), Some(name)) => p.recording.attachments.iter().find(|(n, _)| n == name),
+        _ => None,
+    };
+    match attachment {
+        Some((_, attachment)) => unsafe {
+            copy_to_buffer(attachment, data, data_size as *mut usize)
+        },
+        None => k4a_buffer_result_t::K4A_BUFFER_RESULT_FAILED,
+    }
+}
+
+fn get_capture(
+    playback_handle: k4a_playback_t,
+    capture_handle: *mut k4a_capture_t,
+    forward: bool,
+) -> k4a_stream_result_t {
+    let playback = match unsafe { playback_ref(playback_handle) } {
+        Some(p) => p,
+        None => return k4a_stream_result_t::K4A_STREAM_RESULT_FAILED,
+    };
+    let index = step(
+        &mut playback.cursors.lock().unwrap().capture,
+        playback.recording.captures.len(),
+        forward,
+    );
+    match index {
+        Some(index) => {
+            unsafe { *capture_handle = playback.capture(index) };
+            k4a_stream_result_t::K4A_STREAM_RESULT_SUCCEEDED
+        }
+        None => k4a_stream_result_t::K4A_STREAM_RESULT_EOF,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_next_capture(
+    playback_handle: k4a_playback_t,
+    capture_handle: *mut k4a_capture_t,
+) -> k4a_stream_result_t {
+    get_capture(playback_handle, capture_handle, true)
+}
+
+pub(super) extern "C" fn k4a_playback_get_previous_capture(
+    playback_handle: k4a_playback_t,
+    capture_handle: *mut k4a_capture_t,
+) -> k4a_stream_result_t {
+    get_capture(playback_handle, capture_handle, false)
+}
+
+fn get_imu_sample(
+    playback_handle: k4a_playback_t,
+    imu_sample: *mut k4a_imu_sample_t,
+    forward: bool,
+) -> k4a_stream_result_t {
+    let playback = match unsafe { playback_ref(playback_handle) } {
+        Some(p) => p,
+        None => return k4a_stream_result_t::K4A_STREAM_RESULT_FAILED,
+    };
+    let samples = &playback.recording.imu_samples;
+    match step(
+        &mut playback.cursors.lock().unwrap().imu_sample,
+        samples.len(),
+        forward,
+    ) {
+        Some(index) => {
+            unsafe { *imu_sample = samples[index] };
+            k4a_stream_result_t::K4A_STREAM_RESULT_SUCCEEDED
+        }
+        None => k4a_stream_result_t::K4A_STREAM_RESULT_EOF,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_next_imu_sample(
+    playback_handle: k4a_playback_t,
+    imu_sample: *mut k4a_imu_sample_t,
+) -> k4a_stream_result_t {
+    get_imu_sample(playback_handle, imu_sample, true)
+}
+
+pub(super) extern "C" fn k4a_playback_get_previous_imu_sample(
+    playback_handle: k4a_playback_t,
+    imu_sample: *mut k4a_imu_sample_t,
+) -> k4a_stream_result_t {
+    get_imu_sample(playback_handle, imu_sample, false)
+}
+
+struct SimDataBlock {
+    timestamp_usec: u64,
+    data: Vec<u8>,
+}
+
+fn get_data_block(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    data_block_handle: *mut k4a_playback_data_block_t,
+    forward: bool,
+) -> k4a_stream_result_t {
+    let (playback, name) = match unsafe { (playback_ref(playback_handle), c_str(track_name)) } {
+        (Some(p), Some(name)) if p.custom_track(name).is_some() => (p, name),
+        _ => {
+            fail("Data blocks can only be read from custom tracks");
+            return k4a_stream_result_t::K4A_STREAM_RESULT_FAILED;
+        }
+    };
+    let blocks = playback.blocks(name);
+    let mut cursors = playback.cursors.lock().unwrap();
+    let cursor = cursors.blocks.entry(name.to_string()).or_insert(0);
+    match step(cursor, blocks.len(), forward) {
+        Some(index) => {
+            unsafe {
+                *data_block_handle = into_handle(SimDataBlock {
+                    timestamp_usec: blocks[index].timestamp_usec,
+                    data: blocks[index].data.clone(),
+                })
+            };
+            k4a_stream_result_t::K4A_STREAM_RESULT_SUCCEEDED
+        }
+        None => k4a_stream_result_t::K4A_STREAM_RESULT_EOF,
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_get_next_data_block(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    data_block_handle: *mut k4a_playback_data_block_t,
+) -> k4a_stream_result_t {
+    get_data_block(playback_handle, track_name, data_block_handle, true)
+}
+
+pub(super) extern "C" fn k4a_playback_get_previous_data_block(
+    playback_handle: k4a_playback_t,
+    track_name: *const c_char,
+    data_block_handle: *mut k4a_playback_data_block_t,
+) -> k4a_stream_result_t {
+    get_data_block(playback_handle, track_name, data_block_handle, false)
+}
+
+pub(super) extern "C" fn k4a_playback_data_block_get_device_timestamp_usec(
+    data_block_handle: k4a_playback_data_block_t,
+) -> u64 {
+    unsafe { from_handle::<SimDataBlock, _>(data_block_handle) }.map_or(0, |b| b.timestamp_usec)
+}
+
+pub(super) extern "C" fn k4a_playback_data_block_get_buffer_size(
+    data_block_handle: k4a_playback_data_block_t,
+) -> size_t {
+    unsafe { from_handle::<SimDataBlock, _>(data_block_handle) }
+        .map_or(0, |b| b.data.len() as size_t)
+}
+
+pub(super) extern "C" fn k4a_playback_data_block_get_buffer(
+    data_block_handle: k4a_playback_data_block_t,
+) -> *mut u8 {
+    unsafe { from_handle::<SimDataBlock, _>(data_block_handle) }
+        .map_or(ptr::null_mut(), |b| b.data.as_ptr() as *mut u8)
+}
+
+pub(super) extern "C" fn k4a_playback_data_block_release(
+    data_block_handle: k4a_playback_data_block_t,
+) {
+    if !data_block_handle.is_null() {
+        drop(unsafe { Box::from_raw(data_block_handle as *mut SimDataBlock) });
+    }
+}
+
+pub(super) extern "C" fn k4a_playback_seek_timestamp(
+    playback_handle: k4a_playback_t,
+    offset_usec: i64,
+    origin: k4a_playback_seek_origin_t,
+) -> k4a_result_t {
+    let playback = match unsafe { playback_ref(playback_handle) } {
+        Some(p) => p,
+        None => return fail("Invalid playback handle"),
+    };
+    let base = match origin {
+        k4a_playback_seek_origin_t::K4A_PLAYBACK_SEEK_BEGIN => playback.start_usec as i64,
+        k4a_playback_seek_origin_t::K4A_PLAYBACK_SEEK_END => playback.end_usec as i64 + 1,
+        k4a_playback_seek_origin_t::K4A_PLAYBACK_SEEK_DEVICE_TIME => 0,
+    };
+    playback.seek((base + offset_usec).max(0) as u64);
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4a_playback_get_recording_length_usec(
+    playback_handle: k4a_playback_t,
+) -> u64 {
+    unsafe { playback_ref(playback_handle) }.map_or(0, |p| p.end_usec.saturating_sub(p.start_usec))
+}
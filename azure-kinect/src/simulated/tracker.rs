@@ -0,0 +1,359 @@
+use super::image::{capture_ref, image_ref, new_image};
+use super::*;
+use std::collections::VecDeque;
+use std::sync::Condvar;
+use std::time::{Duration, Instant};
+
+/// Number of captures the tracker queues before `k4abt_tracker_enqueue_capture` blocks.
+const QUEUE_SIZE: usize = 3;
+
+/// Joint positions of a person standing upright, relative to the pelvis, in millimeters.
+/// The camera's y axis points down and the person faces the camera.
+const SKELETON: [[f32; 3]; 32] = [
+    [0.0, 0.0, 0.0],
+    [0.0, -200.0, 0.0],
+    [0.0, -380.0, 0.0],
+    [0.0, -560.0, 0.0],
+    [40.0, -520.0, 0.0],
+    [180.0, -500.0, 0.0],
+    [200.0, -230.0, 0.0],
+    [210.0, 0.0, 0.0],
+    [210.0, 70.0, 0.0],
+    [210.0, 150.0, 0.0],
+    [180.0, 80.0, -30.0],
+    [-40.0, -520.0, 0.0],
+    [-180.0, -500.0, 0.0],
+    [-200.0, -230.0, 0.0],
+    [-210.0, 0.0, 0.0],
+    [-210.0, 70.0, 0.0],
+    [-210.0, 150.0, 0.0],
+    [-180.0, 80.0, -30.0],
+    [90.0, 0.0, 0.0],
+    [100.0, 420.0, 0.0],
+    [100.0, 820.0, 0.0],
+    [100.0, 860.0, -120.0],
+    [-90.0, 0.0, 0.0],
+    [-100.0, 420.0, 0.0],
+    [-100.0, 820.0, 0.0],
+    [-100.0, 860.0, -120.0],
+    [0.0, -680.0, 0.0],
+    [0.0, -660.0, -90.0],
+    [30.0, -700.0, -70.0],
+    [70.0, -690.0, 0.0],
+    [-30.0, -700.0, -70.0],
+    [-70.0, -690.0, 0.0],
+];
+
+/// The id of the one person the simulated tracker reports.
+const BODY_ID: u32 = 1;
+
+struct SimFrame {
+    refs: AtomicUsize,
+    capture: usize,
+    timestamp_usec: u64,
+    skeleton: Option<k4abt_skeleton_t>,
+    body_index_map: usize,
+}
+
+impl RefCounted for SimFrame {
+    fn refs(&self) -> &AtomicUsize {
+        &self.refs
+    }
+}
+
+impl Drop for SimFrame {
+    fn drop(&mut self) {
+        image::k4a_image_release(self.body_index_map as k4a_image_t);
+        image::k4a_capture_release(self.capture as k4a_capture_t);
+    }
+}
+
+struct Queue {
+    frames: VecDeque<usize>,
+    shutdown: bool,
+}
+
+struct SimTracker {
+    calibration: k4a_calibration_t,
+    queue: Mutex<Queue>,
+    changed: Condvar,
+}
+
+unsafe fn tracker_ref<'a>(tracker_handle: k4abt_tracker_t) -> Option<&'a SimTracker> {
+    from_handle(tracker_handle)
+}
+
+unsafe fn frame_ref<'a>(body_frame_handle: k4abt_frame_t) -> Option<&'a SimFrame> {
+    from_handle(body_frame_handle)
+}
+
+fn skeleton(timestamp_usec: u64) -> k4abt_skeleton_t {
+    let t = timestamp_usec as f32 / 1_000_000.0;
+    let pelvis = [
+        400.0 * (2.0 * std::f32::consts::PI * t / 8.0).sin(),
+        100.0,
+        2500.0,
+    ];
+    let joint = |offset: &[f32; 3]| k4abt_joint_t {
+        position: k4a_float3_t {
+            v: [
+                pelvis[0] + offset[0],
+                pelvis[1] + offset[1],
+                pelvis[2] + offset[2],
+            ],
+        },
+        orientation: k4a_quaternion_t {
+            v: [1.0, 0.0, 0.0, 0.0],
+        },
+        confidence_level: k4abt_joint_confidence_level_t::K4ABT_JOINT_CONFIDENCE_MEDIUM,
+    };
+    let mut joints = [joint(&SKELETON[0]); 32];
+    for (joint_out, offset) in joints.iter_mut().zip(SKELETON.iter()) {
+        *joint_out = joint(offset);
+    }
+    k4abt_skeleton_t { joints }
+}
+
+/// Paints a disc around every joint that is visible to the depth camera.
+fn body_index_map(
+    calibration: &k4a_calibration_t,
+    skeleton: Option<&k4abt_skeleton_t>,
+) -> k4a_image_t {
+    let camera = &calibration.depth_camera_calibration;
+    let (width, height) = (camera.resolution_width, camera.resolution_height);
+    let mut data = vec![K4ABT_BODY_INDEX_MAP_BACKGROUND as u8; (width * height) as usize];
+    let p = unsafe { camera.intrinsics.parameters.param };
+    for joint in skeleton.iter().flat_map(|s| s.joints.iter()) {
+        let [x, y, z] = unsafe { joint.position.v };
+        if z <= 0.0 {
+            continue;
+        }
+        let (u, v) = ((p.fx * x / z + p.cx) as i32, (p.fy * y / z + p.cy) as i32);
+        let radius = (p.fx * 60.0 / z) as i32;
+        for py in (v - radius).max(0)..(v + radius).min(height) {
+            for px in (u - radius).max(0)..(u + radius).min(width) {
+                if (px - u).pow(2) + (py - v).pow(2) <= radius * radius {
+                    data[(py * width + px) as usize] = 0;
+                }
+            }
+        }
+    }
+    new_image(
+        k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM8,
+        width,
+        height,
+        width,
+        data,
+    )
+}
+
+pub(super) extern "C" fn k4abt_tracker_create(
+    sensor_calibration: *const k4a_calibration_t,
+    _config: k4abt_tracker_configuration_t,
+    tracker_handle: *mut k4abt_tracker_t,
+) -> k4a_result_t {
+    let calibration = match unsafe { sensor_calibration.as_ref() } {
+        Some(calibration) => *calibration,
+        None => return fail("Invalid sensor calibration"),
+    };
+    if matches!(
+        calibration.depth_mode,
+        k4a_depth_mode_t::K4A_DEPTH_MODE_OFF | k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR
+    ) {
+        return fail("Body tracking requires a depth mode that produces depth images");
+    }
+    unsafe {
+        *tracker_handle = into_handle(SimTracker {
+            calibration,
+            queue: Mutex::new(Queue {
+                frames: VecDeque::new(),
+                shutdown: false,
+            }),
+            changed: Condvar::new(),
+        })
+    };
+    k4a_result_t::K4A_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4abt_tracker_destroy(tracker_handle: k4abt_tracker_t) {
+    if !tracker_handle.is_null() {
+        let tracker = unsafe { Box::from_raw(tracker_handle as *mut SimTracker) };
+        for frame in tracker.queue.lock().unwrap().frames.drain(..) {
+            k4abt_frame_release(frame as k4abt_frame_t);
+        }
+    }
+}
+
+pub(super) extern "C" fn k4abt_tracker_set_temporal_smoothing(
+    _tracker_handle: k4abt_tracker_t,
+    _smoothing_factor: f32,
+) {
+}
+
+/// Waits on the tracker's queue until `ready` holds, the tracker shuts down or the timeout
+/// expires.
+fn wait<'a>(
+    tracker: &'a SimTracker,
+    timeout_in_ms: i32,
+    ready: impl Fn(&Queue) -> bool,
+) -> Result<std::sync::MutexGuard<'a, Queue>, k4a_wait_result_t> {
+    let deadline = if timeout_in_ms < 0 {
+        None
+    } else {
+        Some(Instant::now() + Duration::from_millis(timeout_in_ms as u64))
+    };
+    let mut queue = tracker.queue.lock().unwrap();
+    loop {
+        if ready(&queue) {
+            return Ok(queue);
+        }
+        if queue.shutdown {
+            return Err(k4a_wait_result_t::K4A_WAIT_RESULT_FAILED);
+        }
+        queue = match deadline {
+            None => tracker.changed.wait(queue).unwrap(),
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(k4a_wait_result_t::K4A_WAIT_RESULT_TIMEOUT);
+                }
+                tracker
+                    .changed
+                    .wait_timeout(queue, deadline - now)
+                    .unwrap()
+                    .0
+            }
+        };
+    }
+}
+
+pub(super) extern "C" fn k4abt_tracker_enqueue_capture(
+    tracker_handle: k4abt_tracker_t,
+    sensor_capture_handle: k4a_capture_t,
+    timeout_in_ms: i32,
+) -> k4a_wait_result_t {
+    let tracker = match unsafe { tracker_ref(tracker_handle) } {
+        Some(tracker) => tracker,
+        None => return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED,
+    };
+    let depth = match unsafe { capture_ref(sensor_capture_handle) }
+        .and_then(|c| unsafe { image_ref(c.depth()) })
+    {
+        Some(depth) => depth,
+        None => {
+            fail("The capture has no depth image");
+            return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED;
+        }
+    };
+    let timestamp_usec = depth.metadata.lock().unwrap().device_timestamp_usec;
+    let mut queue = match wait(tracker, timeout_in_ms, |q| {
+        !q.shutdown && q.frames.len() < QUEUE_SIZE
+    }) {
+        Ok(queue) => queue,
+        Err(result) => return result,
+    };
+    let skeleton = skeleton(timestamp_usec);
+    image::k4a_capture_reference(sensor_capture_handle);
+    let frame: k4abt_frame_t = into_handle(SimFrame {
+        refs: AtomicUsize::new(1),
+        capture: sensor_capture_handle as usize,
+        timestamp_usec,
+        skeleton: Some(skeleton),
+        body_index_map: body_index_map(&tracker.calibration, Some(&skeleton)) as usize,
+    });
+    queue.frames.push_back(frame as usize);
+    tracker.changed.notify_all();
+    k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4abt_tracker_pop_result(
+    tracker_handle: k4abt_tracker_t,
+    body_frame_handle: *mut k4abt_frame_t,
+    timeout_in_ms: i32,
+) -> k4a_wait_result_t {
+    let tracker = match unsafe { tracker_ref(tracker_handle) } {
+        Some(tracker) => tracker,
+        None => return k4a_wait_result_t::K4A_WAIT_RESULT_FAILED,
+    };
+    let mut queue = match wait(tracker, timeout_in_ms, |q| !q.frames.is_empty()) {
+        Ok(queue) => queue,
+        Err(result) => return result,
+    };
+    let frame = queue.frames.pop_front().unwrap();
+    tracker.changed.notify_all();
+    unsafe { *body_frame_handle = frame as k4abt_frame_t };
+    k4a_wait_result_t::K4A_WAIT_RESULT_SUCCEEDED
+}
+
+pub(super) extern "C" fn k4abt_tracker_shutdown(tracker_handle: k4abt_tracker_t) {
+    if let Some(tracker) = unsafe { tracker_ref(tracker_handle) } {
+        tracker.queue.lock().unwrap().shutdown = true;
+        tracker.changed.notify_all();
+    }
+}
+
+pub(super) extern "C" fn k4abt_frame_release(body_frame_handle: k4abt_frame_t) {
+    unsafe { release::<SimFrame, _>(body_frame_handle) }
+}
+
+pub(super) extern "C" fn k4abt_frame_reference(body_frame_handle: k4abt_frame_t) {
+    unsafe { add_ref::<SimFrame, _>(body_frame_handle) }
+}
+
+pub(super) extern "C" fn k4abt_frame_get_num_bodies(body_frame_handle: k4abt_frame_t) -> u32 {
+    unsafe { frame_ref(body_frame_handle) }.map_or(0, |f| f.skeleton.is_some() as u32)
+}
+
+pub(super) extern "C" fn k4abt_frame_get_body_skeleton(
+    body_frame_handle: k4abt_frame_t,
+    index: u32,
+    skeleton: *mut k4abt_skeleton_t,
+) -> k4a_result_t {
+    match unsafe { frame_ref(body_frame_handle) }.and_then(|f| f.skeleton.filter(|_| index == 0)) {
+        Some(s) => {
+            unsafe { *skeleton = s };
+            k4a_result_t::K4A_RESULT_SUCCEEDED
+        }
+        None => fail(&format!("No body at index {}", index)),
+    }
+}
+
+pub(super) extern "C" fn k4abt_frame_get_body_id(
+    body_frame_handle: k4abt_frame_t,
+    index: u32,
+) -> u32 {
+    match unsafe { frame_ref(body_frame_handle) } {
+        Some(f) if f.skeleton.is_some() && index == 0 => BODY_ID,
+        _ => K4ABT_INVALID_BODY_ID,
+    }
+}
+
+pub(super) extern "C" fn k4abt_frame_get_device_timestamp_usec(
+    body_frame_handle: k4abt_frame_t,
+) -> u64 {
+    unsafe { frame_ref(body_frame_handle) }.map_or(0, |f| f.timestamp_usec)
+}
+
+pub(super) extern "C" fn k4abt_frame_get_body_index_map(
+    body_frame_handle: k4abt_frame_t,
+) -> k4a_image_t {
+    match unsafe { frame_ref(body_frame_handle) } {
+        Some(f) => {
+            image::k4a_image_reference(f.body_index_map as k4a_image_t);
+            f.body_index_map as k4a_image_t
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+pub(super) extern "C" fn k4abt_frame_get_capture(
+    body_frame_handle: k4abt_frame_t,
+) -> k4a_capture_t {
+    match unsafe { frame_ref(body_frame_handle) } {
+        Some(f) => {
+            image::k4a_capture_reference(f.capture as k4a_capture_t);
+            f.capture as k4a_capture_t
+        }
+        None => ptr::null_mut(),
+    }
+}
@@ -0,0 +1,468 @@
+use super::imu::ImuHistory;
+use super::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{sync_channel, SyncSender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::Waker;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a reader thread waits on the SDK before it checks whether it should stop.
+const POLL_TIMEOUT_MS: i32 = 100;
+/// IMU samples queued by a [`CaptureStream`], about one second at 1.6 kHz. The oldest are
+/// dropped when it is full.
+const IMU_QUEUE_CAPACITY: usize = 2048;
+/// IMU samples kept by a [`CaptureStream`] for timestamp lookups, about two seconds at 1.6 kHz.
+const IMU_HISTORY_CAPACITY: usize = 3200;
+
+/// What a [`CaptureStream`] does with a new capture when its queue is full.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Drop the oldest queued capture, so the reader always gets the latest ones.
+    DropOldest,
+    /// Drop the new capture.
+    DropNewest,
+    /// Wait for the reader. The SDK drops captures itself once its own queue is full.
+    Block,
+}
+
+/// Reads the captures of a device on a dedicated thread and queues them for the application, so
+/// a slow reader like a render loop neither stalls the device nor misses the latest capture.
+///
+/// The cameras run from [`CaptureStream::start`] until the stream is dropped or stopped.
+pub struct CaptureStream {
+    device: Arc<Device>,
+    reader: Reader<Capture>,
+    imu: Option<Arc<Shared<k4a_imu_sample_t>>>,
+    imu_history: Option<Arc<ImuHistory>>,
+    health: Arc<Mutex<StreamHealth>>,
+}
+
+impl CaptureStream {
+    /// Starts the cameras of `device` and reads their captures into a queue of `capacity`
+    /// captures, at least one.
+    pub fn start(
+        device: Device,
+        configuration: &k4a_device_configuration_t,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> Result<CaptureStream, Error> {
+        Self::start_reader(device, configuration, capacity, policy, false)
+    }
+
+    /// Like [`CaptureStream::start`], and also starts the IMU and reads its samples on a second
+    /// thread, into a queue of their own and into a buffer of the last two seconds that is
+    /// searched by timestamp.
+    pub fn start_with_imu(
+        device: Device,
+        configuration: &k4a_device_configuration_t,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> Result<CaptureStream, Error> {
+        Self::start_reader(device, configuration, capacity, policy, true)
+    }
+
+    fn start_reader(
+        device: Device,
+        configuration: &k4a_device_configuration_t,
+        capacity: usize,
+        policy: OverflowPolicy,
+        with_imu: bool,
+    ) -> Result<CaptureStream, Error> {
+        let device = Arc::new(device);
+        let health = Arc::new(Mutex::new(StreamHealth::new(configuration.camera_fps)));
+        let (imu, imu_history) = if with_imu {
+            (
+                Some(Arc::new(Shared::new(
+                    IMU_QUEUE_CAPACITY,
+                    OverflowPolicy::DropOldest,
+                ))),
+                Some(Arc::new(ImuHistory::new(IMU_HISTORY_CAPACITY))),
+            )
+        } else {
+            (None, None)
+        };
+        let reader = {
+            let device = device.clone();
+            let imu = imu.clone().zip(imu_history.clone());
+            let health = health.clone();
+            let configuration = *configuration;
+            Reader::spawn("k4a-capture", capacity, policy, move |starter| {
+                let mut cameras = match device.start_cameras_shared(&configuration) {
+                    Ok(cameras) => cameras,
+                    Err(e) => return starter.fail(e),
+                };
+                // Every capture is measured, including those the overflow policy drops.
+                let measured = |capture: Result<Capture, Error>| {
+                    if let Ok(capture) = &capture {
+                        health.lock().unwrap().add_capture(capture);
+                    }
+                    capture
+                };
+                match imu {
+                    None => {
+                        starter.run(|| measured(cameras.get_capture(POLL_TIMEOUT_MS)));
+                    }
+                    Some((imu, imu_history)) => {
+                        let imu_session = match cameras.start_imu() {
+                            Ok(imu_session) => imu_session,
+                            Err(e) => return starter.fail(e),
+                        };
+                        let done = AtomicBool::new(false);
+                        thread::scope(|scope| {
+                            // Samples arrive every 625 µs, so they are read on a thread of their
+                            // own, which neither waits for captures nor for a full capture queue.
+                            let imu_thread = thread::Builder::new()
+                                .name("k4a-imu".into())
+                                .spawn_scoped(scope, || {
+                                    let error = loop {
+                                        if done.load(Ordering::SeqCst) {
+                                            break Error::new(ErrorKind::Failed);
+                                        }
+                                        match imu_session.get_imu_sample(POLL_TIMEOUT_MS) {
+                                            Ok(sample) => {
+                                                health.lock().unwrap().add_imu_sample(&sample);
+                                                imu_history.push(sample);
+                                                imu.push(sample);
+                                            }
+                                            Err(e) if e.is_timeout() => imu.tick(),
+                                            Err(e) => break e,
+                                        }
+                                    };
+                                    imu_history.finish(error.clone());
+                                    imu.finish(error);
+                                });
+                            if imu_thread.is_err() {
+                                return starter.fail(Error::new(ErrorKind::Failed));
+                            }
+                            starter.run(|| measured(imu_session.get_capture(POLL_TIMEOUT_MS)));
+                            done.store(true, Ordering::SeqCst);
+                        });
+                    }
+                }
+            })?
+        };
+        Ok(CaptureStream {
+            device,
+            reader,
+            imu,
+            imu_history,
+            health,
+        })
+    }
+
+    /// The streaming device, for its color controls and calibration.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Takes the oldest queued capture. Fails with [`ErrorKind::Timeout`] if none arrived in time,
+    /// or with the error of the device once the reader thread has stopped on it.
+    pub fn get_capture(&self, timeout_in_ms: i32) -> Result<Capture, Error> {
+        self.reader.shared.pop(timeout_in_ms)
+    }
+
+    /// Takes the oldest queued capture, waiting for as long as it takes.
+    pub fn get_capture_wait_infinite(&self) -> Result<Capture, Error> {
+        self.get_capture(K4A_WAIT_INFINITE)
+    }
+
+    /// Takes the oldest queued IMU sample of a stream started with
+    /// [`CaptureStream::start_with_imu`]. Fails with [`ErrorKind::Timeout`] if none arrived in
+    /// time.
+    pub fn get_imu_sample(&self, timeout_in_ms: i32) -> Result<k4a_imu_sample_t, Error> {
+        match &self.imu {
+            Some(imu) => imu.pop(timeout_in_ms),
+            None => Err(Error::new(ErrorKind::Failed)),
+        }
+    }
+
+    /// Takes the oldest queued IMU sample, waiting for as long as it takes.
+    pub fn get_imu_sample_wait_infinite(&self) -> Result<k4a_imu_sample_t, Error> {
+        self.get_imu_sample(K4A_WAIT_INFINITE)
+    }
+
+    /// The buffered IMU samples of a stream started with [`CaptureStream::start_with_imu`] whose
+    /// accelerometer timestamp is from `start_usec` up to, but excluding, `end_usec`. Only the
+    /// last two seconds are buffered. Waits for a sample at or after `end_usec` to arrive, so
+    /// that the range is complete, and fails with [`ErrorKind::Timeout`] if it did not in time.
+    pub fn imu_samples_between(
+        &self,
+        start_usec: u64,
+        end_usec: u64,
+        timeout_in_ms: i32,
+    ) -> Result<Vec<k4a_imu_sample_t>, Error> {
+        match &self.imu_history {
+            Some(history) => history.between(start_usec, end_usec, timeout_in_ms),
+            None => Err(Error::new(ErrorKind::Failed)),
+        }
+    }
+
+    /// Accelerometer and gyroscope readings at a device timestamp, such as the
+    /// `get_device_timestamp_usec` of a depth image, interpolated between the IMU samples around
+    /// it. Waits for the samples after the timestamp to arrive, and fails with
+    /// [`ErrorKind::Timeout`] if they did not in time, or [`ErrorKind::Failed`] if the timestamp
+    /// is older than the buffered samples.
+    pub fn imu_at(&self, timestamp_usec: u64, timeout_in_ms: i32) -> Result<ImuReading, Error> {
+        match &self.imu_history {
+            Some(history) => history.interpolate(timestamp_usec, timeout_in_ms),
+            None => Err(Error::new(ErrorKind::Failed)),
+        }
+    }
+
+    /// Timing of the captures and IMU samples read since the stream started.
+    pub fn health(&self) -> HealthReport {
+        self.health.lock().unwrap().report()
+    }
+
+    /// Number of captures dropped by the overflow policy so far.
+    pub fn dropped(&self) -> u64 {
+        self.reader.shared.lock().dropped
+    }
+
+    /// The error that stopped the reader thread, once it has stopped.
+    pub(crate) fn finished(&self) -> Option<Error> {
+        self.reader.shared.lock().finished.clone()
+    }
+
+    /// Stops the cameras and returns the device.
+    pub fn stop(mut self) -> Device {
+        self.reader.shutdown();
+        let device = self.device.clone();
+        drop(self);
+        match Arc::try_unwrap(device) {
+            Ok(device) => device,
+            Err(_) => unreachable!("the reader thread has exited"),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn capture_queue(&self) -> &Shared<Capture> {
+        &self.reader.shared
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn imu_queue(&self) -> Option<&Shared<k4a_imu_sample_t>> {
+        self.imu.as_deref()
+    }
+}
+
+/// A thread that reads items from the SDK into a [`Shared`] queue until it is dropped.
+pub(crate) struct Reader<T> {
+    pub(crate) shared: Arc<Shared<T>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+/// Handed to the body of a reader thread, to report whether it could start.
+pub(crate) struct Starter<T> {
+    shared: Arc<Shared<T>>,
+    started: SyncSender<Result<(), Error>>,
+}
+
+impl<T: Send + 'static> Reader<T> {
+    /// Spawns a thread running `body`, and waits until it has called [`Starter::run`] or
+    /// [`Starter::fail`].
+    pub(crate) fn spawn(
+        name: &str,
+        capacity: usize,
+        policy: OverflowPolicy,
+        body: impl FnOnce(Starter<T>) + Send + 'static,
+    ) -> Result<Reader<T>, Error> {
+        let shared = Arc::new(Shared::new(capacity, policy));
+        let (started_sender, started) = sync_channel(1);
+        let starter = Starter {
+            shared: shared.clone(),
+            started: started_sender,
+        };
+        let thread = thread::Builder::new()
+            .name(name.into())
+            .spawn(move || body(starter))
+            .map_err(|_| Error::new(ErrorKind::Failed))?;
+        let mut reader = Reader {
+            shared,
+            thread: Some(thread),
+        };
+        match started.recv() {
+            Ok(Ok(())) => Ok(reader),
+            Ok(Err(e)) => Err(e),
+            Err(_) => {
+                reader.shutdown();
+                Err(Error::new(ErrorKind::Failed))
+            }
+        }
+    }
+}
+
+impl<T> Reader<T> {
+    fn shutdown(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            self.shared.stop.store(true, Ordering::SeqCst);
+            // Wake a reader thread that is blocked on a full queue.
+            drop(self.shared.lock());
+            self.shared.space.notify_all();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<T> Drop for Reader<T> {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl<T> Starter<T> {
+    pub(crate) fn fail(self, error: Error) {
+        let _ = self.started.send(Err(error));
+    }
+
+    /// Reports the start, then queues the items returned by `read`, which waits for at most
+    /// [`POLL_TIMEOUT_MS`], until the reader is dropped or `read` fails with anything but a
+    /// timeout. Returns the error the queue finished with.
+    pub(crate) fn run(self, mut read: impl FnMut() -> Result<T, Error>) -> Error {
+        let _ = self.started.send(Ok(()));
+        let error = loop {
+            if self.shared.stop.load(Ordering::SeqCst) {
+                break Error::new(ErrorKind::Failed);
+            }
+            match read() {
+                Ok(item) => self.shared.push(item),
+                Err(e) if e.is_timeout() => self.shared.tick(),
+                Err(e) => break e,
+            }
+        };
+        self.shared.finish(error.clone());
+        error
+    }
+}
+
+/// A bounded queue filled by a reader thread.
+pub(crate) struct Shared<T> {
+    queue: Mutex<Queue<T>>,
+    /// Signalled when an item is queued or the reader thread has finished.
+    available: Condvar,
+    /// Signalled when an item is taken or the reader is stopping.
+    space: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+    stop: AtomicBool,
+}
+
+pub(crate) struct Queue<T> {
+    pub(crate) items: VecDeque<T>,
+    pub(crate) dropped: u64,
+    /// Set when the reader thread has finished, with the error that ended it.
+    pub(crate) finished: Option<Error>,
+    /// Woken with `available`, for an asynchronous reader.
+    pub(crate) waker: Option<Waker>,
+}
+
+impl<T> Shared<T> {
+    fn new(capacity: usize, policy: OverflowPolicy) -> Shared<T> {
+        Shared {
+            queue: Mutex::new(Queue {
+                items: VecDeque::with_capacity(capacity.max(1)),
+                dropped: 0,
+                finished: None,
+                waker: None,
+            }),
+            available: Condvar::new(),
+            space: Condvar::new(),
+            capacity: capacity.max(1),
+            policy,
+            stop: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, Queue<T>> {
+        self.queue.lock().unwrap()
+    }
+
+    /// Takes the oldest item, for the caller that has locked the queue.
+    pub(crate) fn take(&self, queue: &mut Queue<T>) -> Option<T> {
+        let item = queue.items.pop_front();
+        if item.is_some() {
+            self.space.notify_one();
+        }
+        item
+    }
+
+    fn pop(&self, timeout_in_ms: i32) -> Result<T, Error> {
+        let deadline = match timeout_in_ms {
+            K4A_WAIT_INFINITE => None,
+            timeout => Some(Instant::now() + Duration::from_millis(timeout.max(0) as u64)),
+        };
+        let mut queue = self.lock();
+        loop {
+            if let Some(item) = self.take(&mut queue) {
+                return Ok(item);
+            }
+            if let Some(error) = &queue.finished {
+                return Err(error.clone());
+            }
+            queue = match deadline {
+                None => self.available.wait(queue).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::new(ErrorKind::Timeout));
+                    }
+                    self.available
+                        .wait_timeout(queue, deadline - now)
+                        .unwrap()
+                        .0
+                }
+            };
+        }
+    }
+
+    fn push(&self, item: T) {
+        let mut queue = self.lock();
+        while queue.items.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::DropOldest => {
+                    queue.items.pop_front();
+                    queue.dropped += 1;
+                }
+                OverflowPolicy::DropNewest => {
+                    queue.dropped += 1;
+                    return;
+                }
+                OverflowPolicy::Block => {
+                    if self.stop.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    queue = self.space.wait(queue).unwrap();
+                }
+            }
+        }
+        queue.items.push_back(item);
+        self.wake(queue);
+    }
+
+    /// Wakes an asynchronous reader after a wait on the SDK timed out, so it can check its own
+    /// timeout.
+    fn tick(&self) {
+        let mut queue = self.lock();
+        if let Some(waker) = queue.waker.take() {
+            drop(queue);
+            waker.wake();
+        }
+    }
+
+    fn finish(&self, error: Error) {
+        let mut queue = self.lock();
+        queue.finished = Some(error);
+        self.wake(queue);
+    }
+
+    fn wake(&self, mut queue: MutexGuard<'_, Queue<T>>) {
+        let waker = queue.waker.take();
+        drop(queue);
+        self.available.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
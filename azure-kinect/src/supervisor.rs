@@ -0,0 +1,408 @@
+//! Keeps a device streaming across disconnections.
+//!
+//! When a USB cable glitches, the handle of a device fails every call from then on and only
+//! opening the device again helps. A [`Supervisor`] streams the device with the given serial
+//! number through a [`CaptureStream`], and when the stream stops on an error it looks for the
+//! device every retry interval. Once the device is back, it is opened again, its color controls
+//! are restored and the cameras and IMU restart with the same configuration. The application
+//! keeps reading captures from the supervisor, which times out while the device is away, and
+//! learns what happened from [`DeviceEvent`]s.
+//!
+//! ```no_run
+//! # use azure_kinect::*;
+//! # use azure_kinect::supervisor::*;
+//! # fn run(api: std::sync::Arc<Api>, config: &k4a_device_configuration_t) -> Result<(), Error> {
+//! let supervisor = Supervisor::builder(api, "000123456789")
+//!     .configuration(config)
+//!     .on_event(Box::new(|event| eprintln!("{:?}", event)))
+//!     .start()?;
+//! loop {
+//!     match supervisor.get_capture(1000) {
+//!         Ok(capture) => println!("{}", capture.get_temperature_c()),
+//!         Err(e) if e.is_timeout() => continue,
+//!         Err(e) => return Err(e),
+//!     }
+//! }
+//! # }
+//! ```
+
+use super::*;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What happened to a supervised device, reported to the [`DeviceEventHandler`].
+#[derive(Clone, Debug)]
+pub enum DeviceEvent {
+    /// The stream stopped with this error, and the supervisor is looking for the device.
+    Disconnected(Error),
+    /// The device is streaming again with the previous configuration.
+    Reconnected,
+    /// The device was found but could not be started again, or has not been found for
+    /// [`Supervisor::MISSING_REPORT_INTERVALS`] retry intervals in a row. The supervisor keeps
+    /// trying.
+    ReconnectFailed(Error),
+    /// A color control could not be restored after reconnecting.
+    ColorControlFailed(ColorControl, Error),
+}
+
+/// Callback of a [`Supervisor`], called on its thread.
+pub type DeviceEventHandler = Box<dyn Fn(&DeviceEvent) + Send + Sync + 'static>;
+
+/// Configures and starts a [`Supervisor`].
+pub struct SupervisorBuilder {
+    api: Arc<Api>,
+    serial: String,
+    configuration: k4a_device_configuration_t,
+    imu: bool,
+    capacity: usize,
+    policy: OverflowPolicy,
+    retry_interval: Duration,
+    color_controls: Option<ColorControlProfile>,
+    handler: Option<DeviceEventHandler>,
+}
+
+impl SupervisorBuilder {
+    /// Configuration of the cameras. Defaults to `k4a_device_configuration_t::default()`.
+    pub fn configuration(mut self, configuration: &k4a_device_configuration_t) -> Self {
+        self.configuration = *configuration;
+        self
+    }
+
+    /// Also streams the IMU, like [`CaptureStream::start_with_imu`]. Off by default.
+    pub fn imu(mut self, imu: bool) -> Self {
+        self.imu = imu;
+        self
+    }
+
+    /// Capacity and overflow policy of the capture queue. Defaults to a single capture and
+    /// [`OverflowPolicy::DropOldest`].
+    pub fn queue(mut self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.capacity = capacity;
+        self.policy = policy;
+        self
+    }
+
+    /// How often the supervisor looks for a lost device and checks the stream. Defaults to one
+    /// second.
+    pub fn retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Color controls applied when the device is opened. Without them, the controls the device
+    /// starts with are kept. Either way the controls are read back every retry interval, so
+    /// those set later through [`Supervisor::with_stream`] are restored as well.
+    pub fn color_controls(mut self, profile: ColorControlProfile) -> Self {
+        self.color_controls = Some(profile);
+        self
+    }
+
+    /// Called with every [`DeviceEvent`], on the supervisor thread.
+    pub fn on_event(mut self, handler: DeviceEventHandler) -> Self {
+        self.handler = Some(handler);
+        self
+    }
+
+    /// Opens the device and starts streaming. Fails if the device cannot be started now.
+    pub fn start(self) -> Result<Supervisor, Error> {
+        let shared = Arc::new(Watched {
+            api: self.api,
+            serial: self.serial,
+            configuration: self.configuration,
+            imu: self.imu,
+            capacity: self.capacity,
+            policy: self.policy,
+            retry_interval: self.retry_interval,
+            handler: self.handler,
+            state: Mutex::new(State {
+                stream: None,
+                lost: None,
+                profile: ColorControlProfile {
+                    controls: Vec::new(),
+                },
+                stop: false,
+            }),
+            changed: Condvar::new(),
+        });
+        let profile = self.color_controls.unwrap_or(ColorControlProfile {
+            controls: Vec::new(),
+        });
+        let stream = shared.connect(&profile)?;
+        {
+            let mut state = shared.lock();
+            state.profile = ColorControlProfile::read(stream.device())?;
+            state.stream = Some(Arc::new(stream));
+        }
+        let thread = {
+            let shared = shared.clone();
+            thread::Builder::new()
+                .name("k4a-supervisor".into())
+                .spawn(move || shared.watch())
+                .map_err(|_| Error::new(ErrorKind::Failed))?
+        };
+        Ok(Supervisor {
+            shared,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// Streams a device identified by its serial number, and reopens it whenever it is lost. See the
+/// [module documentation](self).
+pub struct Supervisor {
+    shared: Arc<Watched>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Supervisor {
+    /// Number of retry intervals without finding the lost device after which a
+    /// [`DeviceEvent::ReconnectFailed`] is reported, and again after as many more.
+    pub const MISSING_REPORT_INTERVALS: u32 = 10;
+
+    pub fn builder(api: Arc<Api>, serial: &str) -> SupervisorBuilder {
+        SupervisorBuilder {
+            api,
+            serial: serial.to_string(),
+            configuration: k4a_device_configuration_t::default(),
+            imu: false,
+            capacity: 1,
+            policy: OverflowPolicy::DropOldest,
+            retry_interval: Duration::from_secs(1),
+            color_controls: None,
+            handler: None,
+        }
+    }
+
+    pub fn serial(&self) -> &str {
+        &self.shared.serial
+    }
+
+    /// Calls `f` with the current stream, for the device, its color controls and its
+    /// calibration. `None` while the device is lost.
+    ///
+    /// The stream is only lent: a stream kept after the device is lost would keep its handle open,
+    /// and the device could not be opened again.
+    pub fn with_stream<R>(&self, f: impl FnOnce(&CaptureStream) -> R) -> Option<R> {
+        let stream = self.shared.lock().stream.clone()?;
+        Some(f(&stream))
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.shared.lock().stream.is_some()
+    }
+
+    /// Takes the oldest queued capture. Fails with [`ErrorKind::Timeout`] if none arrived in time,
+    /// including while the device is lost.
+    pub fn get_capture(&self, timeout_in_ms: i32) -> Result<Capture, Error> {
+        self.read(timeout_in_ms, |stream, timeout| stream.get_capture(timeout))
+    }
+
+    /// Takes the oldest queued capture, waiting for as long as it takes.
+    pub fn get_capture_wait_infinite(&self) -> Result<Capture, Error> {
+        self.get_capture(K4A_WAIT_INFINITE)
+    }
+
+    /// Takes the oldest queued IMU sample of a supervisor started with
+    /// [`SupervisorBuilder::imu`]. Fails with [`ErrorKind::Timeout`] if none arrived in time.
+    pub fn get_imu_sample(&self, timeout_in_ms: i32) -> Result<k4a_imu_sample_t, Error> {
+        self.read(timeout_in_ms, |stream, timeout| {
+            stream.get_imu_sample(timeout)
+        })
+    }
+
+    /// Takes the oldest queued IMU sample, waiting for as long as it takes.
+    pub fn get_imu_sample_wait_infinite(&self) -> Result<k4a_imu_sample_t, Error> {
+        self.get_imu_sample(K4A_WAIT_INFINITE)
+    }
+
+    fn read<T>(
+        &self,
+        timeout_in_ms: i32,
+        read: impl Fn(&CaptureStream, i32) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let deadline = match timeout_in_ms {
+            K4A_WAIT_INFINITE => None,
+            timeout => Some(Instant::now() + Duration::from_millis(timeout.max(0) as u64)),
+        };
+        loop {
+            let stream = self.shared.wait_for_stream(deadline)?;
+            let timeout = match deadline {
+                None => K4A_WAIT_INFINITE,
+                Some(deadline) => deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as i32,
+            };
+            match read(&stream, timeout) {
+                // The device is lost: wait for the supervisor to bring it back.
+                Err(e) if !e.is_timeout() && stream.finished().is_some() => {
+                    self.shared.lose(&stream, e)
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        self.shared.lock().stop = true;
+        self.shared.changed.notify_all();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// State shared by a [`Supervisor`] and its thread.
+struct Watched {
+    api: Arc<Api>,
+    serial: String,
+    configuration: k4a_device_configuration_t,
+    imu: bool,
+    capacity: usize,
+    policy: OverflowPolicy,
+    retry_interval: Duration,
+    handler: Option<DeviceEventHandler>,
+    state: Mutex<State>,
+    /// Signalled when the stream is replaced or lost, or the supervisor is dropped.
+    changed: Condvar,
+}
+
+struct State {
+    stream: Option<Arc<CaptureStream>>,
+    /// Set by a reader that found the stream stopped, until the thread reports it.
+    lost: Option<Error>,
+    /// The color controls restored on reconnection.
+    profile: ColorControlProfile,
+    stop: bool,
+}
+
+impl Watched {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    fn emit(&self, event: DeviceEvent) {
+        if let Some(handler) = &self.handler {
+            handler(&event);
+        }
+    }
+
+    fn connect(&self, profile: &ColorControlProfile) -> Result<CaptureStream, Error> {
+        let device = Device::open_by_serial(self.api.clone(), &self.serial)?;
+        for (control, error) in profile.apply(&device) {
+            self.emit(DeviceEvent::ColorControlFailed(control, error));
+        }
+        if self.imu {
+            CaptureStream::start_with_imu(device, &self.configuration, self.capacity, self.policy)
+        } else {
+            CaptureStream::start(device, &self.configuration, self.capacity, self.policy)
+        }
+    }
+
+    fn wait_for_stream(&self, deadline: Option<Instant>) -> Result<Arc<CaptureStream>, Error> {
+        let mut state = self.lock();
+        loop {
+            if let Some(stream) = &state.stream {
+                return Ok(stream.clone());
+            }
+            state = match deadline {
+                None => self.changed.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::new(ErrorKind::Timeout));
+                    }
+                    self.changed.wait_timeout(state, deadline - now).unwrap().0
+                }
+            };
+        }
+    }
+
+    /// Drops `stream`, unless the supervisor has replaced it already.
+    fn lose(&self, stream: &Arc<CaptureStream>, error: Error) {
+        let mut state = self.lock();
+        if matches!(&state.stream, Some(current) if Arc::ptr_eq(current, stream)) {
+            let lost = state.stream.take();
+            state.lost = Some(error);
+            self.changed.notify_all();
+            // Stopping the stream joins its threads, which must not wait for the lock.
+            drop(state);
+            drop(lost);
+        }
+    }
+
+    /// Body of the supervisor thread.
+    fn watch(&self) {
+        let mut missing = 0;
+        let mut state = self.lock();
+        while !state.stop {
+            if let Some(error) = state.stream.as_ref().and_then(|stream| stream.finished()) {
+                let lost = state.stream.take();
+                state.lost = Some(error);
+                drop(state);
+                drop(lost);
+                state = self.lock();
+            }
+            if let Some(error) = state.lost.take() {
+                drop(state);
+                self.emit(DeviceEvent::Disconnected(error));
+                state = self.lock();
+                continue;
+            }
+            match state.stream.clone() {
+                Some(stream) => {
+                    drop(state);
+                    let profile = ColorControlProfile::read(stream.device());
+                    state = self.lock();
+                    if let Ok(profile) = profile {
+                        state.profile = profile;
+                    }
+                }
+                None => {
+                    let profile = state.profile.clone();
+                    drop(state);
+                    let result = self.connect(&profile);
+                    state = self.lock();
+                    match result {
+                        Ok(stream) => {
+                            missing = 0;
+                            if state.stop {
+                                drop(state);
+                                drop(stream);
+                                break;
+                            }
+                            state.stream = Some(Arc::new(stream));
+                            self.changed.notify_all();
+                            drop(state);
+                            self.emit(DeviceEvent::Reconnected);
+                            state = self.lock();
+                        }
+                        // Expected while the device is unplugged, but not for long.
+                        Err(e) if matches!(e.kind(), ErrorKind::DeviceNotFound(_)) => {
+                            missing += 1;
+                            if missing % Supervisor::MISSING_REPORT_INTERVALS == 0 {
+                                drop(state);
+                                self.emit(DeviceEvent::ReconnectFailed(e));
+                                state = self.lock();
+                            }
+                        }
+                        Err(e) => {
+                            drop(state);
+                            self.emit(DeviceEvent::ReconnectFailed(e));
+                            state = self.lock();
+                        }
+                    }
+                }
+            }
+            if !state.stop && state.lost.is_none() {
+                state = self
+                    .changed
+                    .wait_timeout(state, self.retry_interval)
+                    .unwrap()
+                    .0;
+            }
+        }
+    }
+}
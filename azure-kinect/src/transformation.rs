@@ -12,7 +12,7 @@ pub struct Transformation {
 
 impl Transformation {
     pub fn new<'a>(api: Arc<Api>, calibration: &'a Calibration) -> Transformation {
-        let handle = (api.k4a_transformation_create)(&calibration.calibration);
+        let handle = unsafe { (api.k4a_transformation_create)(&calibration.calibration) };
         Transformation {
             api: api,
             handle: handle,
@@ -39,17 +39,30 @@ impl Transformation {
         }
     }
 
+    fn check_backend(&self, images: &[&Image]) -> Result<(), Error> {
+        if images
+            .iter()
+            .all(|image| image.api.is_simulated() == self.api.is_simulated())
+        {
+            Ok(())
+        } else {
+            Err(Error::new(ErrorKind::Failed))
+        }
+    }
+
     pub fn depth_image_to_color_camera_exist_image(
         &self,
         depth_image: &Image,
         transformed_depth_image: &mut Image,
     ) -> Result<(), Error> {
-        Error::from((self.api.k4a_transformation_depth_image_to_color_camera)(
-            self.handle,
-            depth_image.handle,
-            transformed_depth_image.handle,
-        ))
-        .to_result(())
+        self.check_backend(&[depth_image, transformed_depth_image])?;
+        Error::check("k4a_transformation_depth_image_to_color_camera", unsafe {
+            (self.api.k4a_transformation_depth_image_to_color_camera)(
+                self.handle,
+                depth_image.handle,
+                transformed_depth_image.handle,
+            )
+        })
     }
 
     pub fn depth_image_to_color_camera(&self, depth_image: &Image) -> Result<Image, Error> {
@@ -73,18 +86,32 @@ impl Transformation {
         interpolation_type: k4a_transformation_interpolation_type_t,
         invalid_custom_value: u32,
     ) -> Result<(), Error> {
-        Error::from((self
+        self.check_backend(&[
+            depth_image,
+            custom_image,
+            transformed_depth_image,
+            transformed_custom_image,
+        ])?;
+        let depth_image_to_color_camera_custom = self
             .api
-            .k4a_transformation_depth_image_to_color_camera_custom)(
-            self.handle,
-            depth_image.handle,
-            custom_image.handle,
-            transformed_depth_image.handle,
-            transformed_custom_image.handle,
-            interpolation_type,
-            invalid_custom_value,
-        ))
-        .to_result(())
+            .k4a_transformation_depth_image_to_color_camera_custom
+            .ok_or(Error::new(ErrorKind::Unsupported(
+                "k4a_transformation_depth_image_to_color_camera_custom",
+            )))?;
+        Error::check(
+            "k4a_transformation_depth_image_to_color_camera_custom",
+            unsafe {
+                (depth_image_to_color_camera_custom)(
+                    self.handle,
+                    depth_image.handle,
+                    custom_image.handle,
+                    transformed_depth_image.handle,
+                    transformed_custom_image.handle,
+                    interpolation_type,
+                    invalid_custom_value,
+                )
+            },
+        )
     }
 
     pub fn depth_image_to_color_camera_custom(
@@ -97,7 +124,7 @@ impl Transformation {
         let bytes_per_pixel: usize = match custom_image.get_format() {
             k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM8 => std::mem::size_of::<i8>(),
             k4a_image_format_t::K4A_IMAGE_FORMAT_CUSTOM16 => std::mem::size_of::<i16>(),
-            _ => return Err(Error::Failed),
+            _ => return Err(Error::new(ErrorKind::Failed)),
         };
 
         let mut transformed_depth_image = Image::with_format(
@@ -133,13 +160,15 @@ impl Transformation {
         color_image: &Image,
         transformed_color_image: &mut Image,
     ) -> Result<(), Error> {
-        Error::from((self.api.k4a_transformation_color_image_to_depth_camera)(
-            self.handle,
-            depth_image.handle,
-            color_image.handle,
-            transformed_color_image.handle,
-        ))
-        .to_result(())
+        self.check_backend(&[depth_image, color_image, transformed_color_image])?;
+        Error::check("k4a_transformation_color_image_to_depth_camera", unsafe {
+            (self.api.k4a_transformation_color_image_to_depth_camera)(
+                self.handle,
+                depth_image.handle,
+                color_image.handle,
+                transformed_color_image.handle,
+            )
+        })
     }
 
     pub fn color_image_to_depth_camera(
@@ -169,13 +198,15 @@ impl Transformation {
         camera: k4a_calibration_type_t,
         xyz_image: &mut Image,
     ) -> Result<(), Error> {
-        Error::from((self.api.k4a_transformation_depth_image_to_point_cloud)(
-            self.handle,
-            depth_image.handle,
-            camera,
-            xyz_image.handle,
-        ))
-        .to_result(())
+        self.check_backend(&[depth_image, xyz_image])?;
+        Error::check("k4a_transformation_depth_image_to_point_cloud", unsafe {
+            (self.api.k4a_transformation_depth_image_to_point_cloud)(
+                self.handle,
+                depth_image.handle,
+                camera,
+                xyz_image.handle,
+            )
+        })
     }
 
     pub fn depth_image_to_point_cloud(
@@ -197,7 +228,7 @@ impl Transformation {
 
 impl Drop for Transformation {
     fn drop(&mut self) {
-        (self.api.k4a_transformation_destroy)(self.handle);
+        unsafe { (self.api.k4a_transformation_destroy)(self.handle) };
         self.handle = ptr::null_mut();
     }
 }
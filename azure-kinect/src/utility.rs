@@ -2,79 +2,75 @@ use super::*;
 use std::ffi::CString;
 use std::ptr;
 
-pub(crate) fn get_k4a_cstring(
+/// Reads a NUL terminated string into a zero-initialized buffer and returns the bytes before
+/// the terminator.
+fn get_k4a_string_bytes(
+    call: &'static str,
     f: &dyn Fn(*mut ::std::os::raw::c_char, *mut usize) -> k4a_buffer_result_t,
-) -> Result<CString, Error> {
-    unsafe {
-        let mut buffer: usize = 0;
-        let r = (f)(ptr::null_mut(), &mut buffer);
-        match r {
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Ok(CString::default()),
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => {
-                if buffer > 1 {
-                    let mut retbuf = Vec::<u8>::with_capacity(buffer);
-                    retbuf.set_len(buffer - 1);
-                    Error::from((f)(
+) -> Result<Vec<u8>, Error> {
+    let mut buffer: usize = 0;
+    let r = (f)(ptr::null_mut(), &mut buffer);
+    match r {
+        k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Ok(Vec::new()),
+        k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => {
+            if buffer > 1 {
+                let mut retbuf = vec![0u8; buffer];
+                Error::check(
+                    call,
+                    (f)(
                         retbuf.as_mut_ptr() as *mut ::std::os::raw::c_char,
                         &mut buffer,
-                    ))
-                    .to_result(CString::from_vec_unchecked(retbuf))
-                } else {
-                    Err(Error::from(r))
-                }
+                    ),
+                )?;
+                let len = retbuf.iter().position(|&c| c == 0).unwrap_or(retbuf.len());
+                retbuf.truncate(len);
+                Ok(retbuf)
+            } else {
+                Err(Error::new(ErrorKind::TooSmall).with_call(call))
             }
-            _ => Err(Error::from(r)),
         }
+        _ => Error::check(call, r).map(|()| Vec::new()),
     }
 }
 
+pub(crate) fn get_k4a_cstring(
+    call: &'static str,
+    f: &dyn Fn(*mut ::std::os::raw::c_char, *mut usize) -> k4a_buffer_result_t,
+) -> Result<CString, Error> {
+    CString::new(get_k4a_string_bytes(call, f)?)
+        .map_err(|_| Error::new(ErrorKind::Failed).with_call(call))
+}
+
 pub(crate) fn get_k4a_string(
+    call: &'static str,
     f: &dyn Fn(*mut ::std::os::raw::c_char, *mut usize) -> k4a_buffer_result_t,
 ) -> Result<String, Error> {
-    unsafe {
-        let mut buffer: usize = 0;
-        let r = (f)(ptr::null_mut(), &mut buffer);
-        match r {
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Ok(String::new()),
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => {
-                if buffer > 1 {
-                    let mut retstr = String::with_capacity(buffer);
-                    retstr.as_mut_vec().set_len(buffer - 1);
-                    Error::from((f)(
-                        retstr.as_mut_ptr() as *mut ::std::os::raw::c_char,
-                        &mut buffer,
-                    ))
-                    .to_result(retstr)
-                } else {
-                    Err(Error::from(r))
-                }
-            }
-            _ => Err(Error::from(r)),
-        }
-    }
+    String::from_utf8(get_k4a_string_bytes(call, f)?)
+        .map_err(|_| Error::new(ErrorKind::Failed).with_call(call))
 }
 
 pub(crate) fn get_k4a_binary_data(
+    call: &'static str,
     f: &dyn Fn(*mut u8, *mut usize) -> k4a_buffer_result_t,
 ) -> Result<Vec<u8>, Error> {
-    unsafe {
-        let mut buffer: usize = 0;
-        let r = (f)(ptr::null_mut(), &mut buffer);
-        match r {
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Ok(Vec::<u8>::new()),
-            k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => {
-                if buffer > 1 {
-                    let mut retbuf = Vec::<u8>::with_capacity(buffer);
-                    retbuf.set_len(buffer);
-                    Error::from((f)(retbuf.as_mut_ptr(), &mut buffer)).to_result(retbuf)
-                } else {
-                    Err(Error::from(r))
-                }
+    let mut buffer: usize = 0;
+    let r = (f)(ptr::null_mut(), &mut buffer);
+    match r {
+        k4a_buffer_result_t::K4A_BUFFER_RESULT_SUCCEEDED => Ok(Vec::<u8>::new()),
+        k4a_buffer_result_t::K4A_BUFFER_RESULT_TOO_SMALL => {
+            if buffer > 1 {
+                let mut retbuf = vec![0u8; buffer];
+                Error::check(call, (f)(retbuf.as_mut_ptr(), &mut buffer))?;
+                retbuf.truncate(buffer);
+                Ok(retbuf)
+            } else {
+                Err(Error::new(ErrorKind::TooSmall).with_call(call))
             }
-            _ => Err(Error::from(r)),
         }
+        _ => Error::check(call, r).map(|()| Vec::new()),
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,8 +91,8 @@ mod tests {
                 }
             };
 
-        let rst1 = get_k4a_string(&f);
-        let rst2 = get_k4a_cstring(&f);
+        let rst1 = get_k4a_string("test", &f);
+        let rst2 = get_k4a_cstring("test", &f);
         assert_eq!(rst1.unwrap(), t1);
         assert_eq!(rst2.unwrap().to_str().unwrap(), t1);
     }
@@ -0,0 +1,408 @@
+use super::*;
+
+/// Version of an Azure Kinect Sensor SDK library.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SdkVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl SdkVersion {
+    /// The SDK version the bundled bindings were generated from.
+    pub const BINDINGS: SdkVersion =
+        SdkVersion::new(K4A_VERSION_MAJOR, K4A_VERSION_MINOR, K4A_VERSION_PATCH);
+
+    pub const fn new(major: u32, minor: u32, patch: u32) -> SdkVersion {
+        SdkVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version from the end of a library file name such as `libk4a.so.1.4.1`.
+    pub(crate) fn from_file_name(file_name: &str) -> Option<SdkVersion> {
+        let (_, suffix) = file_name.rsplit_once(".so.")?;
+        SdkVersion::parse(suffix)
+    }
+
+    /// Parses `major[.minor[.patch]]`, such as `1.4.1` or `1.3`.
+    pub(crate) fn parse(version: &str) -> Option<SdkVersion> {
+        let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+        let major = parts.next()??;
+        let minor = parts.next().unwrap_or(Some(0))?;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        Some(SdkVersion::new(major, minor, patch))
+    }
+}
+
+impl std::fmt::Display for SdkVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Optional features of the sensor SDK that depend on which entry points the loaded library
+/// exports. See [`Api::supports`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// `k4a_set_allocator`, which routes the image allocations of the SDK through user callbacks.
+    Allocator,
+    /// `k4a_transformation_depth_image_to_color_camera_custom`, added in SDK 1.3.
+    CustomTransformation,
+}
+
+/// Version of the firmware of one subsystem of a device, ordered by major, minor and then
+/// iteration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FirmwareVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub iteration: u32,
+}
+
+impl FirmwareVersion {
+    pub const fn new(major: u32, minor: u32, iteration: u32) -> FirmwareVersion {
+        FirmwareVersion {
+            major,
+            minor,
+            iteration,
+        }
+    }
+}
+
+impl From<k4a_version_t> for FirmwareVersion {
+    fn from(version: k4a_version_t) -> FirmwareVersion {
+        FirmwareVersion::new(version.major, version.minor, version.iteration)
+    }
+}
+
+impl std::fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.iteration)
+    }
+}
+
+/// Parses `major.minor[.iteration]`, such as `1.6.110`.
+impl std::str::FromStr for FirmwareVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<FirmwareVersion, Error> {
+        SdkVersion::parse(s.trim())
+            .filter(|_| s.contains('.'))
+            .map(|v| FirmwareVersion::new(v.major, v.minor, v.patch))
+            .ok_or_else(|| {
+                Error::new(ErrorKind::UnsupportedFirmware(format!(
+                    "{:?} is not a firmware version",
+                    s
+                )))
+            })
+    }
+}
+
+/// A subsystem of a device with firmware of its own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FirmwareSubsystem {
+    Rgb,
+    Depth,
+    DepthSensor,
+    Audio,
+}
+
+impl FirmwareSubsystem {
+    pub const ALL: [FirmwareSubsystem; 4] = [
+        FirmwareSubsystem::Rgb,
+        FirmwareSubsystem::Depth,
+        FirmwareSubsystem::DepthSensor,
+        FirmwareSubsystem::Audio,
+    ];
+
+    /// Name in the text form of a [`FirmwareRequirement`].
+    pub fn name(self) -> &'static str {
+        match self {
+            FirmwareSubsystem::Rgb => "rgb",
+            FirmwareSubsystem::Depth => "depth",
+            FirmwareSubsystem::DepthSensor => "depth_sensor",
+            FirmwareSubsystem::Audio => "audio",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FirmwareBuild {
+    /// Production firmware.
+    Release,
+    /// Pre-production firmware.
+    Debug,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FirmwareSignature {
+    Microsoft,
+    Test,
+    Unsigned,
+}
+
+/// The firmware of a device, returned by [`Device::get_firmware`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceFirmware {
+    pub rgb: FirmwareVersion,
+    pub depth: FirmwareVersion,
+    pub depth_sensor: FirmwareVersion,
+    pub audio: FirmwareVersion,
+    pub build: FirmwareBuild,
+    pub signature: FirmwareSignature,
+}
+
+impl DeviceFirmware {
+    pub fn version(&self, subsystem: FirmwareSubsystem) -> FirmwareVersion {
+        match subsystem {
+            FirmwareSubsystem::Rgb => self.rgb,
+            FirmwareSubsystem::Depth => self.depth,
+            FirmwareSubsystem::DepthSensor => self.depth_sensor,
+            FirmwareSubsystem::Audio => self.audio,
+        }
+    }
+}
+
+impl From<k4a_hardware_version_t> for DeviceFirmware {
+    fn from(version: k4a_hardware_version_t) -> DeviceFirmware {
+        DeviceFirmware {
+            rgb: version.rgb.into(),
+            depth: version.depth.into(),
+            depth_sensor: version.depth_sensor.into(),
+            audio: version.audio.into(),
+            build: match version.firmware_build {
+                k4a_firmware_build_t::K4A_FIRMWARE_BUILD_RELEASE => FirmwareBuild::Release,
+                k4a_firmware_build_t::K4A_FIRMWARE_BUILD_DEBUG => FirmwareBuild::Debug,
+            },
+            signature: match version.firmware_signature {
+                k4a_firmware_signature_t::K4A_FIRMWARE_SIGNATURE_MSFT => {
+                    FirmwareSignature::Microsoft
+                }
+                k4a_firmware_signature_t::K4A_FIRMWARE_SIGNATURE_TEST => FirmwareSignature::Test,
+                k4a_firmware_signature_t::K4A_FIRMWARE_SIGNATURE_UNSIGNED => {
+                    FirmwareSignature::Unsigned
+                }
+            },
+        }
+    }
+}
+
+/// Firmware a device must have, checked with [`Device::check_firmware`].
+///
+/// The text form lists `subsystem=version` pairs, and `release` or `signed` to require
+/// production or Microsoft signed firmware, separated by `,`:
+///
+/// ```
+/// # use azure_kinect::*;
+/// let requirement: FirmwareRequirement = "rgb=1.6.110,depth=1.6.79,release".parse()?;
+/// assert_eq!(
+///     requirement.minimum(FirmwareSubsystem::Rgb),
+///     Some(FirmwareVersion::new(1, 6, 110))
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FirmwareRequirement {
+    minimum: Vec<(FirmwareSubsystem, FirmwareVersion)>,
+    release_build: bool,
+    microsoft_signed: bool,
+}
+
+/// A way in which the firmware of a device does not meet a [`FirmwareRequirement`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FirmwareMismatch {
+    Outdated {
+        subsystem: FirmwareSubsystem,
+        installed: FirmwareVersion,
+        minimum: FirmwareVersion,
+    },
+    DebugBuild,
+    NotMicrosoftSigned(FirmwareSignature),
+}
+
+impl std::fmt::Display for FirmwareMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FirmwareMismatch::Outdated {
+                subsystem,
+                installed,
+                minimum,
+            } => write!(
+                f,
+                "{} firmware {} is older than {}",
+                subsystem.name(),
+                installed,
+                minimum
+            ),
+            FirmwareMismatch::DebugBuild => write!(f, "the firmware is a debug build"),
+            FirmwareMismatch::NotMicrosoftSigned(signature) => {
+                write!(f, "the firmware signature is {:?}", signature)
+            }
+        }
+    }
+}
+
+impl FirmwareRequirement {
+    /// Requires nothing.
+    pub fn new() -> FirmwareRequirement {
+        FirmwareRequirement::default()
+    }
+
+    /// Requires `version` or later on `subsystem`.
+    pub fn at_least(mut self, subsystem: FirmwareSubsystem, version: FirmwareVersion) -> Self {
+        self.minimum.retain(|&(s, _)| s != subsystem);
+        self.minimum.push((subsystem, version));
+        self
+    }
+
+    /// Requires production firmware.
+    pub fn release_build(mut self, required: bool) -> Self {
+        self.release_build = required;
+        self
+    }
+
+    /// Requires Microsoft signed firmware.
+    pub fn microsoft_signed(mut self, required: bool) -> Self {
+        self.microsoft_signed = required;
+        self
+    }
+
+    pub fn minimum(&self, subsystem: FirmwareSubsystem) -> Option<FirmwareVersion> {
+        self.minimum
+            .iter()
+            .find(|&&(s, _)| s == subsystem)
+            .map(|&(_, version)| version)
+    }
+
+    /// Every way in which `firmware` does not meet the requirement, in the order of
+    /// [`FirmwareSubsystem::ALL`] and then build and signature. Empty if it does.
+    pub fn check(&self, firmware: &DeviceFirmware) -> Vec<FirmwareMismatch> {
+        let mut mismatches: Vec<_> = FirmwareSubsystem::ALL
+            .iter()
+            .filter_map(|&subsystem| {
+                let minimum = self.minimum(subsystem)?;
+                let installed = firmware.version(subsystem);
+                if installed < minimum {
+                    Some(FirmwareMismatch::Outdated {
+                        subsystem,
+                        installed,
+                        minimum,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if self.release_build && firmware.build != FirmwareBuild::Release {
+            mismatches.push(FirmwareMismatch::DebugBuild);
+        }
+        if self.microsoft_signed && firmware.signature != FirmwareSignature::Microsoft {
+            mismatches.push(FirmwareMismatch::NotMicrosoftSigned(firmware.signature));
+        }
+        mismatches
+    }
+}
+
+impl std::fmt::Display for FirmwareRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut items: Vec<String> = FirmwareSubsystem::ALL
+            .iter()
+            .filter_map(|&subsystem| {
+                self.minimum(subsystem)
+                    .map(|version| format!("{}={}", subsystem.name(), version))
+            })
+            .collect();
+        if self.release_build {
+            items.push("release".into());
+        }
+        if self.microsoft_signed {
+            items.push("signed".into());
+        }
+        write!(f, "{}", items.join(","))
+    }
+}
+
+impl std::str::FromStr for FirmwareRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<FirmwareRequirement, Error> {
+        let mut requirement = FirmwareRequirement::new();
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            requirement = match item.split_once('=') {
+                None if item == "release" => requirement.release_build(true),
+                None if item == "signed" => requirement.microsoft_signed(true),
+                Some((name, version)) => {
+                    let subsystem = FirmwareSubsystem::ALL
+                        .iter()
+                        .copied()
+                        .find(|subsystem| subsystem.name() == name.trim())
+                        .ok_or_else(|| {
+                            Error::new(ErrorKind::UnsupportedFirmware(format!(
+                                "unknown subsystem {:?}",
+                                name
+                            )))
+                        })?;
+                    requirement.at_least(subsystem, version.parse()?)
+                }
+                None => {
+                    return Err(Error::new(ErrorKind::UnsupportedFirmware(format!(
+                        "unknown firmware requirement {:?}",
+                        item
+                    ))))
+                }
+            };
+        }
+        Ok(requirement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_file_name() {
+        assert_eq!(
+            SdkVersion::from_file_name("libk4a.so.1.4.1"),
+            Some(SdkVersion::new(1, 4, 1))
+        );
+        assert_eq!(
+            SdkVersion::from_file_name("libk4arecord.so.1.3"),
+            Some(SdkVersion::new(1, 3, 0))
+        );
+        assert_eq!(SdkVersion::from_file_name("libk4a.so"), None);
+        assert_eq!(SdkVersion::from_file_name("k4a.dll"), None);
+        assert!(SdkVersion::new(1, 3, 0) < SdkVersion::BINDINGS);
+    }
+
+    #[test]
+    fn firmware_requirement() {
+        let firmware = DeviceFirmware {
+            rgb: FirmwareVersion::new(1, 6, 110),
+            depth: FirmwareVersion::new(1, 6, 79),
+            depth_sensor: FirmwareVersion::new(6109, 7, 0),
+            audio: FirmwareVersion::new(1, 6, 14),
+            build: FirmwareBuild::Debug,
+            signature: FirmwareSignature::Microsoft,
+        };
+        assert!(FirmwareVersion::new(1, 6, 110) > FirmwareVersion::new(1, 5, 200));
+        let requirement: FirmwareRequirement = "rgb=1.6.110, depth=1.6.80,signed".parse().unwrap();
+        assert_eq!(requirement.to_string(), "rgb=1.6.110,depth=1.6.80,signed");
+        assert_eq!(
+            requirement.check(&firmware),
+            vec![FirmwareMismatch::Outdated {
+                subsystem: FirmwareSubsystem::Depth,
+                installed: FirmwareVersion::new(1, 6, 79),
+                minimum: FirmwareVersion::new(1, 6, 80),
+            }]
+        );
+        let requirement = requirement.release_build(true);
+        assert_eq!(requirement.check(&firmware).len(), 2);
+        assert!("rgb=1".parse::<FirmwareRequirement>().is_err());
+        assert!("gpu=1.0.0".parse::<FirmwareRequirement>().is_err());
+        assert!("latest".parse::<FirmwareRequirement>().is_err());
+    }
+}
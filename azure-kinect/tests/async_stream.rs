@@ -0,0 +1,91 @@
+#![cfg(feature = "async")]
+
+use azure_kinect::async_stream::*;
+use azure_kinect::*;
+use futures::executor::block_on;
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+
+//  These tests run against the simulated backend, like `simulated.rs`.
+
+fn depth_only() -> Result<k4a_device_configuration_t, Error> {
+    DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .build()
+}
+
+#[test]
+fn captures_and_imu_samples() -> Result<(), Error> {
+    let device = Device::new(Api::simulated(), 0)?;
+    let stream = CaptureStream::start_with_imu(device, &depth_only()?, 4, OverflowPolicy::Block)?;
+    block_on(async {
+        let mut captures = stream.captures();
+        let first = captures.next().await.unwrap()?;
+        let second = captures.next().await.unwrap()?;
+        assert!(
+            second.get_depth_image().get_device_timestamp_usec()
+                > first.get_depth_image().get_device_timestamp_usec()
+        );
+
+        let samples: Vec<_> = stream.imu_samples()?.take(10).collect().await;
+        let samples = samples.into_iter().collect::<Result<Vec<_>, _>>()?;
+        assert!(samples
+            .windows(2)
+            .all(|w| w[1].acc_timestamp_usec > w[0].acc_timestamp_usec));
+        Ok(())
+    })
+}
+
+#[test]
+fn timeout() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api, 0)?;
+    let stream = CaptureStream::start(device, &depth_only()?, 1, OverflowPolicy::DropOldest)?;
+    // A stream without IMU has no samples.
+    assert!(stream.imu_samples().is_err());
+    block_on(async {
+        // Captures arrive every 33 ms, far more often than the timeout.
+        let mut captures = stream.captures().timeout(Duration::from_millis(500));
+        assert!(captures.next().await.unwrap().is_ok());
+    });
+    drop(stream);
+
+    let api_tracker = ApiTracker::simulated();
+    let device = Device::new(Api::simulated(), 0)?;
+    let calibration = device.get_calibration(
+        k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED,
+        k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF,
+    )?;
+    let tracker = Tracker::new(
+        api_tracker,
+        &calibration.calibration,
+        k4abt_tracker_configuration_t::default(),
+    )?;
+    // Nothing is enqueued, so the tracker never produces a frame.
+    let mut frames = BodyFrames::new(Arc::new(tracker), 1)?.timeout(Duration::from_millis(200));
+    let error = block_on(frames.next()).unwrap().err().unwrap();
+    assert!(error.is_timeout());
+    Ok(())
+}
+
+#[test]
+fn body_frames() -> Result<(), Error> {
+    let api_tracker = ApiTracker::simulated();
+    let mut device = Device::new(Api::simulated(), 0)?;
+    let config = depth_only()?;
+    let calibration = device.get_calibration(config.depth_mode, config.color_resolution)?;
+    let tracker = Tracker::new(
+        api_tracker,
+        &calibration.calibration,
+        k4abt_tracker_configuration_t::default(),
+    )?;
+    let mut frames = BodyFrames::new(Arc::new(tracker), 4)?;
+    let cameras = device.start_cameras(&config)?;
+    frames
+        .tracker()
+        .enqueue_capture(&cameras.get_capture(1000)?, 1000)?;
+    let frame = block_on(frames.next()).unwrap()?;
+    assert_eq!(frames.tracker().get_num_bodies(&frame), 1);
+    Ok(())
+}
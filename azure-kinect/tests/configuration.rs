@@ -0,0 +1,96 @@
+use azure_kinect::*;
+use std::convert::TryFrom;
+
+fn rejects(config: DeviceConfiguration, needle: &str) {
+    match config.build() {
+        Err(error) => match error.kind() {
+            ErrorKind::InvalidConfiguration(message) => {
+                assert!(message.contains(needle), "{:?} lacks {:?}", message, needle)
+            }
+            _ => panic!("unexpected error {}", error),
+        },
+        Ok(_) => panic!("accepted a configuration mentioning {:?}", needle),
+    }
+}
+
+#[test]
+fn valid_configuration() -> Result<(), Error> {
+    let config = DeviceConfiguration::new()
+        .color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_NV12,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_720P,
+        )
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_UNBINNED)
+        .fps(k4a_fps_t::K4A_FRAMES_PER_SECOND_15)
+        .synchronized_images_only(true)
+        .subordinate(160)
+        .build()?;
+    assert_eq!(
+        config.wired_sync_mode,
+        k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE
+    );
+    assert_eq!(config.subordinate_delay_off_master_usec, 160);
+
+    // Delays of a whole frame period are as far as the SDK goes.
+    let config = DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED)
+        .depth_delay_off_color_usec(-33_333)
+        .subordinate(33_333)
+        .build()?;
+    assert_eq!(config.depth_delay_off_color_usec, -33_333);
+
+    let raw = k4a_device_configuration_t::default();
+    let checked = k4a_device_configuration_t::try_from(DeviceConfiguration::from(raw))?;
+    assert_eq!(checked.depth_mode, raw.depth_mode);
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations() {
+    let color = DeviceConfiguration::new().color(
+        k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
+        k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+    );
+    let depth = DeviceConfiguration::new().depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED);
+
+    rejects(DeviceConfiguration::new(), "at least one");
+    rejects(
+        depth.depth(k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_UNBINNED),
+        "WFOV_UNBINNED",
+    );
+    rejects(
+        color.color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_3072P,
+        ),
+        "3072P",
+    );
+    rejects(
+        color.color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_YUY2,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+        ),
+        "only supported at K4A_COLOR_RESOLUTION_720P",
+    );
+    rejects(
+        color.color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+        ),
+        "not a color camera format",
+    );
+    rejects(
+        color.synchronized_images_only(true),
+        "synchronized_images_only",
+    );
+    rejects(depth.depth_delay_off_color_usec(-33_334), "frame period");
+    rejects(depth.subordinate(33_334), "frame period");
+    rejects(depth.master(), "master");
+    rejects(
+        DeviceConfiguration::from(k4a_device_configuration_t {
+            subordinate_delay_off_master_usec: 10,
+            ..k4a_device_configuration_t::default()
+        }),
+        "requires subordinate mode",
+    );
+}
@@ -10,7 +10,7 @@ fn record_test_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let factory = FactoryRecord::with_library_directory(
         std::env::current_dir()?
             .to_str()
-            .ok_or(error::Error::Failed)?,
+            .ok_or(Error::new(ErrorKind::Failed))?,
     )?
     .set_debug_message_handler(
         Box::new(|level, file, line, message| {
@@ -0,0 +1,36 @@
+use azure_kinect::*;
+
+#[test]
+fn report_lists_every_path_tried() {
+    let search = LibrarySearch::empty()
+        .directory("/nonexistent/first")
+        .directory("/nonexistent/second");
+    let error = match ApiRecord::with_search(&search) {
+        Err(error) => error,
+        Ok(_) => panic!("loaded k4arecord from a nonexistent directory"),
+    };
+    let report = match error.kind() {
+        ErrorKind::LibraryNotFound(report) => report,
+        _ => panic!("unexpected error {}", error),
+    };
+    assert_eq!(report.library, "k4arecord");
+    assert!(!report.attempts.is_empty());
+    assert!(report.attempts[0].path.starts_with("/nonexistent/first"));
+    assert!(report
+        .attempts
+        .last()
+        .unwrap()
+        .path
+        .starts_with("/nonexistent/second"));
+    let message = error.to_string();
+    assert!(message.starts_with("could not load k4arecord, tried:"));
+    assert!(message.contains("/nonexistent/second"));
+}
+
+#[test]
+fn empty_search_tries_nothing() {
+    match ApiTracker::with_search(&LibrarySearch::empty()).map_err(|e| e.kind().clone()) {
+        Err(ErrorKind::LibraryNotFound(report)) => assert!(report.attempts.is_empty()),
+        _ => panic!("expected LibraryNotFound"),
+    }
+}
@@ -0,0 +1,1022 @@
+use azure_kinect::*;
+
+//  These tests run against the simulated backend and need no hardware or SDK install.
+
+#[test]
+fn device_info() -> Result<(), Error> {
+    let api = Api::simulated();
+    assert!(api.device_get_installed_count() >= 1);
+    assert_eq!(api.sdk_version(), SdkVersion::BINDINGS);
+    assert!(api.supports(Capability::CustomTransformation));
+    let device = Device::new(api, 0)?;
+    assert_eq!(
+        device.get_serialnum()?,
+        simulated::DEFAULT_SERIAL_NUMBER.to_string()
+    );
+    let version = device.get_version()?;
+    assert_eq!(version.rgb.major, 1);
+    assert_eq!(device.is_sync_connected()?, (false, false));
+    Ok(())
+}
+
+#[test]
+fn enumerate_and_open_by_serial() -> Result<(), Error> {
+    let api = Api::simulated();
+    simulated::attach_device("000000000007");
+    let devices = Device::enumerate(&api);
+    let info = devices
+        .iter()
+        .filter_map(|device| device.as_ref().ok())
+        .find(|info| info.serial == "000000000007")
+        .expect("attached device is listed");
+    assert_eq!(info.version.rgb.major, 1);
+    assert!(!info.sync_in_connected);
+
+    let device = Device::open_by_serial(api.clone(), "000000000007")?;
+    assert_eq!(device.info()?.index, info.index);
+    drop(device);
+    assert!(simulated::detach_device("000000000007"));
+    let error = Device::open_by_serial(api, "000000000007").err().unwrap();
+    assert!(matches!(error.kind(), ErrorKind::DeviceNotFound(_)));
+    Ok(())
+}
+
+#[test]
+fn captures() -> Result<(), Error> {
+    let api = Api::simulated();
+    let mut device = Device::new(api, 0)?;
+    let config = k4a_device_configuration_t {
+        synchronized_images_only: true,
+        ..k4a_device_configuration_t::default()
+    };
+    let cameras = device.start_cameras(&config)?;
+    let mut last_timestamp = 0;
+    for _ in 0..3 {
+        let capture = cameras.get_capture(1000)?;
+        let color = capture.get_color_image();
+        assert_eq!(color.get_format(), config.color_format);
+        assert_eq!(color.get_width_pixels(), 1280);
+        assert_eq!(color.get_height_pixels(), 720);
+        assert_eq!(color.get_stride_bytes(), 1280 * 4);
+        assert!(color.get_device_timestamp_usec() > last_timestamp);
+        last_timestamp = color.get_device_timestamp_usec();
+
+        let depth = capture.get_depth_image();
+        assert_eq!(
+            depth.get_format(),
+            k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16
+        );
+        assert_eq!(depth.get_width_pixels(), 320);
+        assert_eq!(depth.get_height_pixels(), 288);
+        assert_eq!(depth.get_size(), 320 * 288 * 2);
+
+        let ir = capture.get_ir_image();
+        assert_eq!(ir.get_format(), k4a_image_format_t::K4A_IMAGE_FORMAT_IR16);
+        assert!(capture.get_temperature_c() > 0.0);
+    }
+    // Dropping the session stops the cameras, so they can be started again.
+    drop(cameras);
+    let cameras = device.start_cameras(&config)?;
+    assert!(cameras.get_capture(1000).is_ok());
+    Ok(())
+}
+
+#[test]
+fn invalid_configuration() -> Result<(), Error> {
+    let api = Api::simulated();
+    let mut device = Device::new(api, 0)?;
+    let config = k4a_device_configuration_t {
+        color_format: k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_NV12,
+        color_resolution: k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+        ..k4a_device_configuration_t::default()
+    };
+    assert!(device.start_cameras(&config).is_err());
+    Ok(())
+}
+
+#[test]
+fn color_control_profile() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api, 0)?;
+    let mut profile = ColorControlProfile::read(&device)?;
+    assert_eq!(profile.controls.len(), ColorControlProfile::COMMANDS.len());
+    assert!(profile
+        .controls
+        .contains(&ColorControl::Exposure(ControlMode::Auto)));
+    assert_eq!(profile.to_string().parse::<ColorControlProfile>()?, profile);
+
+    profile.controls = vec![
+        ColorControl::Brightness(100),
+        ColorControl::Contrast(11),
+        ColorControl::Sharpness(3),
+    ];
+    let failures = profile.apply(&device);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, ColorControl::Contrast(11));
+    assert!(matches!(
+        failures[0].1.kind(),
+        ErrorKind::InvalidColorControl(_)
+    ));
+    assert_eq!(
+        device.get_color_control(k4a_color_control_command_t::K4A_COLOR_CONTROL_SHARPNESS)?,
+        ColorControl::Sharpness(3)
+    );
+
+    let profile: ColorControlProfile = "WHITEBALANCE=4500; GAIN=10".parse()?;
+    assert!(profile.apply(&device).is_empty());
+    assert!("BRIGHTNESS=auto".parse::<ColorControlProfile>().is_err());
+    assert!("NOT_A_CONTROL=1".parse::<ColorControlProfile>().is_err());
+    Ok(())
+}
+
+#[test]
+fn debug_messages() -> Result<(), Error> {
+    use std::sync::{Arc, Mutex};
+
+    let api = Api::simulated();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let sink = messages.clone();
+    api.replace_debug_message_handler(
+        Some(Box::new(move |level, _file, _line, message| {
+            sink.lock().unwrap().push((level, message.to_string()));
+        })),
+        k4a_log_level_t::K4A_LOG_LEVEL_WARNING,
+    )?;
+    // The handler is called from a thread other than the one that set it.
+    let device = Device::new(api.clone(), 0)?;
+    let config = k4a_device_configuration_t {
+        color_format: k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_NV12,
+        color_resolution: k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+        ..k4a_device_configuration_t::default()
+    };
+    let error = std::thread::spawn(move || {
+        let mut device = device;
+        device.start_cameras(&config).map(drop)
+    })
+    .join()
+    .unwrap()
+    .unwrap_err();
+    api.replace_debug_message_handler(None, k4a_log_level_t::K4A_LOG_LEVEL_OFF)?;
+    assert!(messages
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(level, _)| *level == k4a_log_level_t::K4A_LOG_LEVEL_ERROR));
+    // The error keeps the messages logged before the call failed.
+    assert!(error
+        .sdk_messages()
+        .iter()
+        .any(|message| message.level == k4a_log_level_t::K4A_LOG_LEVEL_ERROR));
+    // They are not attached to later errors.
+    let mut device = Device::new(api, 0)?;
+    let again = device.start_cameras(&config).err().unwrap();
+    assert!(again.sdk_messages().is_empty());
+    Ok(())
+}
+
+#[test]
+fn error_context() -> Result<(), Error> {
+    let api = Api::simulated();
+    let mut device = Device::new(api.clone(), 0)?;
+    let config = k4a_device_configuration_t {
+        color_format: k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_NV12,
+        color_resolution: k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P,
+        ..k4a_device_configuration_t::default()
+    };
+    let error = device.start_cameras(&config).err().unwrap();
+    assert!(matches!(error.kind(), ErrorKind::Failed));
+    assert_eq!(error.call(), Some("k4a_device_start_cameras"));
+    let id = error.device().unwrap();
+    assert_eq!(id.index, 0);
+    assert_eq!(id.serial.as_deref(), Some(simulated::DEFAULT_SERIAL_NUMBER));
+    assert!(error
+        .to_string()
+        .starts_with("k4a_device_start_cameras: Failed on device 0"));
+
+    let cameras = device.start_cameras(&k4a_device_configuration_t::default())?;
+    let timeout = loop {
+        if let Err(error) = cameras.get_capture(0) {
+            break error;
+        }
+    };
+    assert!(timeout.is_timeout());
+    assert!(timeout.sdk_messages().is_empty());
+
+    let path = std::env::temp_dir().join("azure-kinect-sim-missing.mkv");
+    let error = match playback::Playback::playback_open(
+        api,
+        ApiRecord::simulated(),
+        path.to_str().unwrap(),
+    ) {
+        Err(error) => error,
+        Ok(_) => panic!("opened a missing recording"),
+    };
+    assert_eq!(error.call(), Some("k4a_playback_open"));
+    assert_eq!(error.path(), Some(path.as_path()));
+    Ok(())
+}
+
+#[test]
+fn imu() -> Result<(), Error> {
+    let api = Api::simulated();
+    let mut device = Device::new(api, 0)?;
+    let mut cameras = device.start_cameras(&k4a_device_configuration_t::default())?;
+    let imu = cameras.start_imu()?;
+    let first = imu.get_imu_sample(1000)?;
+    let second = imu.get_imu_sample(1000)?;
+    assert!(second.acc_timestamp_usec > first.acc_timestamp_usec);
+    assert!(unsafe { (first.acc_sample.xyz.z + 9.81).abs() } < 0.1);
+    // Captures are still read while the IMU runs.
+    assert!(imu.get_capture(1000).is_ok());
+    drop(imu);
+    let imu = cameras.start_imu()?;
+    assert!(imu.get_imu_sample(1000).is_ok());
+    Ok(())
+}
+
+#[test]
+fn capture_stream() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api, 0)?;
+    let config = DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .build()?;
+    let stream = CaptureStream::start(device, &config, 2, OverflowPolicy::DropOldest)?;
+    // The simulated device runs at 30 fps, so the queue overflows while nobody reads it.
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    assert!(stream.dropped() > 0);
+    let first = stream.get_capture(1000)?;
+    let second = stream.get_capture(1000)?;
+    let first = first.get_depth_image().get_device_timestamp_usec();
+    let second = second.get_depth_image().get_device_timestamp_usec();
+    assert!(second > first);
+    // Stopping the stream stops the cameras and gives the device back.
+    let mut device = stream.stop();
+    assert!(device.start_cameras(&config).is_ok());
+    drop(device);
+
+    let device = Device::new(Api::simulated(), 0)?;
+    let stream = CaptureStream::start(device, &config, 1, OverflowPolicy::Block)?;
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    let capture = stream.get_capture_wait_infinite()?;
+    // Captures can be handed to another thread.
+    std::thread::spawn(move || assert!(capture.get_temperature_c() > 0.0))
+        .join()
+        .unwrap();
+    assert_eq!(stream.dropped(), 0);
+    assert!(stream.device().get_serialnum().is_ok());
+    Ok(())
+}
+
+#[test]
+fn color_controls() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api, 0)?;
+    let command = k4a_color_control_command_t::K4A_COLOR_CONTROL_BRIGHTNESS;
+    let capabilities = device.get_color_control_capabilities(command)?;
+    assert_eq!(capabilities.max_value, 255);
+    assert!(!capabilities.supports_auto);
+    assert_eq!(
+        device.get_color_control(command)?,
+        ColorControl::Brightness(128)
+    );
+
+    device.set_color_control(ColorControl::Brightness(200))?;
+    assert_eq!(
+        device.get_color_control(command)?,
+        ColorControl::Brightness(200)
+    );
+    device.set_color_control(ColorControl::Brightness(128))?;
+
+    // Settings outside the capabilities are rejected before they reach the device.
+    let error = device
+        .set_color_control(ColorControl::Brightness(256))
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidColorControl(_)));
+    assert!(error.call().is_none());
+    assert!(device.set_color_control(ColorControl::Gain(0)).is_ok());
+    assert!(device
+        .set_color_control(ColorControl::WhiteBalance(ControlMode::Manual(4_505)))
+        .is_err());
+
+    device.set_color_control(ColorControl::WhiteBalance(ControlMode::Manual(5_000)))?;
+    assert_eq!(
+        device.get_color_control(k4a_color_control_command_t::K4A_COLOR_CONTROL_WHITEBALANCE)?,
+        ColorControl::WhiteBalance(ControlMode::Manual(5_000))
+    );
+    device.set_color_control(ColorControl::WhiteBalance(ControlMode::Auto))?;
+    device.set_color_control(ColorControl::Exposure(ControlMode::Manual(
+        std::time::Duration::from_micros(10_500),
+    )))?;
+    assert_eq!(
+        device.get_color_control(
+            k4a_color_control_command_t::K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE
+        )?,
+        ColorControl::Exposure(ControlMode::Manual(std::time::Duration::from_micros(
+            10_500
+        )))
+    );
+    assert_eq!(
+        device.get_color_control(
+            k4a_color_control_command_t::K4A_COLOR_CONTROL_POWERLINE_FREQUENCY
+        )?,
+        ColorControl::PowerlineFrequency(PowerlineFrequency::Hz60)
+    );
+    Ok(())
+}
+
+#[test]
+fn allocator() -> Result<(), Error> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // Other tests allocate through the hook while it is installed, so only buffers of an
+    // otherwise unused size are counted.
+    const SIZE: usize = 123 * 45 * 2;
+
+    #[derive(Default)]
+    struct Counting {
+        live: AtomicUsize,
+    }
+
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, size: usize) -> *mut u8 {
+            if size == SIZE {
+                self.live.fetch_add(1, Ordering::SeqCst);
+            }
+            SystemAllocator.allocate(size)
+        }
+
+        unsafe fn deallocate(&self, buffer: *mut u8, size: usize) {
+            if size == SIZE {
+                self.live.fetch_sub(1, Ordering::SeqCst);
+            }
+            SystemAllocator.deallocate(buffer, size)
+        }
+    }
+
+    let api = Api::simulated();
+    assert!(api.supports(Capability::Allocator));
+    let counting = Arc::new(Counting::default());
+    api.set_allocator(counting.clone())?;
+    let image = Image::with_format(
+        api.clone(),
+        k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+        123,
+        45,
+        0,
+    )?;
+    api.reset_allocator()?;
+    assert_eq!(image.get_size(), SIZE);
+    assert_eq!(counting.live.load(Ordering::SeqCst), 1);
+    let stats = api.allocation_stats();
+    assert!(stats.total_allocations >= 1);
+    assert!(stats.peak_bytes >= image.get_size());
+
+    // The buffer goes back to the allocator it came from after the hook was removed.
+    drop(image);
+    assert_eq!(counting.live.load(Ordering::SeqCst), 0);
+    Ok(())
+}
+
+#[test]
+fn calibration() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api.clone(), 0)?;
+    let depth_mode = k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED;
+    let color_resolution = k4a_color_resolution_t::K4A_COLOR_RESOLUTION_1080P;
+    let calibration = device.get_calibration(depth_mode, color_resolution)?;
+    let from_raw = Calibration::from_raw(
+        api,
+        &device.get_raw_calibration()?,
+        depth_mode,
+        color_resolution,
+    )?;
+    assert_eq!(
+        calibration
+            .calibration
+            .color_camera_calibration
+            .resolution_width,
+        from_raw
+            .calibration
+            .color_camera_calibration
+            .resolution_width
+    );
+
+    let depth = k4a_calibration_type_t::K4A_CALIBRATION_TYPE_DEPTH;
+    let color = k4a_calibration_type_t::K4A_CALIBRATION_TYPE_COLOR;
+    let point = k4a_float2_t {
+        xy: bindings::k4a_float2_t__xy { x: 320.0, y: 288.0 },
+    };
+    let (point3d, valid) = calibration.convert_2d_to_3d(&point, 2000.0, depth, depth)?;
+    assert!(valid);
+    let in_color = calibration.convert_3d_to_3d(&point3d, depth, color)?;
+    let back = calibration.convert_3d_to_3d(&in_color, color, depth)?;
+    let (point2d, valid) = calibration.convert_3d_to_2d(&point3d, depth, depth)?;
+    assert!(valid);
+    unsafe {
+        assert!((back.xyz.x - point3d.xyz.x).abs() < 0.01);
+        assert!((back.xyz.z - point3d.xyz.z).abs() < 0.01);
+        assert!((point2d.xy.x - 320.0).abs() < 0.01);
+        assert!((point2d.xy.y - 288.0).abs() < 0.01);
+    }
+    Ok(())
+}
+
+#[test]
+fn record_and_playback() -> Result<(), Error> {
+    let api = Api::simulated();
+    let api_record = ApiRecord::simulated();
+    let mut device = Device::new(api.clone(), 0)?;
+    let config = k4a_device_configuration_t::default();
+    let path = std::env::temp_dir().join(format!("azure-kinect-sim-{}.mkv", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    {
+        let cameras = device.start_cameras(&config)?;
+        let record = record::Record::new(api_record.clone(), path, &cameras, &config)?;
+        record.add_tag("K4A_TEST_TAG", "value")?;
+        record.add_attachment("notes.txt", b"hello")?;
+        let profile = ColorControlProfile::read(&cameras)?;
+        record.add_attachment(
+            ColorControlProfile::ATTACHMENT_NAME,
+            profile.to_string().as_bytes(),
+        )?;
+        record.write_header()?;
+        for _ in 0..3 {
+            record.write_capture(&cameras.get_capture(1000)?)?;
+        }
+        record.flush()?;
+    }
+
+    let playback = playback::Playback::playback_open(api, api_record, path)?;
+    assert_eq!(playback.get_tag("K4A_TEST_TAG")?, "value");
+    assert_eq!(playback.get_attachment("notes.txt")?, b"hello");
+    assert_eq!(
+        ColorControlProfile::from_playback(&playback)?,
+        ColorControlProfile::read(&device)?
+    );
+    assert_eq!(
+        playback.get_record_configuration()?.depth_mode,
+        config.depth_mode
+    );
+    let report = StreamHealth::scan(&playback)?;
+    assert_eq!(report.depth.count, 3);
+    assert!(report.depth.fps.is_some());
+    let mut count = 0;
+    while let Ok(capture) = playback.get_next_capture() {
+        assert_eq!(capture.get_depth_image().get_width_pixels(), 320);
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    assert!(playback.get_previous_capture().is_ok());
+    drop(playback);
+    std::fs::remove_file(path).ok();
+    Ok(())
+}
+
+#[test]
+fn body_tracking() -> Result<(), Error> {
+    let api = Api::simulated();
+    let api_tracker = ApiTracker::simulated();
+    let mut device = Device::new(api, 0)?;
+    let config = k4a_device_configuration_t::default();
+    let calibration = device.get_calibration(config.depth_mode, config.color_resolution)?;
+    let tracker = Tracker::new(
+        api_tracker,
+        &calibration.calibration,
+        k4abt_tracker_configuration_t::default(),
+    )?;
+    let cameras = device.start_cameras(&config)?;
+    let capture = cameras.get_capture(1000)?;
+    tracker.enqueue_capture(&capture, 1000)?;
+    let frame = tracker.pop_result(1000)?;
+    assert_eq!(tracker.get_num_bodies(&frame), 1);
+    let skeleton = tracker.get_body_skeleton(&frame, 0)?;
+    assert!(unsafe { skeleton.joints[0].position.xyz.z } > 0.0);
+    assert_eq!(
+        tracker.get_device_timestamp_usec(&frame),
+        capture.get_depth_image().get_device_timestamp_usec()
+    );
+    Ok(())
+}
+
+#[test]
+fn detached_device() -> Result<(), Error> {
+    let api = Api::simulated();
+    simulated::attach_device("000000000042");
+    let mut device = Device::open_by_serial(api.clone(), "000000000042")?;
+    let cameras = device.start_cameras(&k4a_device_configuration_t::default())?;
+    assert!(cameras.get_capture(1000).is_ok());
+    assert!(simulated::detach_device("000000000042"));
+    assert!(cameras.get_capture(1000).is_err());
+    assert!(cameras.get_serialnum().is_err());
+    Ok(())
+}
+
+#[test]
+fn supervisor_reconnects() -> Result<(), Error> {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let api = Api::simulated();
+    simulated::attach_device("000000000043");
+    let config = DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .build()?;
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let supervisor = {
+        let events = events.clone();
+        Supervisor::builder(api, "000000000043")
+            .configuration(&config)
+            .imu(true)
+            .retry_interval(Duration::from_millis(20))
+            .on_event(Box::new(move |event| {
+                events.lock().unwrap().push(format!("{:?}", event))
+            }))
+            .start()?
+    };
+    assert!(supervisor.get_capture(1000).is_ok());
+    assert!(supervisor.get_imu_sample(1000).is_ok());
+    supervisor
+        .with_stream(|stream| {
+            stream
+                .device()
+                .set_color_control(ColorControl::Brightness(90))
+        })
+        .unwrap()?;
+    // Give the supervisor time to read the new control back.
+    std::thread::sleep(Duration::from_millis(100));
+
+    assert!(simulated::detach_device("000000000043"));
+    // Once the queued capture is taken, reads time out instead of failing while the device is
+    // away.
+    let error = loop {
+        if let Err(e) = supervisor.get_capture(200) {
+            break e;
+        }
+    };
+    assert!(error.is_timeout());
+    assert!(!supervisor.is_connected());
+    assert!(events.lock().unwrap()[0].starts_with("Disconnected"));
+    // A device missing for a while is reported.
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while events.lock().unwrap().len() < 2 {
+        assert!(std::time::Instant::now() < deadline);
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(events.lock().unwrap()[1].starts_with("ReconnectFailed"));
+    assert!(events.lock().unwrap()[1].contains("DeviceNotFound"));
+
+    simulated::attach_device("000000000043");
+    assert!(supervisor.get_capture(2000).is_ok());
+    assert!(supervisor.get_imu_sample(1000).is_ok());
+    assert_eq!(events.lock().unwrap().last().unwrap(), "Reconnected");
+    let brightness = supervisor.with_stream(|stream| {
+        stream
+            .device()
+            .get_color_control(k4a_color_control_command_t::K4A_COLOR_CONTROL_BRIGHTNESS)
+    });
+    assert_eq!(brightness.unwrap()?, ColorControl::Brightness(90));
+    drop(supervisor);
+    assert!(simulated::detach_device("000000000043"));
+    Ok(())
+}
+
+#[test]
+fn imu_buffer() -> Result<(), Error> {
+    let sample = |timestamp_usec: u64, x: f32| k4a_imu_sample_t {
+        temperature: 30.0,
+        acc_sample: k4a_float3_t { v: [x, 0.0, -9.81] },
+        acc_timestamp_usec: timestamp_usec,
+        gyro_sample: k4a_float3_t {
+            v: [0.0, 2.0 * x, 0.0],
+        },
+        gyro_timestamp_usec: timestamp_usec + 100,
+    };
+    let mut buffer = ImuBuffer::new(3);
+    for (i, &timestamp_usec) in [1000, 2000, 1500, 3000, 4000].iter().enumerate() {
+        buffer.push(sample(timestamp_usec, i as f32));
+    }
+    // The out of order sample is ignored, and the oldest one was replaced.
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.time_range(), Some((2000, 4000)));
+    let between: Vec<_> = buffer
+        .between(2000, 4000)
+        .map(|s| s.acc_timestamp_usec)
+        .collect();
+    assert_eq!(between, vec![2000, 3000]);
+    assert_eq!(buffer.between(5000, 6000).count(), 0);
+
+    let reading = buffer.interpolate(2500).unwrap();
+    assert_eq!(reading.acc, [2.0, 0.0, -9.81]);
+    // The gyroscope was sampled 100 µs later: 2500 is 40% of the way from 2100 to 3100.
+    assert!((reading.gyro[1] - 2.0 * (1.0 + 0.4 * 2.0)).abs() < 1e-5);
+    assert!(buffer.interpolate(1000).is_none());
+    assert!(buffer.interpolate(4050).is_none());
+
+    let api = Api::simulated();
+    let device = Device::new(api, 0)?;
+    let config = DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .build()?;
+    let stream = CaptureStream::start_with_imu(device, &config, 1, OverflowPolicy::DropOldest)?;
+    // The first frame may predate the start of the IMU, but not by a whole frame period.
+    stream.get_capture(1000)?;
+    let capture = stream.get_capture(1000)?;
+    let timestamp_usec = capture.get_depth_image().get_device_timestamp_usec();
+    let reading = stream.imu_at(timestamp_usec, 1000)?;
+    assert_eq!(reading.timestamp_usec, timestamp_usec);
+    assert!((reading.acc[2] + 9.81).abs() < 1e-3);
+    let samples = stream.imu_samples_between(timestamp_usec - 10_000, timestamp_usec, 1000)?;
+    // The simulated IMU samples every 625 µs.
+    assert_eq!(samples.len(), 16);
+    assert!(samples
+        .iter()
+        .all(|s| (timestamp_usec - 10_000..timestamp_usec).contains(&s.acc_timestamp_usec)));
+    assert!(stream.imu_at(0, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn device_group() -> Result<(), Error> {
+    let api = Api::simulated();
+    let serials = ["000000000051", "000000000052", "000000000053"];
+    for serial in &serials {
+        simulated::attach_device(serial);
+    }
+    let open = |serials: &[&str]| {
+        serials
+            .iter()
+            .map(|serial| Device::open_by_serial(api.clone(), serial))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let config = DeviceConfiguration::new()
+        .color(
+            k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
+            k4a_color_resolution_t::K4A_COLOR_RESOLUTION_720P,
+        )
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED);
+
+    // Without cables there is no master.
+    let error = DeviceGroup::start(open(&serials)?, config, 1_000)
+        .err()
+        .unwrap();
+    assert!(matches!(error.kind(), ErrorKind::InvalidConfiguration(_)));
+
+    // A daisy chain: 53 drives 52, which drives 51.
+    simulated::set_sync_jacks("000000000051", true, false);
+    simulated::set_sync_jacks("000000000052", true, true);
+    simulated::set_sync_jacks("000000000053", false, true);
+    let mut group = DeviceGroup::start(open(&serials)?, config, 1_000)?;
+    assert_eq!(group.len(), 3);
+    assert_eq!(group.device(0).unwrap().get_serialnum()?, "000000000053");
+    assert_eq!(
+        group.configuration(0).unwrap().wired_sync_mode,
+        k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_MASTER
+    );
+    let delays: Vec<_> = (0..3)
+        .map(|i| {
+            let configuration = group.configuration(i).unwrap();
+            (
+                configuration.subordinate_delay_off_master_usec,
+                configuration.depth_delay_off_color_usec,
+            )
+        })
+        .collect();
+    assert_eq!(delays, vec![(0, 0), (160, 160), (320, 320)]);
+    // Each device has its own delays, and so its own depth exposure.
+    for (i, a) in delays.iter().enumerate() {
+        for b in &delays[i + 1..] {
+            assert_ne!(a.0, b.0);
+            assert_ne!(a.1, b.1);
+            assert_ne!(a.0 as i64 + a.1 as i64, b.0 as i64 + b.1 as i64);
+        }
+    }
+
+    for _ in 0..3 {
+        let captures = group.get_capture_set(1000)?;
+        assert_eq!(captures.len(), 3);
+        let timestamps: Vec<_> = captures
+            .iter()
+            .map(|c| c.get_color_image().get_device_timestamp_usec())
+            .collect();
+        // The subordinates capture the same frame, their delay later.
+        assert_eq!(timestamps[1], timestamps[0] + 160);
+        assert_eq!(timestamps[2], timestamps[0] + 320);
+        // And their depth frames another delay later, so no two lasers overlap.
+        let depth_timestamps: Vec<_> = captures
+            .iter()
+            .map(|c| c.get_depth_image().get_device_timestamp_usec())
+            .collect();
+        assert_eq!(depth_timestamps[1], depth_timestamps[0] + 320);
+        assert_eq!(depth_timestamps[2], depth_timestamps[0] + 640);
+    }
+    let devices = group.stop();
+    assert_eq!(devices.len(), 3);
+    drop(devices);
+
+    // Two masters.
+    simulated::set_sync_jacks("000000000051", false, true);
+    let error = DeviceGroup::start(open(&serials)?, config, 1_000)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("both drive sync out"));
+    for serial in &serials {
+        assert!(simulated::detach_device(serial));
+    }
+    Ok(())
+}
+
+#[test]
+fn stream_health() -> Result<(), Error> {
+    let api = Api::simulated();
+    let device = Device::new(api.clone(), 0)?;
+    let config = DeviceConfiguration::new()
+        .depth(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED)
+        .fps(k4a_fps_t::K4A_FRAMES_PER_SECOND_30)
+        .build()?;
+    let stream = CaptureStream::start_with_imu(device, &config, 1, OverflowPolicy::DropOldest)?;
+    // The frames come in real time, so only the synthetic series below test the rate.
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    let report = loop {
+        let report = stream.health();
+        if report.depth.count >= 5 && report.imu.count > 100 {
+            break report;
+        }
+        assert!(std::time::Instant::now() < deadline);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(report.depth.count, report.ir.count);
+    assert_eq!(report.color.count, 0);
+    assert!(report.depth.fps.is_some());
+    assert!(report.imu.latency_usec.is_none());
+    assert!(report.depth.latency_usec.is_some());
+    assert!(report.color_to_depth_usec.is_none());
+    drop(stream);
+
+    let depth_health = |timestamps_usec: &[u64]| -> Result<TimingReport, Error> {
+        let mut health = StreamHealth::new(k4a_fps_t::K4A_FRAMES_PER_SECOND_30);
+        for &timestamp_usec in timestamps_usec {
+            let mut capture = Capture::new(api.clone())?;
+            let mut image = Image::with_format(
+                api.clone(),
+                k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+                4,
+                4,
+                8,
+            )?;
+            image.set_device_timestamp_usec(timestamp_usec);
+            capture.set_depth_image(image);
+            health.add_capture(&capture);
+        }
+        Ok(health.report().depth)
+    };
+
+    // Every other frame 1 ms late: the rate holds, and the intervals are 1 ms either side.
+    let jittered: Vec<u64> = (0..11).map(|i| i * 33_333 + i % 2 * 1_000).collect();
+    let depth = depth_health(&jittered)?;
+    assert_eq!(depth.count, 11);
+    assert_eq!(depth.missed, 0);
+    assert!((depth.fps.unwrap() - 30.0).abs() < 0.01);
+    let interval = depth.interval_usec.unwrap();
+    assert_eq!((interval.min, interval.max), (32_333.0, 34_333.0));
+    assert!((interval.mean - 33_333.0).abs() < 1e-6);
+    assert!((interval.std_dev - 1_000.0).abs() < 1e-6);
+    assert_eq!(depth.latency_usec, None);
+
+    // Frames missing from a series of timestamps are counted against the frame rate.
+    let depth = depth_health(&[0, 33_333, 66_666, 166_666, 200_000])?;
+    assert_eq!(depth.count, 5);
+    assert_eq!(depth.missed, 2);
+    assert_eq!(depth.interval_usec.unwrap().max, 100_000.0);
+    assert_eq!(depth.fps, Some(20.0));
+    Ok(())
+}
+
+#[test]
+fn firmware() -> Result<(), Error> {
+    let device = Device::new(Api::simulated(), 0)?;
+    let firmware = device.get_firmware()?;
+    assert_eq!(firmware.rgb, FirmwareVersion::new(1, 6, 110));
+    assert_eq!(firmware.build, FirmwareBuild::Release);
+    let current = FirmwareRequirement::new()
+        .at_least(FirmwareSubsystem::Rgb, "1.6.110".parse()?)
+        .release_build(true)
+        .microsoft_signed(true);
+    assert_eq!(device.check_firmware(&current)?, firmware);
+
+    let newer: FirmwareRequirement = "depth=1.6.80,audio=1.7.0".parse()?;
+    let error = device.check_firmware(&newer).err().unwrap();
+    assert!(matches!(error.kind(), ErrorKind::UnsupportedFirmware(_)));
+    assert!(error
+        .to_string()
+        .contains("depth firmware 1.6.79 is older than 1.6.80"));
+    assert!(error.to_string().contains("audio firmware"));
+    Ok(())
+}
+
+#[test]
+fn image_views() -> Result<(), Error> {
+    let api = Api::simulated();
+    // Rows of 3 pixels padded to 4.
+    let mut image = Image::with_format(
+        api.clone(),
+        k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+        3,
+        2,
+        8,
+    )?;
+    {
+        let mut view = image.as_depth16_mut()?;
+        for (y, row) in view.rows_mut().enumerate() {
+            for (x, mm) in row.iter_mut().enumerate() {
+                *mm = (10 * y + x) as u16;
+            }
+        }
+        view[(2, 1)] = 1000;
+        assert!(view.get_mut(3, 0).is_none());
+    }
+    let view = image.as_depth16()?;
+    assert_eq!((view.width(), view.height()), (3, 2));
+    assert_eq!(view.row(0), &[0, 1, 2]);
+    assert_eq!(view.row(1), &[10, 11, 1000]);
+    assert_eq!(view[(1, 1)], 11);
+    assert_eq!(view.get(0, 2), None);
+    let error = image.as_bgra32().err().unwrap();
+    assert!(matches!(error.kind(), ErrorKind::InvalidImage(_)));
+
+    // A clone shares the buffer, so neither can be written to while both are alive.
+    let mut clone = image.clone();
+    assert!(clone.as_depth16_mut().is_err());
+    drop(image);
+    assert_eq!(clone.as_depth16_mut()?[(2, 1)], 1000);
+
+    // Images of a capture may be shared with other `Image`s.
+    let mut capture = Capture::new(api.clone())?;
+    capture.set_depth_image(clone);
+    let mut depth = capture.get_depth_image();
+    assert_eq!(depth.as_depth16()?.row(1), &[10, 11, 1000]);
+    assert!(depth.as_depth16_mut().is_err());
+    assert!(capture.get_ir_image().as_ir16().is_err());
+
+    // Neither can the image a clone of was set on a capture, though the clone is gone.
+    let mut image = Image::with_format(
+        api.clone(),
+        k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+        3,
+        2,
+        6,
+    )?;
+    let mut capture = Capture::new(api)?;
+    capture.set_depth_image(image.clone());
+    let depth = capture.get_depth_image();
+    let view = depth.as_depth16()?;
+    assert!(image.as_depth16_mut().is_err());
+    assert_eq!(view.width(), 3);
+    Ok(())
+}
+
+#[test]
+fn owned_image_buffer() -> Result<(), Error> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    struct Tracked(Vec<u16>, Arc<AtomicBool>);
+
+    impl AsMut<[u16]> for Tracked {
+        fn as_mut(&mut self) -> &mut [u16] {
+            &mut self.0
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            assert!(!self.1.swap(true, Ordering::SeqCst), "dropped twice");
+        }
+    }
+
+    let api = Api::simulated();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let pixels = (0..6).collect::<Vec<u16>>();
+    let mut image = Image::with_owned_buffer(
+        api.clone(),
+        k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+        3,
+        2,
+        6,
+        Tracked(pixels, dropped.clone()),
+    )?;
+    image.as_depth16_mut()?[(0, 1)] = 30;
+    let mut capture = Capture::new(api.clone())?;
+    capture.set_depth_image(image);
+    assert!(!dropped.load(Ordering::SeqCst));
+    let depth = capture.get_depth_image();
+    assert_eq!(depth.as_depth16()?.row(1), &[30, 4, 5]);
+    drop(capture);
+    assert!(!dropped.load(Ordering::SeqCst));
+    drop(depth);
+    assert!(dropped.load(Ordering::SeqCst));
+
+    let bgra = Image::with_owned_buffer(
+        api.clone(),
+        k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32,
+        2,
+        1,
+        8,
+        vec![1u8, 2, 3, 4, 5, 6, 7, 8],
+    )?;
+    assert_eq!(bgra.as_bgra32()?[(1, 0)], [5, 6, 7, 8]);
+
+    // A buffer too small for the image is given back.
+    let dropped = Arc::new(AtomicBool::new(false));
+    let error = Image::with_owned_buffer(
+        api,
+        k4a_image_format_t::K4A_IMAGE_FORMAT_DEPTH16,
+        3,
+        2,
+        6,
+        Tracked(vec![0; 5], dropped.clone()),
+    );
+    assert!(error.is_err());
+    assert!(dropped.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn convert_color_images() -> Result<(), Error> {
+    use k4a_image_format_t::*;
+
+    let api = Api::simulated();
+    let near = |actual: [u8; 4], expected: [u8; 4], tolerance: u8| {
+        actual
+            .iter()
+            .zip(&expected)
+            .all(|(&a, &e)| a.max(e) - a.min(e) <= tolerance)
+    };
+    // Black, white and two pixels of red in the studio range of BT.601, on two rows.
+    let nv12: Vec<u8> = vec![
+        16, 235, 81, 81, //
+        16, 235, 81, 81, //
+        128, 128, 90, 240,
+    ];
+    let yuy2: Vec<u8> = vec![
+        16, 128, 235, 128, 81, 90, 81, 240, //
+        16, 128, 235, 128, 81, 90, 81, 240,
+    ];
+    for (format, stride, buffer) in vec![
+        (K4A_IMAGE_FORMAT_COLOR_NV12, 4, nv12),
+        (K4A_IMAGE_FORMAT_COLOR_YUY2, 8, yuy2),
+    ] {
+        let mut image = Image::with_owned_buffer(api.clone(), format, 4, 2, stride, buffer)?;
+        image.set_device_timestamp_usec(1234);
+        let bgra = image.convert_to(K4A_IMAGE_FORMAT_COLOR_BGRA32)?;
+        assert_eq!(bgra.get_format(), K4A_IMAGE_FORMAT_COLOR_BGRA32);
+        assert_eq!(bgra.get_device_timestamp_usec(), 1234);
+        let view = bgra.as_bgra32()?;
+        for y in 0..2 {
+            assert_eq!(view[(0, y)], [0, 0, 0, 255]);
+            assert_eq!(view[(1, y)], [255, 255, 255, 255]);
+            assert!(
+                near(view[(2, y)], [0, 0, 255, 255], 2),
+                "{:?}",
+                view[(2, y)]
+            );
+            assert_eq!(view[(3, y)], view[(2, y)]);
+        }
+        let parallel = image.convert_to_parallel(K4A_IMAGE_FORMAT_COLOR_BGRA32, 3)?;
+        assert!(parallel.as_bgra32()?.rows().eq(view.rows()));
+        let error = image.convert_to(K4A_IMAGE_FORMAT_DEPTH16).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::InvalidImage(_)));
+    }
+
+    // The top left corner of the test pattern is a bar of 40 or 200 in each channel.
+    simulated::attach_device("000000000025");
+    let mut formats = vec![K4A_IMAGE_FORMAT_COLOR_NV12, K4A_IMAGE_FORMAT_COLOR_YUY2];
+    if cfg!(feature = "mjpg") {
+        formats.push(K4A_IMAGE_FORMAT_COLOR_MJPG);
+    }
+    for format in formats {
+        let mut device = Device::open_by_serial(api.clone(), "000000000025")?;
+        let config = DeviceConfiguration::new()
+            .color(format, k4a_color_resolution_t::K4A_COLOR_RESOLUTION_720P)
+            .build()?;
+        let cameras = device.start_cameras(&config)?;
+        let color = cameras.get_capture(1000)?.get_color_image();
+        let bgra = color.convert_to_parallel(K4A_IMAGE_FORMAT_COLOR_BGRA32, 4)?;
+        let view = bgra.as_bgra32()?;
+        assert_eq!((view.width(), view.height()), (1280, 720));
+        let [b, g, r, a] = view[(0, 0)];
+        for channel in [b, g, r] {
+            assert!(
+                channel.max(40) - channel.min(40) <= 6 || channel.max(200) - channel.min(200) <= 6,
+                "{:?}: {:?}",
+                format,
+                view[(0, 0)]
+            );
+        }
+        assert_eq!(a, 255);
+    }
+    assert!(simulated::detach_device("000000000025"));
+    Ok(())
+}
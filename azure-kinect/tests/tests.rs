@@ -10,7 +10,7 @@ fn test_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let factory = Factory::with_library_directory(
         std::env::current_dir()?
             .to_str()
-            .ok_or(error::Error::Failed)?,
+            .ok_or(Error::new(ErrorKind::Failed))?,
     )?
     .set_debug_message_handler(
         Box::new(|level, file, line, message| {
@@ -21,7 +21,7 @@ fn test_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     let c = factory.device_get_installed_count();
     println!("device count = {}", c);
-    let device = factory.device_open(0)?;
+    let mut device = factory.device_open(0)?;
     let serial = device.get_serialnum()?;
     let version = device.get_version()?;
     println!("serial = {} / hw ver = {:?}", serial, version);
@@ -31,7 +31,7 @@ fn test_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     println!("color control(brightness) = {:?}", color_control);
 
     let camera_config = k4a_device_configuration_t::default();
-    let camera = device.start_cameras(&camera_config)?;
+    let mut camera = device.start_cameras(&camera_config)?;
 
     {
         let imu = camera.start_imu()?;
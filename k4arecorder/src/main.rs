@@ -2,7 +2,7 @@ mod param;
 mod recorder;
 use crate::recorder::do_recording;
 use azure_kinect::*;
-use std::sync::atomic;
+use std::sync::{atomic, Arc};
 
 fn main() {
     std::process::exit(match main2() {
@@ -17,9 +17,9 @@ fn main() {
 fn main2() -> Result<(), Box<dyn std::error::Error>> {
     let param = param::Parameter::new()?;
 
-    let factory = FactoryRecord::new()?;
+    let api = Api::new()?;
     if param.list_device {
-        list_devices(&factory);
+        list_devices(&api);
     } else {
         let request_abort1 = std::sync::Arc::new(atomic::AtomicBool::new(false));
         let request_abort2 = request_abort1.clone();
@@ -28,7 +28,8 @@ fn main2() -> Result<(), Box<dyn std::error::Error>> {
         })
         .expect("Error setting Ctrl-C handler");
 
-        do_recording(&factory, &param, || -> bool {
+        let api_record = ApiRecord::new()?;
+        do_recording(api, api_record, &param, || -> bool {
             request_abort1.load(atomic::Ordering::SeqCst)
         })?;
     }
@@ -36,22 +37,18 @@ fn main2() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn list_devices(factory: &FactoryRecord) {
-    let device_count = factory.device_get_installed_count();
-    if device_count > 0 {
-        for i in 0..device_count {
+fn list_devices(api: &Arc<Api>) {
+    let devices = Device::enumerate(api);
+    if !devices.is_empty() {
+        for (i, device) in devices.iter().enumerate() {
             println!("Index:{}", i);
-            if let Ok(device) = factory.device_open(i) {
-                match device.get_serialnum() {
-                    Ok(s) => println!("\tSerial:{}", s),
-                    Err(_) => println!("\tSerial:ERROR"),
-                };
-                if let Ok(version) = device.get_version() {
-                    println!("\tColor:{}", version.rgb);
-                    println!("\tDepth:{}", version.depth);
+            match device {
+                Ok(info) => {
+                    println!("\tSerial:{}", info.serial);
+                    println!("\tColor:{}", info.version.rgb);
+                    println!("\tDepth:{}", info.version.depth);
                 }
-            } else {
-                println!("{}\tDevice Open Failed", i);
+                Err(_) => println!("{}\tDevice Open Failed", i),
             }
         }
     } else {
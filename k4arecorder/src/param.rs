@@ -1,4 +1,3 @@
-use crate::recorder::Error;
 use azure_kinect::*;
 use clap::{App, Arg, ArgMatches};
 use std::time::Duration;
@@ -12,11 +11,11 @@ pub struct Parameter {
     pub record_imu: bool,
     pub absolute_exposure_value: Option<i32>,
     pub gain: Option<i32>,
+    pub min_firmware: FirmwareRequirement,
 }
 
 impl Parameter {
-    pub fn new<'a>() -> Result<Parameter, Error<'a>>
-    {
+    pub fn new() -> Result<Parameter, String> {
         let p = Parameter::from(create_app().get_matches());
 
         if let Ok(r) = p.as_ref() {
@@ -29,7 +28,7 @@ impl Parameter {
         p
     }
 
-    fn from<'a, 'b>(args: ArgMatches<'a>) -> Result<Parameter, Error<'b>> {
+    fn from(args: ArgMatches) -> Result<Parameter, String> {
         let format_resolution = to_format_and_resolution(args.value_of("color-mode").unwrap())?;
         let param = Parameter {
             list_device: args.is_present("list"),
@@ -38,7 +37,7 @@ impl Parameter {
             recording_length: correct_param::<u64, _, _>(args.value_of("record-length"), |value| {
                 Duration::from_secs(std::cmp::max(0, value))
             }),
-            device_config: k4a_device_configuration_t {
+            device_config: DeviceConfiguration::from(k4a_device_configuration_t {
                 color_format: format_resolution.0,
                 color_resolution: format_resolution.1,
                 depth_mode: to_depth_mode(args.value_of("depth-mode").unwrap())?,
@@ -56,7 +55,9 @@ impl Parameter {
                     .parse()
                     .unwrap_or(0),
                 disable_streaming_indicator: false,
-            },
+            })
+            .build()
+            .map_err(|e| e.to_string())?,
             record_imu: args
                 .value_of("imu")
                 .unwrap_or("ON")
@@ -67,27 +68,13 @@ impl Parameter {
                 200000,
             ),
             gain: correct_param_range(args.value_of("gain"), 0, 255),
+            min_firmware: args
+                .value_of("min-firmware")
+                .unwrap_or("")
+                .parse()
+                .map_err(|e: Error| e.to_string())?,
         };
 
-        if param.device_config.camera_fps == k4a_fps_t::K4A_FRAMES_PER_SECOND_30
-            && (param.device_config.depth_mode == k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_UNBINNED
-                || param.device_config.color_resolution
-                    == k4a_color_resolution_t::K4A_COLOR_RESOLUTION_3072P)
-        {
-            return Err(Error::ErrorStr(
-                "Error: 30 Frames per second is not supported by this camera mode.",
-            ));
-        }
-
-        if param.device_config.subordinate_delay_off_master_usec > 0
-            && param.device_config.wired_sync_mode
-                != k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE
-        {
-            return Err(Error::ErrorStr(
-                "--sync-delay is only valid if --external-sync is set to Subordinate.",
-            ));
-        }
-
         Ok(param)
     }
 }
@@ -146,6 +133,10 @@ fn create_app<'a, 'b>() -> App<'a, 'b> {
             .long("gain")
             .short("g")
             .help("Set cameras manual gain. The valid range is 0 to 255. (default: auto)"))
+        .arg(Arg::with_name("min-firmware")
+            .long("min-firmware")
+            .takes_value(true)
+            .help("Refuse to record unless the device firmware is at least the given versions, such as\n'rgb=1.6.110,depth=1.6.79,depth_sensor=6109.7.0,audio=1.6.14'. Add 'release' or 'signed' to\nrequire production or Microsoft signed firmware."))
         .arg(Arg::with_name("OUTPUT")
             .help("Sets the output file"))
 }
@@ -171,9 +162,9 @@ fn correct_param_range<T: Ord + core::str::FromStr + Copy + Clone>(
     correct_param(value, |value| std::cmp::max(min, std::cmp::min(max, value)))
 }
 
-fn to_format_and_resolution<'a>(
+fn to_format_and_resolution(
     value: &str,
-) -> Result<(k4a_image_format_t, k4a_color_resolution_t), Error<'a>> {
+) -> Result<(k4a_image_format_t, k4a_color_resolution_t), String> {
     match value.to_ascii_lowercase().as_str() {
         "3072p" => Ok((
             k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
@@ -211,14 +202,11 @@ fn to_format_and_resolution<'a>(
             k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_MJPG,
             k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF,
         )),
-        _ => Err(Error::Error(format!(
-            "Unknown color mode specified: {}",
-            value
-        ))),
+        _ => Err(format!("Unknown color mode specified: {}", value)),
     }
 }
 
-fn to_depth_mode<'a>(value: &str) -> Result<k4a_depth_mode_t, Error<'a>> {
+fn to_depth_mode(value: &str) -> Result<k4a_depth_mode_t, String> {
     match value.to_ascii_uppercase().as_str() {
         "NFOV_2X2BINNED" => Ok(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_2X2BINNED),
         "NFOV_UNBINNED" => Ok(k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED),
@@ -226,46 +214,34 @@ fn to_depth_mode<'a>(value: &str) -> Result<k4a_depth_mode_t, Error<'a>> {
         "WFOV_UNBINNED" => Ok(k4a_depth_mode_t::K4A_DEPTH_MODE_WFOV_UNBINNED),
         "PASSIVE_IR" => Ok(k4a_depth_mode_t::K4A_DEPTH_MODE_PASSIVE_IR),
         "OFF" => Ok(k4a_depth_mode_t::K4A_DEPTH_MODE_OFF),
-        _ => Err(Error::Error(format!(
-            "Unknown depth mode specified: {}",
-            value
-        ))),
+        _ => Err(format!("Unknown depth mode specified: {}", value)),
     }
 }
 
-fn to_frame_rate<'a>(value: &str) -> Result<k4a_fps_t, Error<'a>> {
+fn to_frame_rate(value: &str) -> Result<k4a_fps_t, String> {
     match value {
         "30" => Ok(k4a_fps_t::K4A_FRAMES_PER_SECOND_30),
         "15" => Ok(k4a_fps_t::K4A_FRAMES_PER_SECOND_15),
         "5" => Ok(k4a_fps_t::K4A_FRAMES_PER_SECOND_5),
-        _ => Err(Error::Error(format!(
-            "Unknown frame rate specified: {}",
-            value
-        ))),
+        _ => Err(format!("Unknown frame rate specified: {}", value)),
     }
 }
 
-fn to_imu_mode<'a>(value: &str) -> Result<bool, Error<'a>> {
+fn to_imu_mode(value: &str) -> Result<bool, String> {
     match value.to_ascii_uppercase().as_str() {
         "ON" => Ok(true),
         "OFF" => Ok(false),
-        _ => Err(Error::Error(format!(
-            "Unknown imu mode specified: {}",
-            value
-        ))),
+        _ => Err(format!("Unknown imu mode specified: {}", value)),
     }
 }
 
-fn to_external_sync<'a>(value: &str) -> Result<k4a_wired_sync_mode_t, Error<'a>> {
+fn to_external_sync(value: &str) -> Result<k4a_wired_sync_mode_t, String> {
     match value.to_ascii_lowercase().as_str() {
         "master" => Ok(k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_MASTER),
         "subordinate" => Ok(k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE),
         "sub" => Ok(k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_SUBORDINATE),
         "standalone" => Ok(k4a_wired_sync_mode_t::K4A_WIRED_SYNC_MODE_STANDALONE),
-        _ => Err(Error::Error(format!(
-            "Unknown external sync mode specified: {}",
-            value
-        ))),
+        _ => Err(format!("Unknown external sync mode specified: {}", value)),
     }
 }
 
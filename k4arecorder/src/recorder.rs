@@ -1,28 +1,8 @@
 use crate::param::Parameter;
 use azure_kinect::*;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-#[derive(Debug)]
-pub enum Error<'a> {
-    ErrorStr(&'a str),
-    Error(String),
-}
-
-impl std::error::Error for Error<'_> {}
-
-impl std::fmt::Display for Error<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Error::ErrorStr(s) => s,
-                Error::Error(s) => s.as_str(),
-            }
-        )
-    }
-}
-
 struct Processing {
     timer: Instant,
     requested_abort: bool,
@@ -52,23 +32,17 @@ impl Processing {
 }
 
 pub(crate) fn do_recording<F: Fn() -> bool>(
-    factory: &FactoryRecord,
+    api: Arc<Api>,
+    api_record: Arc<ApiRecord>,
     param: &Parameter,
     request_abort: F,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let installed_devices = factory.device_get_installed_count();
+    let installed_devices = api.device_get_installed_count();
     if param.device_index >= installed_devices {
-        return Err(Box::new(Error::ErrorStr("Device not found.")));
+        return Err("Device not found.".into());
     }
 
-    let device = match factory.device_open(param.device_index) {
-        Ok(device) => device,
-        Err(_) => {
-            return Err(Box::new(Error::ErrorStr(
-                "Runtime error: k4a_device_open() failed ",
-            )))
-        }
-    };
+    let mut device = Device::new(api, param.device_index)?;
 
     let serial_number = device.get_serialnum()?;
     println!("Device serial number: {}", serial_number);
@@ -87,76 +61,45 @@ pub(crate) fn do_recording<F: Fn() -> bool>(
         version_info.depth, version_info.depth_sensor.major, version_info.depth_sensor.minor
     );
     println!("; A: {}", version_info.audio);
+    device.check_firmware(&param.min_firmware)?;
 
+    // `param.device_config` was checked by `DeviceConfiguration`.
     let camera_fps = param.device_config.camera_fps.get_u32();
-    if camera_fps <= 0
-        || (param.device_config.color_resolution
-            == k4a_color_resolution_t::K4A_COLOR_RESOLUTION_OFF
-            && param.device_config.depth_mode == k4a_depth_mode_t::K4A_DEPTH_MODE_OFF)
-    {
-        return Err(Box::new(Error::ErrorStr(
-            "Either the color or depth modes must be enabled to record.",
-        )));
-    }
 
-    if let Some(absolute_exposure_value) = param.absolute_exposure_value {
-        if let Err(_) = device.set_color_control(
-            k4a_color_control_command_t::K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE,
-            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL,
-            absolute_exposure_value,
-        ) {
-            eprintln!("Runtime error: k4a_device_set_color_control() for manual exposure failed ");
-        }
-    } else {
-        if let Err(_) = device.set_color_control(
-            k4a_color_control_command_t::K4A_COLOR_CONTROL_EXPOSURE_TIME_ABSOLUTE,
-            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO,
-            0,
-        ) {
-            eprintln!("Runtime error: k4a_device_set_color_control() for auto exposure failed ");
-        }
+    let exposure = match param.absolute_exposure_value {
+        Some(usec) => ControlMode::Manual(Duration::from_micros(usec as u64)),
+        None => ControlMode::Auto,
+    };
+    if let Err(e) = device.set_color_control(ColorControl::Exposure(exposure)) {
+        eprintln!("Runtime error: {}", e);
     }
 
+    // Gain has no automatic mode, so it is left alone unless given.
     if let Some(gain) = param.gain {
-        if let Err(_) = device.set_color_control(
-            k4a_color_control_command_t::K4A_COLOR_CONTROL_GAIN,
-            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_MANUAL,
-            gain,
-        ) {
-            eprintln!("Runtime error: k4a_device_set_color_control() for manual gain failed ");
-        }
-    } else {
-        if let Err(_) = device.set_color_control(
-            k4a_color_control_command_t::K4A_COLOR_CONTROL_GAIN,
-            k4a_color_control_mode_t::K4A_COLOR_CONTROL_MODE_AUTO,
-            0,
-        ) {
-            eprintln!("Runtime error: k4a_device_set_color_control() for auto gain failed ");
+        if let Err(e) = device.set_color_control(ColorControl::Gain(gain)) {
+            eprintln!("Runtime error: {}", e);
         }
     }
 
-    let camera = device.start_cameras(&param.device_config)?;
-    let imu = if param.record_imu {
-        Option::Some(camera.start_imu()?)
+    let mut cameras = device.start_cameras(&param.device_config)?;
+    let imu;
+    // Captures are read through the IMU session while it runs.
+    let camera: &CameraSession = if param.record_imu {
+        imu = Some(cameras.start_imu()?);
+        imu.as_ref().unwrap()
     } else {
-        Option::None
+        imu = None;
+        &cameras
     };
 
     println!("Device started");
 
-    let recording = match factory.record_create(
+    let recording = record::Record::new(
+        api_record,
         param.recording_filename.as_str(),
-        &device,
+        camera,
         &param.device_config,
-    ) {
-        Ok(recording) => recording,
-        Err(_) => {
-            return Err(Box::new(Error::Error(format!(
-                "Unable to create recording file: {}",
-                param.recording_filename
-            ))))
-        }
-    };
+    )?;
 
     if imu.is_some() {
         recording.add_imu_track()?;
@@ -177,13 +120,8 @@ pub(crate) fn do_recording<F: Fn() -> bool>(
     let mut first_captured = false;
     while first_capture.is_processing() && !request_abort() {
         match camera.get_capture(100) {
-            Err(azure_kinect::Error::Timeout) => continue,
-            Err(e) => {
-                return Err(Box::new(Error::Error(format!(
-                    "Runtime error: k4a_device_get_capture() returned error: {}",
-                    e
-                ))))
-            }
+            Err(e) if e.is_timeout() => continue,
+            Err(e) => return Err(e.into()),
             _ => (),
         };
         first_captured = true;
@@ -193,9 +131,7 @@ pub(crate) fn do_recording<F: Fn() -> bool>(
     if request_abort() {
         return Ok(());
     } else if !first_captured {
-        return Err(Box::new(Error::ErrorStr(
-            "Timed out waiting for first capture.",
-        )));
+        return Err("Timed out waiting for first capture.".into());
     }
 
     println!("Started recording");
@@ -209,47 +145,21 @@ pub(crate) fn do_recording<F: Fn() -> bool>(
     while recording_process.is_processing() && !request_abort() {
         let capture = match camera.get_capture(camera_timeout_ms as i32) {
             Ok(c) => c,
-            Err(azure_kinect::Error::Timeout) => continue,
-            Err(e) => {
-                return Err(Box::new(Error::Error(format!(
-                    "Runtime error: k4a_device_get_capture() returned {}",
-                    e
-                ))))
-            }
+            Err(e) if e.is_timeout() => continue,
+            Err(e) => return Err(e.into()),
         };
 
-        match recording.write_capture(&capture) {
-            Err(e) => {
-                return Err(Box::new(Error::Error(format!(
-                    "Runtime error: k4a_record_write_imu_sample() returned {}",
-                    e
-                ))))
-            }
-            _ => (),
-        };
+        recording.write_capture(&capture)?;
 
-        if imu.is_some() {
+        if let Some(imu) = &imu {
             while recording_process.is_processing() && !request_abort() {
-                let sample = match imu.as_ref().unwrap().get_imu_sample(0) {
+                let sample = match imu.get_imu_sample(0) {
                     Ok(s) => s,
-                    Err(azure_kinect::Error::Timeout) => break,
-                    Err(e) => {
-                        return Err(Box::new(Error::Error(format!(
-                            "Runtime error: k4a_imu_get_sample() returned {}",
-                            e
-                        ))))
-                    }
+                    Err(e) if e.is_timeout() => break,
+                    Err(e) => return Err(e.into()),
                 };
 
-                match recording.write_imu_sample(sample) {
-                    Err(e) => {
-                        return Err(Box::new(Error::Error(format!(
-                            "Runtime error: k4a_record_write_imu_sample() returned {}",
-                            e
-                        ))))
-                    }
-                    _ => (),
-                };
+                recording.write_imu_sample(sample)?;
             }
         }
     }
@@ -259,7 +169,7 @@ pub(crate) fn do_recording<F: Fn() -> bool>(
     }
 
     std::mem::drop(imu);
-    std::mem::drop(camera);
+    std::mem::drop(cameras);
 
     println!("Saving recording...");
     recording.flush()?;
@@ -5,7 +5,7 @@ use nannou::prelude::*;
 use azure_kinect::*;
 
 pub struct Kinect {
-    device: Device,
+    stream: CaptureStream,
     tracker: Tracker,
 }
 
@@ -16,8 +16,6 @@ impl Kinect {
             depth_mode: k4a_depth_mode_t::K4A_DEPTH_MODE_NFOV_UNBINNED,
             ..k4a_device_configuration_t::default()
         };
-        device.start_cameras(&camera_config)?;
-
         let sensor_calibration = device
         .get_calibration(
             camera_config.depth_mode,
@@ -33,21 +31,24 @@ impl Kinect {
         )
         .expect("Body tracker initialization failed!");
 
+        // Captures are read on a thread of their own, and the frame loop only takes the latest one.
+        let stream = CaptureStream::start(device, &camera_config, 1, OverflowPolicy::DropOldest)?;
+
         Ok( Kinect {
-            device,
+            stream,
             tracker,
         })
     }
 
     pub fn draw_skeleton(&self, draw: &Draw, rect: Rect) {
-        if let Ok(capture) = self.device.get_capture(0) {
+        if let Ok(capture) = self.stream.get_capture(0) {
             match self.tracker.enqueue_capture(capture.handle, K4A_WAIT_INFINITE) {
                 Ok(_) => (),
-                Err(err) => match azure_kinect::Error::from(err) {
-                    Error::Timeout => {
+                Err(err) => match err.kind() {
+                    ErrorKind::Timeout => {
                         println!("Error! Add capture to tracker process queue timeout!")
                     }
-                    Error::Failed => {
+                    ErrorKind::Failed => {
                         println!("Error! Add capture to tracker process queue failed!")
                     }
                     _ => println!("an unexpected error occured"),
@@ -1,12 +1,11 @@
 use std::sync::Arc;
 use nannou::image::{Bgra, Luma, ImageBuffer};
 use nannou::prelude::*;
-use byteorder::{ByteOrder, LittleEndian}; 
 
 use azure_kinect::*;
 
 pub struct Kinect {
-    device: Device,
+    stream: CaptureStream,
     pub colour_texture: Option<wgpu::Texture>,
     pub depth_dimension: Vec2,
     pub depth_range: Vec2,
@@ -35,7 +34,8 @@ impl Kinect {
             synchronized_images_only: true,
             ..k4a_device_configuration_t::default()
         };
-        device.start_cameras(&camera_config)?;
+        // Captures are read on a thread of their own, and the frame loop only takes the latest one.
+        let stream = CaptureStream::start(device, &camera_config, 1, OverflowPolicy::DropOldest)?;
 
         let r = camera_config.depth_mode.get_range();
         let depth_range = vec2(r.min as f32, r.max as f32);
@@ -77,7 +77,7 @@ impl Kinect {
         };
 
         Ok( Kinect {
-            device,
+            stream,
             colour_texture: None,
             depth_dimension,
             depth_range,
@@ -138,18 +138,14 @@ impl Kinect {
     pub fn update(&mut self, wgpu_device: &wgpu::Device, queue: &wgpu::Queue) {
         //let texture_usage = wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT;
         let texture_usage = wgpu::TextureUsages::TEXTURE_BINDING;
-        if let Ok(capture) = self.device.get_capture(0) {
-            let colour_image = capture.get_color_image();
-            let _format = colour_image.get_format();
-            let width = colour_image.get_width_pixels();
-            let height = colour_image.get_height_pixels();
-            let len = colour_image.get_size();
-            let _pitch = width as usize * 4;
-
-            if width > 0 && height > 0 {
-                //println!("format = {:?} - width = {} - height = {} - len = {} - pitch = {}", format, width, height, len, pitch);
-                let slice = unsafe { std::slice::from_raw_parts(colour_image.get_buffer(), len) };
-                let colour_image_buffer: ImageBuffer<Bgra<u8>, _> = ImageBuffer::from_raw(width as u32, height as u32, slice.to_vec())
+        if let Ok(capture) = self.stream.get_capture(0) {
+            // MJPG, NV12 and YUY2 are converted, BGRA32 is used as is.
+            let colour_image = capture.get_color_image().convert_to(k4a_image_format_t::K4A_IMAGE_FORMAT_COLOR_BGRA32);
+            let colour_view = colour_image.as_ref().ok().and_then(|image| image.as_bgra32().ok()).filter(|view| view.width() > 0 && view.height() > 0);
+
+            if let Some(view) = colour_view {
+                let pixels: Vec<u8> = view.rows().flatten().flatten().copied().collect();
+                let colour_image_buffer: ImageBuffer<Bgra<u8>, _> = ImageBuffer::from_raw(view.width() as u32, view.height() as u32, pixels)
                         .expect("can't create Image Buffer from raw pixels");
 
                 self.colour_texture = Some(wgpu::Texture::load_from_image_buffer(
@@ -162,21 +158,12 @@ impl Kinect {
             }
 
             let depth_image = capture.get_depth_image();
-            let width = depth_image.get_width_pixels();
-            let height = depth_image.get_height_pixels();
-
-            if width > 0 && height > 0 {
-                let slice = unsafe { std::slice::from_raw_parts(depth_image.get_buffer(), depth_image.get_size()) };
-                // 1: Iterate over slice and grab a chunk of 2
-                // 2: for each chunk convert the 2 u8's into a single u16
-                // 3: Collect the u16's into a vector 
-                // 4: Upload the data into an ImageBuffer of type Gray16
-                let mut v = Vec::new();
-                for chunk in slice.chunks(2) {
-                    let data = [chunk[0], chunk[1]];
-                    v.push(LittleEndian::read_u16(&data));
-                }
-                let depth_image_buffer: ImageBuffer<Luma<u16>, _> = ImageBuffer::from_raw(width as u32, height as u32, v)
+            let depth_view = depth_image.as_depth16().ok().filter(|view| view.width() > 0 && view.height() > 0);
+
+            if let Some(view) = depth_view {
+                // Upload the millimeters into an ImageBuffer of type Gray16
+                let v: Vec<u16> = view.rows().flatten().copied().collect();
+                let depth_image_buffer: ImageBuffer<Luma<u16>, _> = ImageBuffer::from_raw(view.width() as u32, view.height() as u32, v)
                     .expect("can't create Image Buffer from raw pixels");
                 let depth_texture = wgpu::Texture::load_from_image_buffer(
                     wgpu_device, 
@@ -199,17 +186,11 @@ impl Kinect {
 
 
             let ir_image = capture.get_ir_image();
-            let width = ir_image.get_width_pixels();
-            let height = ir_image.get_height_pixels();
-
-            if width > 0 && height > 0 {
-                let slice = unsafe { std::slice::from_raw_parts(ir_image.get_buffer(), ir_image.get_size()) };
-                let mut v = Vec::new();
-                for chunk in slice.chunks(2) {
-                    let data = [chunk[0], chunk[1]];
-                    v.push(LittleEndian::read_u16(&data));
-                }
-                let ir_image_buffer: ImageBuffer<Luma<u16>, _> = ImageBuffer::from_raw(width as u32, height as u32, v)
+            let ir_view = ir_image.as_ir16().ok().filter(|view| view.width() > 0 && view.height() > 0);
+
+            if let Some(view) = ir_view {
+                let v: Vec<u16> = view.rows().flatten().copied().collect();
+                let ir_image_buffer: ImageBuffer<Luma<u16>, _> = ImageBuffer::from_raw(view.width() as u32, view.height() as u32, v)
                     .expect("can't create Image Buffer from raw pixels");
                 let ir_texture = wgpu::Texture::load_from_image_buffer(
                     wgpu_device, 